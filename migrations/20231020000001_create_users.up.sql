@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS users (
+    id UUID PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    email VARCHAR(255) NOT NULL,
+    password TEXT NOT NULL,
+    created_at DATE NOT NULL,
+    updated_at DATE NOT NULL,
+    CONSTRAINT users_email_key UNIQUE (email)
+);
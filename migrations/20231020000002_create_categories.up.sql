@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS categories (
+    id UUID PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    color VARCHAR(255) NOT NULL,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    created_at DATE NOT NULL,
+    updated_at DATE NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS categories_user_id_idx ON categories (user_id);
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS videos (
+    id UUID PRIMARY KEY,
+    title VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL,
+    url TEXT NOT NULL,
+    category_id UUID NOT NULL REFERENCES categories (id) ON DELETE RESTRICT,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    created_at DATE NOT NULL,
+    updated_at DATE NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS videos_category_id_idx ON videos (category_id);
+CREATE INDEX IF NOT EXISTS videos_user_id_idx ON videos (user_id);
@@ -0,0 +1,8 @@
+DROP INDEX IF EXISTS videos_search_document_trgm_idx;
+DROP INDEX IF EXISTS videos_search_vector_idx;
+
+ALTER TABLE videos
+    DROP COLUMN IF EXISTS search_vector,
+    DROP COLUMN IF EXISTS search_document;
+
+DROP FUNCTION IF EXISTS immutable_unaccent(TEXT);
@@ -0,0 +1,24 @@
+CREATE EXTENSION IF NOT EXISTS unaccent;
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE OR REPLACE FUNCTION immutable_unaccent(value TEXT)
+    RETURNS TEXT
+    LANGUAGE sql
+    IMMUTABLE
+    PARALLEL SAFE
+    STRICT
+AS $$
+    SELECT public.unaccent('public.unaccent'::regdictionary, value)
+$$;
+
+ALTER TABLE videos
+    ADD COLUMN search_document TEXT
+        GENERATED ALWAYS AS (lower(immutable_unaccent(title || ' ' || description))) STORED,
+    ADD COLUMN search_vector TSVECTOR
+        GENERATED ALWAYS AS (
+            setweight(to_tsvector('simple', immutable_unaccent(title)), 'A') ||
+            setweight(to_tsvector('simple', immutable_unaccent(description)), 'B')
+        ) STORED;
+
+CREATE INDEX IF NOT EXISTS videos_search_vector_idx ON videos USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS videos_search_document_trgm_idx ON videos USING GIN (search_document gin_trgm_ops);
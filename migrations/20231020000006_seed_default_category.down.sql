@@ -0,0 +1,5 @@
+DELETE FROM categories WHERE id = '00000000-0000-0000-0000-000000000001'
+    AND NOT EXISTS (SELECT 1 FROM videos WHERE category_id = '00000000-0000-0000-0000-000000000001');
+
+DELETE FROM users WHERE id = '00000000-0000-0000-0000-000000000000'
+    AND NOT EXISTS (SELECT 1 FROM categories WHERE user_id = '00000000-0000-0000-0000-000000000000');
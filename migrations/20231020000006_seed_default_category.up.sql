@@ -0,0 +1,21 @@
+INSERT INTO users (id, name, email, password, created_at, updated_at)
+VALUES (
+    '00000000-0000-0000-0000-000000000000',
+    'AluraFlix',
+    'system@aluraflix.local',
+    '$argon2id$v=19$m=19456,t=2,p=1$lMDi+UQaWvWqEo9mOGrmUw$Q1Bg3x8QQa9dI9yvbiDjo1M113bQ9ozBOnHuNxYkitQ',
+    CURRENT_DATE,
+    CURRENT_DATE
+)
+ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO categories (id, name, color, user_id, created_at, updated_at)
+VALUES (
+    '00000000-0000-0000-0000-000000000001',
+    'LIVRE',
+    '#ffffff',
+    '00000000-0000-0000-0000-000000000000',
+    CURRENT_DATE,
+    CURRENT_DATE
+)
+ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS videos_deleted_at_idx;
+DROP INDEX IF EXISTS categories_deleted_at_idx;
+DROP INDEX IF EXISTS users_deleted_at_idx;
+
+ALTER TABLE videos DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE categories DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE users DROP COLUMN IF EXISTS deleted_at;
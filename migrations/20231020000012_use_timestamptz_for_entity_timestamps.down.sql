@@ -0,0 +1,13 @@
+DROP INDEX IF EXISTS videos_updated_at_idx;
+
+ALTER TABLE videos
+    ALTER COLUMN created_at TYPE DATE USING (created_at AT TIME ZONE 'UTC')::date,
+    ALTER COLUMN updated_at TYPE DATE USING (updated_at AT TIME ZONE 'UTC')::date;
+
+ALTER TABLE categories
+    ALTER COLUMN created_at TYPE DATE USING (created_at AT TIME ZONE 'UTC')::date,
+    ALTER COLUMN updated_at TYPE DATE USING (updated_at AT TIME ZONE 'UTC')::date;
+
+ALTER TABLE users
+    ALTER COLUMN created_at TYPE DATE USING (created_at AT TIME ZONE 'UTC')::date,
+    ALTER COLUMN updated_at TYPE DATE USING (updated_at AT TIME ZONE 'UTC')::date;
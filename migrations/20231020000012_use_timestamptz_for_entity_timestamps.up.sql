@@ -0,0 +1,13 @@
+ALTER TABLE users
+    ALTER COLUMN created_at TYPE TIMESTAMPTZ USING created_at::timestamp AT TIME ZONE 'UTC',
+    ALTER COLUMN updated_at TYPE TIMESTAMPTZ USING updated_at::timestamp AT TIME ZONE 'UTC';
+
+ALTER TABLE categories
+    ALTER COLUMN created_at TYPE TIMESTAMPTZ USING created_at::timestamp AT TIME ZONE 'UTC',
+    ALTER COLUMN updated_at TYPE TIMESTAMPTZ USING updated_at::timestamp AT TIME ZONE 'UTC';
+
+ALTER TABLE videos
+    ALTER COLUMN created_at TYPE TIMESTAMPTZ USING created_at::timestamp AT TIME ZONE 'UTC',
+    ALTER COLUMN updated_at TYPE TIMESTAMPTZ USING updated_at::timestamp AT TIME ZONE 'UTC';
+
+CREATE INDEX IF NOT EXISTS videos_updated_at_idx ON videos (updated_at);
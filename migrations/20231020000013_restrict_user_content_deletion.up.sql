@@ -0,0 +1,7 @@
+ALTER TABLE categories
+    DROP CONSTRAINT IF EXISTS categories_user_id_fkey,
+    ADD CONSTRAINT categories_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE RESTRICT;
+
+ALTER TABLE videos
+    DROP CONSTRAINT IF EXISTS videos_user_id_fkey,
+    ADD CONSTRAINT videos_user_id_fkey FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE RESTRICT;
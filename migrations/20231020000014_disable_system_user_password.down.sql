@@ -0,0 +1,2 @@
+-- The system user keeps its disabled password: restoring a verifiable hash would reopen the account.
+SELECT 1;
@@ -0,0 +1 @@
+UPDATE users SET password = '$disabled' WHERE id = '00000000-0000-0000-0000-000000000000';
@@ -0,0 +1,22 @@
+use crate::domain::value_objects::role::{Permission, Role};
+use crate::domain::value_objects::ids::UserId;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrentUser {
+    pub id: UserId,
+    pub role: Role,
+}
+
+impl CurrentUser {
+    pub fn new(id: UserId, role: Role) -> Self {
+        Self { id, role }
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.role.can(permission)
+    }
+
+    pub fn can_modify(&self, owner_id: &UserId, own: Permission, any: Permission) -> bool {
+        self.can(any) || (&self.id == owner_id && self.can(own))
+    }
+}
@@ -1,2 +1,4 @@
+pub mod identity;
 pub mod repositories;
-pub mod usecases;
\ No newline at end of file
+pub mod services;
+pub mod usecases;
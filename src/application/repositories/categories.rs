@@ -1,13 +1,40 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use async_trait::async_trait;
-use crate::application::repositories::Repository;
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{Pageable, SortValue};
 use crate::domain::entities::categories::Categories;
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::ids::{CategoryId, UserId};
 
 #[async_trait]
-pub trait CategoriesRepository: Repository<Categories> {
-    async fn find_by_category_id(&self, category_id: UniqueEntityID) -> Vec<Categories>;
-    async fn find_by_user_id(&self, user_id: UniqueEntityID) -> Vec<Categories>;
+pub trait CategoriesRepository: Repository<Categories, CategoryId> + SoftDeleteRepository<Categories, CategoryId> {
+    async fn find_by_category_id(&self, category_id: CategoryId) -> Result<Vec<Categories>, RepositoryError>;
+    async fn find_by_user_id(&self, user_id: UserId) -> Result<Vec<Categories>, RepositoryError>;
+    async fn find_deleted_by_user_id(&self, user_id: UserId) -> Result<Vec<Categories>, RepositoryError>;
 }
 
-pub type CategoriesRepositoryContract = Arc<Mutex<dyn CategoriesRepository>>;
\ No newline at end of file
+pub type CategoriesRepositoryContract = Arc<dyn CategoriesRepository>;
+
+impl Pageable for Categories {
+    const SORTABLE: &'static [&'static str] = &["created_at", "updated_at", "name"];
+    const FILTERABLE: &'static [&'static str] = &["user_id"];
+
+    fn cursor_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn sort_value(&self, field: &str) -> Option<SortValue> {
+        match field {
+            "created_at" => Some(SortValue::Timestamp(self.created_at)),
+            "updated_at" => Some(SortValue::Timestamp(self.updated_at)),
+            "name" => Some(SortValue::Text(self.name.clone())),
+            _ => None,
+        }
+    }
+
+    fn filter_value(&self, field: &str) -> Option<String> {
+        match field {
+            "user_id" => Some(self.user_id.to_string()),
+            _ => None,
+        }
+    }
+}
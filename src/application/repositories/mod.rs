@@ -1,15 +1,23 @@
 use std::fmt::Debug;
 use async_trait::async_trait;
+use time::OffsetDateTime;
+use crate::application::repositories::pagination::{PageRequest, PageResult};
 use crate::domain::errors::domain_error::DomainError;
-use crate::domain::value_objects::unique_id::UniqueEntityID;
 
 pub mod videos;
 pub mod categories;
 pub mod users;
+pub mod refresh_tokens;
+pub mod email_verifications;
+pub mod password_resets;
+pub mod pagination;
+pub mod unit_of_work;
 
 pub enum RepositoryError {
     NotFound(String),
     AlreadyExists(String),
+    Conflict(String),
+    Infrastructure(String),
     Domain(DomainError),
 }
 
@@ -18,6 +26,8 @@ impl From<RepositoryError> for DomainError {
         match error {
             RepositoryError::NotFound(message) => DomainError::new("Not found", &message),
             RepositoryError::AlreadyExists(message) => DomainError::new("Already exists", &message),
+            RepositoryError::Conflict(message) => DomainError::new("Conflict", &message),
+            RepositoryError::Infrastructure(message) => DomainError::new("Infrastructure error", &message),
             RepositoryError::Domain(error) => error,
         }
     }
@@ -28,15 +38,27 @@ impl Debug for RepositoryError {
         match self {
             RepositoryError::NotFound(message) => write!(f, "Not found: {}", message),
             RepositoryError::AlreadyExists(message) => write!(f, "Already exists: {}", message),
+            RepositoryError::Conflict(message) => write!(f, "Conflict: {}", message),
+            RepositoryError::Infrastructure(message) => write!(f, "Infrastructure error: {}", message),
             RepositoryError::Domain(error) => write!(f, "{:?}", error),
         }
     }
 }
 
 #[async_trait]
-pub trait Repository<T> {
-    async fn find_all(&self) -> Vec<T>;
-    async fn find_by_id(&self, id: UniqueEntityID) -> Result<T, RepositoryError>;
-    async fn save(&mut self, entity: T) -> Result<T, RepositoryError>;
-    async fn delete(&mut self, id: UniqueEntityID) -> Option<RepositoryError>;
-}
\ No newline at end of file
+pub trait Repository<T, Id>: Send + Sync {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<T>, RepositoryError>;
+    async fn find_by_id(&self, id: Id) -> Result<T, RepositoryError>;
+    async fn save(&self, entity: T) -> Result<T, RepositoryError>;
+    async fn update(&self, entity: T) -> Result<T, RepositoryError>;
+    async fn delete(&self, id: Id) -> Option<RepositoryError>;
+}
+
+#[async_trait]
+pub trait SoftDeleteRepository<T, Id>: Send + Sync {
+    async fn find_deleted_by_id(&self, id: Id) -> Result<T, RepositoryError>;
+    async fn delete_at_version(&self, id: Id, version: i32) -> Option<RepositoryError>;
+    async fn restore(&self, id: Id) -> Result<T, RepositoryError>;
+    async fn purge(&self, id: Id) -> Option<RepositoryError>;
+    async fn purge_deleted_before(&self, cutoff: OffsetDateTime) -> Result<u64, RepositoryError>;
+}
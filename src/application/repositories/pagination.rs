@@ -0,0 +1,229 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use crate::application::repositories::RepositoryError;
+use crate::domain::errors::domain_error::DomainError;
+
+pub const DEFAULT_PAGE_LIMIT: u32 = 20;
+pub const MAX_PAGE_LIMIT: u32 = 100;
+pub const DEFAULT_SORT_FIELD: &str = "created_at";
+
+pub trait Pageable {
+    const SORTABLE: &'static [&'static str];
+    const FILTERABLE: &'static [&'static str];
+
+    fn cursor_id(&self) -> String;
+    fn sort_value(&self, field: &str) -> Option<SortValue>;
+    fn filter_value(&self, field: &str) -> Option<String>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SortValue {
+    Text(String),
+    Timestamp(OffsetDateTime),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sort {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    pub field: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    pub field: String,
+    pub value: String,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRequest {
+    pub page: u32,
+    pub limit: u32,
+    pub cursor: Option<String>,
+    pub sort: Sort,
+    pub filters: Vec<Filter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PageResult<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    pub page: u32,
+    pub limit: u32,
+    pub next_cursor: Option<String>,
+}
+
+impl SortDirection {
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+}
+
+impl SortValue {
+    pub fn parse_like(&self, value: &str) -> Option<SortValue> {
+        match self {
+            SortValue::Text(_) => Some(SortValue::Text(value.to_string())),
+            SortValue::Timestamp(_) => OffsetDateTime::parse(value, &Rfc3339).ok().map(SortValue::Timestamp),
+        }
+    }
+}
+
+impl Display for SortValue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            SortValue::Text(value) => write!(f, "{}", value),
+            SortValue::Timestamp(value) => write!(f, "{}", value.format(&Rfc3339).map_err(|_| fmt::Error)?),
+        }
+    }
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(value: &str) -> Result<Self, RepositoryError> {
+        let invalid = || RepositoryError::Domain(DomainError::new("Invalid cursor", "The cursor is malformed or has expired"));
+
+        let bytes = URL_SAFE_NO_PAD.decode(value).map_err(|_| invalid())?;
+
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self::new(1, DEFAULT_PAGE_LIMIT)
+    }
+}
+
+impl PageRequest {
+    pub fn new(page: u32, limit: u32) -> Self {
+        Self {
+            page: page.max(1),
+            limit: limit.clamp(1, MAX_PAGE_LIMIT),
+            cursor: None,
+            sort: Sort {
+                field: DEFAULT_SORT_FIELD.to_string(),
+                direction: SortDirection::Asc,
+            },
+            filters: vec![],
+        }
+    }
+
+    pub fn with_cursor(mut self, cursor: Option<String>) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    pub fn sort_by(mut self, field: &str, direction: SortDirection) -> Self {
+        self.sort = Sort { field: field.to_string(), direction };
+        self
+    }
+
+    pub fn filter(mut self, field: &str, value: &str) -> Self {
+        self.filters.push(Filter { field: field.to_string(), value: value.to_string() });
+        self
+    }
+
+    pub fn offset(&self) -> u64 {
+        (self.page as u64 - 1) * self.limit as u64
+    }
+
+    pub fn require_offset(&self) -> Result<(), RepositoryError> {
+        match self.cursor {
+            Some(_) => Err(RepositoryError::Domain(DomainError::new("Invalid cursor", "Ranked results can only be paginated by page"))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn validate<T: Pageable>(&self) -> Result<Option<Cursor>, RepositoryError> {
+        if !T::SORTABLE.contains(&self.sort.field.as_str()) {
+            return Err(RepositoryError::Domain(DomainError::new("Invalid sort field", &self.sort.field)));
+        }
+
+        if let Some(filter) = self.filters.iter().find(|filter| !T::FILTERABLE.contains(&filter.field.as_str())) {
+            return Err(RepositoryError::Domain(DomainError::new("Invalid filter field", &filter.field)));
+        }
+
+        match self.cursor {
+            Some(ref cursor) => {
+                let cursor = Cursor::decode(cursor)?;
+
+                if cursor.field != self.sort.field {
+                    return Err(RepositoryError::Domain(DomainError::new("Invalid cursor", "The cursor does not match the requested sort")));
+                }
+
+                Ok(Some(cursor))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T: Pageable> PageResult<T> {
+    pub fn from_window(mut items: Vec<T>, total: u64, request: &PageRequest) -> Self {
+        let has_more = items.len() > request.limit as usize;
+        items.truncate(request.limit as usize);
+
+        let next_cursor = match items.last() {
+            Some(last) if has_more => Some(Cursor {
+                field: request.sort.field.clone(),
+                value: last.sort_value(&request.sort.field).map(|value| value.to_string()).unwrap_or_default(),
+                id: last.cursor_id(),
+            }.encode()),
+            _ => None,
+        };
+
+        Self {
+            items,
+            total,
+            page: request.page,
+            limit: request.limit,
+            next_cursor,
+        }
+    }
+}
+
+impl<T> PageResult<T> {
+    pub fn from_page(items: Vec<T>, total: u64, request: &PageRequest) -> Self {
+        Self {
+            items,
+            total,
+            page: request.page,
+            limit: request.limit,
+            next_cursor: None,
+        }
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> PageResult<U> {
+        PageResult {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            limit: self.limit,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
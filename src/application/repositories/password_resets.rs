@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::pagination::{Pageable, SortValue};
+use crate::domain::entities::password_resets::PasswordResets;
+use crate::domain::value_objects::ids::{PasswordResetId, UserId};
+
+#[async_trait]
+pub trait PasswordResetsRepository: Repository<PasswordResets, PasswordResetId> {
+    async fn find_by_token_hash(&self, token_hash: &str) -> Option<PasswordResets>;
+    async fn consume_for_user(&self, user_id: UserId) -> Option<RepositoryError>;
+}
+
+pub type PasswordResetsRepositoryContract = Arc<dyn PasswordResetsRepository>;
+
+impl Pageable for PasswordResets {
+    const SORTABLE: &'static [&'static str] = &["created_at", "expires_at"];
+    const FILTERABLE: &'static [&'static str] = &["user_id"];
+
+    fn cursor_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn sort_value(&self, field: &str) -> Option<SortValue> {
+        match field {
+            "created_at" => Some(SortValue::Timestamp(self.created_at)),
+            "expires_at" => Some(SortValue::Timestamp(self.expires_at)),
+            _ => None,
+        }
+    }
+
+    fn filter_value(&self, field: &str) -> Option<String> {
+        match field {
+            "user_id" => Some(self.user_id.to_string()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use crate::application::repositories::RepositoryError;
+use crate::application::repositories::categories::CategoriesRepository;
+use crate::application::repositories::email_verifications::EmailVerificationsRepository;
+use crate::application::repositories::password_resets::PasswordResetsRepository;
+use crate::application::repositories::refresh_tokens::RefreshTokensRepository;
+use crate::application::repositories::users::UsersRepository;
+use crate::application::repositories::videos::VideosRepository;
+
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, RepositoryError>;
+}
+
+#[async_trait]
+pub trait Transaction: Send + Sync {
+    fn users(&self) -> &dyn UsersRepository;
+    fn videos(&self) -> &dyn VideosRepository;
+    fn categories(&self) -> &dyn CategoriesRepository;
+    fn refresh_tokens(&self) -> &dyn RefreshTokensRepository;
+    fn email_verifications(&self) -> &dyn EmailVerificationsRepository;
+    fn password_resets(&self) -> &dyn PasswordResetsRepository;
+
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError>;
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError>;
+}
+
+pub type UnitOfWorkContract = Arc<dyn UnitOfWork>;
+
+pub async fn finish<T, E: From<RepositoryError>>(transaction: Box<dyn Transaction>, result: Result<T, E>) -> Result<T, E> {
+    match result {
+        Ok(value) => {
+            transaction.commit().await?;
+            Ok(value)
+        }
+        Err(error) => {
+            let _ = transaction.rollback().await;
+            Err(error)
+        }
+    }
+}
@@ -1,12 +1,44 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use async_trait::async_trait;
-use crate::application::repositories::Repository;
+use time::OffsetDateTime;
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{Pageable, SortValue};
 use crate::domain::entities::users::Users;
+use crate::domain::value_objects::ids::UserId;
 use crate::domain::value_objects::email::EmailEntity;
+use crate::domain::value_objects::ValueObjectTrait;
 
 #[async_trait]
-pub trait UsersRepository: Repository<Users> {
-    async fn find_by_email(&self, email: EmailEntity) -> Option<Users>;
+pub trait UsersRepository: Repository<Users, UserId> + SoftDeleteRepository<Users, UserId> {
+    async fn find_by_email(&self, email: EmailEntity) -> Result<Option<Users>, RepositoryError>;
+    async fn find_deleted_before(&self, cutoff: OffsetDateTime) -> Result<Vec<Users>, RepositoryError>;
 }
 
-pub type UsersRepositoryContract = Arc<Mutex<dyn UsersRepository>>;
\ No newline at end of file
+pub type UsersRepositoryContract = Arc<dyn UsersRepository>;
+
+impl Pageable for Users {
+    const SORTABLE: &'static [&'static str] = &["created_at", "updated_at", "name", "email"];
+    const FILTERABLE: &'static [&'static str] = &["email", "role"];
+
+    fn cursor_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn sort_value(&self, field: &str) -> Option<SortValue> {
+        match field {
+            "created_at" => Some(SortValue::Timestamp(self.created_at)),
+            "updated_at" => Some(SortValue::Timestamp(self.updated_at)),
+            "name" => Some(SortValue::Text(self.name.clone())),
+            "email" => Some(SortValue::Text(self.email.to_string())),
+            _ => None,
+        }
+    }
+
+    fn filter_value(&self, field: &str) -> Option<String> {
+        match field {
+            "email" => Some(self.email.to_string()),
+            "role" => Some(self.role.to_string()),
+            _ => None,
+        }
+    }
+}
@@ -1,12 +1,46 @@
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use async_trait::async_trait;
-use crate::application::repositories::Repository;
+use time::OffsetDateTime;
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{PageRequest, PageResult, Pageable, SortValue};
 use crate::domain::entities::videos::Videos;
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::ids::{CategoryId, UserId, VideoId};
 
 #[async_trait]
-pub trait VideosRepository: Repository<Videos> {
-   async fn find_by_category_id(&self, category_id: UniqueEntityID) -> Vec<Videos>;
+pub trait VideosRepository: Repository<Videos, VideoId> + SoftDeleteRepository<Videos, VideoId> {
+   async fn find_by_category_id(&self, category_id: CategoryId) -> Result<Vec<Videos>, RepositoryError>;
+   async fn search(&self, query: &str, request: PageRequest) -> Result<PageResult<Videos>, RepositoryError>;
+   async fn purge_deleted_by_category_id(&self, category_id: CategoryId) -> Result<u64, RepositoryError>;
+   async fn delete_by_user_id(&self, user_id: UserId) -> Result<u64, RepositoryError>;
+   async fn restore_by_user_id(&self, user_id: UserId, deleted_since: OffsetDateTime) -> Result<u64, RepositoryError>;
+   async fn purge_by_user_id(&self, user_id: UserId) -> Result<u64, RepositoryError>;
+   async fn reassign_foreign_videos(&self, category_id: CategoryId, owner_id: UserId, target_id: CategoryId) -> Result<u64, RepositoryError>;
 }
 
-pub type VideosRepositoryContract =  Arc<Mutex<dyn VideosRepository>>;
\ No newline at end of file
+pub type VideosRepositoryContract = Arc<dyn VideosRepository>;
+
+impl Pageable for Videos {
+    const SORTABLE: &'static [&'static str] = &["created_at", "updated_at", "title"];
+    const FILTERABLE: &'static [&'static str] = &["category_id", "user_id"];
+
+    fn cursor_id(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn sort_value(&self, field: &str) -> Option<SortValue> {
+        match field {
+            "created_at" => Some(SortValue::Timestamp(self.created_at)),
+            "updated_at" => Some(SortValue::Timestamp(self.updated_at)),
+            "title" => Some(SortValue::Text(self.title.clone())),
+            _ => None,
+        }
+    }
+
+    fn filter_value(&self, field: &str) -> Option<String> {
+        match field {
+            "category_id" => Some(self.category_id.to_string()),
+            "user_id" => Some(self.user_id.to_string()),
+            _ => None,
+        }
+    }
+}
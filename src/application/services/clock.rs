@@ -0,0 +1,8 @@
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> OffsetDateTime;
+}
+
+pub type ClockContract = Arc<dyn Clock>;
@@ -0,0 +1,23 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+use time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LoginAttemptKey {
+    Email(String),
+    Ip(IpAddr),
+}
+
+pub trait LoginThrottle: Send + Sync {
+    fn check(&self, keys: &[LoginAttemptKey]) -> Result<(), Duration>;
+    fn record_success(&self, keys: &[LoginAttemptKey]);
+    fn release(&self, keys: &[LoginAttemptKey]);
+}
+
+pub type LoginThrottleContract = Arc<dyn LoginThrottle>;
+
+impl LoginAttemptKey {
+    pub fn email(email: &str) -> Self {
+        LoginAttemptKey::Email(email.trim().to_lowercase())
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+pub enum MailerError {
+    InvalidAddress(String),
+    Delivery(String),
+}
+
+impl Debug for MailerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MailerError::InvalidAddress(message) => write!(f, "Invalid address: {}", message),
+            MailerError::Delivery(message) => write!(f, "Mail delivery error: {}", message),
+        }
+    }
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError>;
+}
+
+pub type MailerContract = Arc<dyn Mailer>;
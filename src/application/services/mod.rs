@@ -0,0 +1,4 @@
+pub mod clock;
+pub mod login_throttle;
+pub mod mailer;
+pub mod tokens;
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use time::Duration;
+use crate::domain::value_objects::role::Role;
+use crate::domain::value_objects::ids::UserId;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AccessClaims {
+    pub sub: String,
+    pub role: Role,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+pub enum TokenError {
+    Expired,
+    Invalid,
+    Signing(String),
+}
+
+impl Debug for TokenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenError::Expired => write!(f, "Token expired"),
+            TokenError::Invalid => write!(f, "Invalid token"),
+            TokenError::Signing(message) => write!(f, "Token signing error: {}", message),
+        }
+    }
+}
+
+pub trait TokenService: Send + Sync {
+    fn issue_access_token(&self, user_id: &UserId, role: Role) -> Result<String, TokenError>;
+    fn verify_access_token(&self, token: &str) -> Result<AccessClaims, TokenError>;
+    fn access_token_ttl(&self) -> Duration;
+    fn refresh_token_ttl(&self) -> Duration;
+}
+
+pub type TokenServiceContract = Arc<dyn TokenService>;
@@ -1,39 +1,150 @@
 #[cfg(test)]
 mod test_auth_use_case {
     use tokio;
-    use std::sync::{Arc, Mutex};
+    use std::sync::Arc;
     use crate::application::repositories::Repository;
-    use crate::application::usecases::authentication::{AuthUseCase, AuthUseCaseError};
+    use time::{Duration, OffsetDateTime};
+    use time::macros::datetime;
+    use async_trait::async_trait;
+    use crate::application::services::clock::ClockContract;
+    use crate::application::services::mailer::{Mail, Mailer, MailerError};
+    use crate::application::services::tokens::TokenService;
+    use crate::application::usecases::authentication::{AccountPolicy, AuthUseCase, AuthUseCaseError};
     use crate::domain::entities::users::{Users, UsersInput};
+    use crate::infrastructure::clock::{FixedClock, SystemClock};
+    use crate::infrastructure::persistence::in_memory::email_verifications::EmailVerificationsRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::password_resets::PasswordResetsRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::refresh_tokens::RefreshTokensRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::unit_of_work::UnitOfWorkInMemory;
     use crate::infrastructure::persistence::in_memory::users::UsersRepositoryInMemory;
+    use crate::infrastructure::security::jwt::{JwtConfig, JwtTokenService};
+    use crate::infrastructure::security::login_throttle::{InMemoryLoginThrottle, LoginThrottleConfig};
     use crate::domain::value_objects::ValueObjectTrait;
 
+    const PASSWORD: &str = "12345678";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
     struct Sut {
-        users_repository: Arc<Mutex<UsersRepositoryInMemory>>,
+        users_repository: Arc<UsersRepositoryInMemory>,
+        refresh_tokens_repository: Arc<RefreshTokensRepositoryInMemory>,
+        email_verifications_repository: Arc<EmailVerificationsRepositoryInMemory>,
+        password_resets_repository: Arc<PasswordResetsRepositoryInMemory>,
+        token_service: Arc<JwtTokenService>,
+        mailer: Arc<MailerSpy>,
+        login_throttle: Arc<InMemoryLoginThrottle>,
+        clock: Arc<FixedClock>,
         use_case: AuthUseCase,
         initial_user: Users,
     }
 
+    #[derive(Default)]
+    struct MailerSpy {
+        mails: std::sync::Mutex<Vec<Mail>>,
+    }
+
+    impl MailerSpy {
+        fn last_token(&self) -> Option<String> {
+            let mails = self.mails.lock().unwrap();
+            let body = &mails.last()?.body;
+
+            body.split("token=").nth(1)?.split_whitespace().next().map(str::to_string)
+        }
+
+        async fn wait_for(&self, count: usize) {
+            while self.mails.lock().unwrap().len() < count {
+                tokio::task::yield_now().await;
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Mailer for MailerSpy {
+        async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+            self.mails.lock().unwrap().push(mail);
+            Ok(())
+        }
+    }
+
+    fn account_policy(email_verification_required: bool) -> AccountPolicy {
+        AccountPolicy {
+            email_verification_required,
+            email_verification_ttl: Duration::hours(24),
+            password_reset_ttl: Duration::hours(1),
+            link_base_url: "http://localhost:6010".to_string(),
+        }
+    }
+
+    fn login_throttle(backoff_base: Duration, clock: ClockContract) -> Arc<InMemoryLoginThrottle> {
+        Arc::new(InMemoryLoginThrottle::new(LoginThrottleConfig {
+            max_attempts_per_email: 3,
+            max_attempts_per_ip: 5,
+            backoff_base,
+            lockout: Duration::minutes(15),
+        }, clock))
+    }
+
+    fn token_service(clock: ClockContract) -> Arc<JwtTokenService> {
+        Arc::new(JwtTokenService::new(JwtConfig {
+            secret: "test-secret".to_string(),
+            access_token_ttl: Duration::minutes(15),
+            refresh_token_ttl: Duration::days(30),
+        }, clock))
+    }
+
     async fn setup_sut() -> Sut {
-        let users_repository = Arc::new(Mutex::new(UsersRepositoryInMemory::new()));
+        setup_sut_with(account_policy(false), Duration::ZERO).await
+    }
+
+    async fn setup_sut_with(policy: AccountPolicy, backoff_base: Duration) -> Sut {
+        let clock = Arc::new(FixedClock::new(NOW));
+        let users_repository = Arc::new(UsersRepositoryInMemory::new().with_clock(clock.clone()));
 
         let initial_user = Users::new(&UsersInput {
             name: "John Doe".to_string(),
             email: "doejoe@test.com".to_string(),
-            password: "12345678".to_string(),
-        }).unwrap();
+            password: PASSWORD.to_string(),
+        }, NOW).unwrap();
 
         users_repository
-            .lock()
-            .unwrap()
             .save(initial_user.clone())
             .await
             .unwrap();
 
-        let use_case = AuthUseCase::new(users_repository.clone());
+        let refresh_tokens_repository = Arc::new(RefreshTokensRepositoryInMemory::new().with_clock(clock.clone()));
+        let email_verifications_repository = Arc::new(EmailVerificationsRepositoryInMemory::new().with_clock(clock.clone()));
+        let password_resets_repository = Arc::new(PasswordResetsRepositoryInMemory::new().with_clock(clock.clone()));
+        let token_service = token_service(clock.clone());
+        let login_throttle = login_throttle(backoff_base, clock.clone());
+        let mailer = Arc::new(MailerSpy::default());
+
+        let unit_of_work = Arc::new(UnitOfWorkInMemory::new()
+            .with_users(users_repository.clone())
+            .with_refresh_tokens(refresh_tokens_repository.clone())
+            .with_email_verifications(email_verifications_repository.clone())
+            .with_password_resets(password_resets_repository.clone()));
+
+        let use_case = AuthUseCase::new(
+            users_repository.clone(),
+            refresh_tokens_repository.clone(),
+            email_verifications_repository.clone(),
+            password_resets_repository.clone(),
+            unit_of_work,
+            token_service.clone(),
+            mailer.clone(),
+            login_throttle.clone(),
+            policy,
+            clock.clone(),
+        );
 
         Sut {
             users_repository,
+            refresh_tokens_repository,
+            email_verifications_repository,
+            password_resets_repository,
+            token_service,
+            mailer,
+            login_throttle,
+            clock,
             use_case,
             initial_user,
         }
@@ -46,17 +157,30 @@ mod test_auth_use_case {
 
         #[tokio::test]
         async fn it_should_not_sing_in_when_the_user_does_not_exist() {
-            let users_repository = Arc::new(Mutex::new(UsersRepositoryInMemory::new()));
-            let use_case = AuthUseCase::new(users_repository.clone());
+            let users_repository = Arc::new(UsersRepositoryInMemory::new());
+            let refresh_tokens_repository = Arc::new(RefreshTokensRepositoryInMemory::new());
+            let email_verifications_repository = Arc::new(EmailVerificationsRepositoryInMemory::new());
+            let use_case = AuthUseCase::new(
+                users_repository.clone(),
+                refresh_tokens_repository,
+                email_verifications_repository,
+                Arc::new(PasswordResetsRepositoryInMemory::new()),
+                Arc::new(UnitOfWorkInMemory::new()),
+                token_service(SystemClock::shared()),
+                Arc::new(MailerSpy::default()),
+                login_throttle(Duration::ZERO, SystemClock::shared()),
+                account_policy(false),
+                SystemClock::shared(),
+            );
 
             let input = SignInInput {
                 email: "johndoe@test.com".to_string(),
                 password: "12345678".to_string(),
             };
 
-            let result = use_case.sign_in(input).await;
+            let result = use_case.sign_in(input, None).await;
 
-            assert!(result.is_err());
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidCredentials));
         }
 
         #[tokio::test]
@@ -68,9 +192,9 @@ mod test_auth_use_case {
                 password: "123456789".to_string(),
             };
 
-            let result = sut.use_case.sign_in(input).await;
+            let result = sut.use_case.sign_in(input, None).await;
 
-            assert!(result.is_err());
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidCredentials));
         }
 
         #[tokio::test]
@@ -79,13 +203,20 @@ mod test_auth_use_case {
 
             let input = SignInInput {
                 email: sut.initial_user.email.to_string(),
-                password: sut.initial_user.password.to_string(),
+                password: PASSWORD.to_string(),
             };
 
-            let result = sut.use_case.sign_in(input).await;
+            let result = sut.use_case.sign_in(input, None).await;
 
             assert!(result.is_ok());
-            assert_eq!(result.unwrap(), "User logged in".to_string());
+
+            let tokens = result.unwrap();
+            let claims = sut.token_service.verify_access_token(&tokens.access_token).unwrap();
+
+            assert_eq!(claims.sub, sut.initial_user.id.to_string());
+            assert_eq!(tokens.token_type, "Bearer");
+            assert_eq!(tokens.expires_in, 15 * 60);
+            assert_eq!(sut.refresh_tokens_repository.refresh_tokens.read().await.len(), 1);
         }
 
         #[tokio::test]
@@ -94,12 +225,178 @@ mod test_auth_use_case {
 
             let input = SignInInput {
                 email: "johndoe".to_string(),
-                password: sut.initial_user.password.to_string(),
+                password: PASSWORD.to_string(),
             };
 
-            let result = sut.use_case.sign_in(input).await;
+            let result = sut.use_case.sign_in(input, None).await;
 
-            assert!(result.is_err());
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidCredentials));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_refresh_tokens {
+        use crate::application::usecases::authentication::{AuthTokens, RefreshTokenInput, SignInInput};
+        use super::*;
+
+        async fn sign_in(sut: &Sut) -> AuthTokens {
+            let input = SignInInput {
+                email: sut.initial_user.email.to_string(),
+                password: PASSWORD.to_string(),
+            };
+
+            sut.use_case.sign_in(input, None).await.unwrap()
+        }
+
+        fn refresh_input(tokens: &AuthTokens) -> RefreshTokenInput {
+            RefreshTokenInput { refresh_token: tokens.refresh_token.to_string() }
+        }
+
+        #[tokio::test]
+        async fn it_should_rotate_the_refresh_token() {
+            let sut = setup_sut().await;
+            let tokens = sign_in(&sut).await;
+
+            let refreshed = sut.use_case.refresh(refresh_input(&tokens)).await.unwrap();
+
+            assert_ne!(refreshed.refresh_token, tokens.refresh_token);
+            assert!(sut.token_service.verify_access_token(&refreshed.access_token).is_ok());
+
+            let refresh_tokens = sut.refresh_tokens_repository.refresh_tokens.read().await.clone();
+
+            assert_eq!(refresh_tokens.len(), 2);
+            assert!(refresh_tokens[0].is_revoked());
+            assert!(!refresh_tokens[1].is_revoked());
+            assert_eq!(refresh_tokens[0].family_id, refresh_tokens[1].family_id);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_refresh_with_an_unknown_token() {
+            let sut = setup_sut().await;
+
+            let input = RefreshTokenInput { refresh_token: "unknown".to_string() };
+
+            let result = sut.use_case.refresh(input).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidRefreshToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_revoke_the_family_when_a_rotated_token_is_reused() {
+            let sut = setup_sut().await;
+            let tokens = sign_in(&sut).await;
+            let refreshed = sut.use_case.refresh(refresh_input(&tokens)).await.unwrap();
+
+            let reused = sut.use_case.refresh(refresh_input(&tokens)).await;
+
+            assert!(matches!(reused.unwrap_err(), AuthUseCaseError::InvalidRefreshToken));
+
+            let result = sut.use_case.refresh(refresh_input(&refreshed)).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidRefreshToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_refresh_an_expired_token() {
+            let sut = setup_sut().await;
+            let tokens = sign_in(&sut).await;
+
+            sut.clock.advance(Duration::days(31));
+
+            let result = sut.use_case.refresh(refresh_input(&tokens)).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidRefreshToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_revoke_the_session_on_sign_out() {
+            let sut = setup_sut().await;
+            let tokens = sign_in(&sut).await;
+            let other_session = sign_in(&sut).await;
+
+            let result = sut.use_case.sign_out(refresh_input(&tokens)).await;
+
+            assert!(result.is_ok());
+            assert!(sut.use_case.refresh(refresh_input(&tokens)).await.is_err());
+            assert!(sut.use_case.refresh(refresh_input(&other_session)).await.is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_authenticate {
+        use crate::application::usecases::authentication::SignInInput;
+        use crate::domain::value_objects::role::Role;
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_authenticate_the_owner_of_an_access_token() {
+            let sut = setup_sut().await;
+
+            let tokens = sut.use_case.sign_in(SignInInput {
+                email: sut.initial_user.email.to_string(),
+                password: PASSWORD.to_string(),
+            }, None).await.unwrap();
+
+            let user = sut.use_case.authenticate(&tokens.access_token).unwrap();
+
+            assert_eq!(user.id, sut.initial_user.id);
+            assert_eq!(user.role, Role::Creator);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_authenticate_an_invalid_access_token() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.authenticate("invalid");
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidAccessToken));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_password_hashing {
+        use crate::application::repositories::users::UsersRepository;
+        use crate::application::usecases::authentication::SignInInput;
+        use crate::domain::value_objects::password::{Password, PasswordEntity};
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_store_the_password_hashed_on_sign_up() {
+            let sut = setup_sut().await;
+
+            let input = UsersInput {
+                name: "Marie Joe".to_string(),
+                email: "joema@test.com".to_string(),
+                password: PASSWORD.to_string(),
+            };
+
+            let user = sut.use_case.sign_up(input).await.unwrap();
+
+            assert!(matches!(user.password.value(), Password::Hashed(_)));
+            assert_ne!(user.password.to_string(), PASSWORD);
+        }
+
+        #[tokio::test]
+        async fn it_should_rehash_a_legacy_plaintext_password_on_sign_in() {
+            let sut = setup_sut().await;
+
+            let mut legacy_user = sut.initial_user.clone();
+            legacy_user.password = PasswordEntity::from_hash(PASSWORD);
+            sut.users_repository.update(legacy_user).await.unwrap();
+
+            let input = SignInInput {
+                email: sut.initial_user.email.to_string(),
+                password: PASSWORD.to_string(),
+            };
+
+            let result = sut.use_case.sign_in(input, None).await;
+
+            assert!(result.is_ok());
+
+            let user = sut.users_repository.find_by_email(sut.initial_user.email.clone()).await.unwrap().unwrap();
+
+            assert!(matches!(user.password.value(), Password::Hashed(_)));
+            assert!(user.password.verify(PASSWORD));
         }
     }
 
@@ -109,7 +406,7 @@ mod test_auth_use_case {
 
         #[tokio::test]
         async fn it_should_not_sign_up_when_the_email_is_invalid() {
-            let mut sut = setup_sut().await;
+            let sut = setup_sut().await;
 
             let input = UsersInput {
                 name: "John Doe".to_string(),
@@ -124,7 +421,7 @@ mod test_auth_use_case {
 
         #[tokio::test]
         async fn it_should_not_sign_up_when_the_user_already_exists() {
-            let mut sut = setup_sut().await;
+            let sut = setup_sut().await;
 
             let input = UsersInput {
                 name: "John Doe".to_string(),
@@ -140,7 +437,7 @@ mod test_auth_use_case {
 
         #[tokio::test]
         async fn it_should_sign_up_when_the_user_does_not_exist() {
-            let mut sut = setup_sut().await;
+            let sut = setup_sut().await;
 
             let input = UsersInput {
                 name: "Marie Joe".to_string(),
@@ -154,4 +451,591 @@ mod test_auth_use_case {
             assert_eq!(result.unwrap().name, "Marie Joe".to_string());
         }
     }
-}
\ No newline at end of file
+    #[cfg(test)]
+    mod test_email_verification {
+        use crate::application::usecases::authentication::{ResendVerificationInput, SignInInput, VerifyEmailInput};
+        use super::*;
+
+        fn sign_up_input(email: &str) -> UsersInput {
+            UsersInput {
+                name: "Jane Doe".to_string(),
+                email: email.to_string(),
+                password: PASSWORD.to_string(),
+            }
+        }
+
+        fn verify_input(token: &str) -> VerifyEmailInput {
+            VerifyEmailInput { token: token.to_string() }
+        }
+
+        #[tokio::test]
+        async fn it_should_sign_up_an_unverified_user_and_send_a_verification_email() {
+            let sut = setup_sut().await;
+
+            let user = sut.use_case.sign_up(sign_up_input("janedoe@test.com")).await.unwrap();
+            sut.mailer.wait_for(1).await;
+
+            assert!(!user.is_verified());
+
+            let mails = sut.mailer.mails.lock().unwrap().clone();
+            assert_eq!(mails.len(), 1);
+            assert_eq!(mails[0].to, "janedoe@test.com");
+
+            let verification = sut.email_verifications_repository.email_verifications.read().await[0].clone();
+            assert_ne!(verification.token_hash, sut.mailer.last_token().unwrap());
+        }
+
+        #[tokio::test]
+        async fn it_should_sign_up_without_waiting_for_the_verification_email() {
+            let sut = setup_sut().await;
+
+            sut.use_case.sign_up(sign_up_input("janedoe@test.com")).await.unwrap();
+
+            assert!(sut.mailer.mails.lock().unwrap().is_empty());
+
+            sut.mailer.wait_for(1).await;
+
+            assert!(sut.mailer.last_token().is_some());
+        }
+
+        #[tokio::test]
+        async fn it_should_verify_the_email_only_once() {
+            let sut = setup_sut().await;
+            sut.use_case.sign_up(sign_up_input("janedoe@test.com")).await.unwrap();
+            sut.mailer.wait_for(1).await;
+            let token = sut.mailer.last_token().unwrap();
+
+            let user = sut.use_case.verify_email(verify_input(&token)).await.unwrap();
+
+            assert!(user.is_verified());
+
+            let reused = sut.use_case.verify_email(verify_input(&token)).await;
+
+            assert!(matches!(reused.unwrap_err(), AuthUseCaseError::InvalidVerificationToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_verify_with_an_expired_token() {
+            let sut = setup_sut().await;
+            sut.use_case.sign_up(sign_up_input("janedoe@test.com")).await.unwrap();
+            sut.mailer.wait_for(1).await;
+            let token = sut.mailer.last_token().unwrap();
+
+            sut.clock.advance(Duration::days(2));
+
+            let result = sut.use_case.verify_email(verify_input(&token)).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidVerificationToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_invalidate_the_previous_token_when_resending() {
+            let sut = setup_sut().await;
+            sut.use_case.sign_up(sign_up_input("janedoe@test.com")).await.unwrap();
+            sut.mailer.wait_for(1).await;
+            let first = sut.mailer.last_token().unwrap();
+
+            let input = ResendVerificationInput { email: "janedoe@test.com".to_string() };
+            sut.use_case.resend_verification(input).await.unwrap();
+            sut.mailer.wait_for(2).await;
+            let second = sut.mailer.last_token().unwrap();
+
+            assert!(sut.use_case.verify_email(verify_input(&first)).await.is_err());
+            assert!(sut.use_case.verify_email(verify_input(&second)).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_reveal_unknown_emails_when_resending() {
+            let sut = setup_sut().await;
+
+            let input = ResendVerificationInput { email: "nobody@test.com".to_string() };
+            let result = sut.use_case.resend_verification(input).await;
+            tokio::task::yield_now().await;
+
+            assert!(result.is_ok());
+            assert!(sut.mailer.mails.lock().unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_block_sign_in_for_unverified_users_when_required() {
+            let sut = setup_sut_with(account_policy(true), Duration::ZERO).await;
+            sut.use_case.sign_up(sign_up_input("janedoe@test.com")).await.unwrap();
+
+            let sign_in = || SignInInput { email: "janedoe@test.com".to_string(), password: PASSWORD.to_string() };
+
+            let blocked = sut.use_case.sign_in(sign_in(), None).await;
+
+            assert!(matches!(blocked.unwrap_err(), AuthUseCaseError::EmailNotVerified));
+
+            sut.mailer.wait_for(1).await;
+            let token = sut.mailer.last_token().unwrap();
+            sut.use_case.verify_email(verify_input(&token)).await.unwrap();
+
+            assert!(sut.use_case.sign_in(sign_in(), None).await.is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_password_reset {
+        use crate::application::identity::CurrentUser;
+        use crate::application::usecases::authentication::{AuthTokens, ChangePasswordInput, RefreshTokenInput, RequestPasswordResetInput, ResetPasswordInput, SignInInput};
+        use crate::domain::value_objects::role::Role;
+        use super::*;
+
+        const NEW_PASSWORD: &str = "87654321";
+
+        async fn sign_in(sut: &Sut, password: &str) -> Result<AuthTokens, AuthUseCaseError> {
+            sut.use_case.sign_in(SignInInput {
+                email: sut.initial_user.email.to_string(),
+                password: password.to_string(),
+            }, None).await
+        }
+
+        async fn request_reset(sut: &Sut) -> String {
+            let input = RequestPasswordResetInput { email: sut.initial_user.email.to_string() };
+            let sent = sut.mailer.mails.lock().unwrap().len();
+            sut.use_case.request_password_reset(input).await.unwrap();
+            sut.mailer.wait_for(sent + 1).await;
+
+            sut.mailer.last_token().unwrap()
+        }
+
+        fn reset_input(token: &str, password: &str) -> ResetPasswordInput {
+            ResetPasswordInput { token: token.to_string(), password: password.to_string() }
+        }
+
+        fn current_user(sut: &Sut) -> CurrentUser {
+            CurrentUser::new(sut.initial_user.id, Role::Creator)
+        }
+
+        #[tokio::test]
+        async fn it_should_not_reveal_unknown_emails_when_requesting_a_reset() {
+            let sut = setup_sut().await;
+
+            let input = RequestPasswordResetInput { email: "nobody@test.com".to_string() };
+            let result = sut.use_case.request_password_reset(input).await;
+            tokio::task::yield_now().await;
+
+            assert!(result.is_ok());
+            assert!(sut.mailer.mails.lock().unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_send_the_reset_email_without_waiting_for_the_delivery() {
+            let sut = setup_sut().await;
+
+            let input = RequestPasswordResetInput { email: sut.initial_user.email.to_string() };
+            sut.use_case.request_password_reset(input).await.unwrap();
+
+            assert!(sut.mailer.mails.lock().unwrap().is_empty());
+
+            sut.mailer.wait_for(1).await;
+
+            assert!(sut.mailer.last_token().is_some());
+        }
+
+        #[tokio::test]
+        async fn it_should_issue_the_reset_token_after_answering_the_request() {
+            let sut = setup_sut().await;
+
+            let input = RequestPasswordResetInput { email: sut.initial_user.email.to_string() };
+            sut.use_case.request_password_reset(input).await.unwrap();
+
+            assert!(sut.password_resets_repository.password_resets.read().await.is_empty());
+
+            sut.mailer.wait_for(1).await;
+
+            assert_eq!(sut.password_resets_repository.password_resets.read().await.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_store_only_the_hash_of_the_reset_token() {
+            let sut = setup_sut().await;
+
+            let token = request_reset(&sut).await;
+
+            let reset = sut.password_resets_repository.password_resets.read().await[0].clone();
+            assert_ne!(reset.token_hash, token);
+            assert_eq!(reset.user_id, sut.initial_user.id);
+        }
+
+        #[tokio::test]
+        async fn it_should_reset_the_password_and_revoke_the_sessions() {
+            let sut = setup_sut().await;
+            let tokens = sign_in(&sut, PASSWORD).await.unwrap();
+            let token = request_reset(&sut).await;
+
+            sut.use_case.reset_password(reset_input(&token, NEW_PASSWORD)).await.unwrap();
+
+            assert!(matches!(sign_in(&sut, PASSWORD).await.unwrap_err(), AuthUseCaseError::InvalidCredentials));
+            assert!(sign_in(&sut, NEW_PASSWORD).await.is_ok());
+
+            let refresh = sut.use_case.refresh(RefreshTokenInput { refresh_token: tokens.refresh_token }).await;
+            assert!(matches!(refresh.unwrap_err(), AuthUseCaseError::InvalidRefreshToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_reset_the_password_only_once() {
+            let sut = setup_sut().await;
+            let token = request_reset(&sut).await;
+
+            sut.use_case.reset_password(reset_input(&token, NEW_PASSWORD)).await.unwrap();
+            let reused = sut.use_case.reset_password(reset_input(&token, "another-password")).await;
+
+            assert!(matches!(reused.unwrap_err(), AuthUseCaseError::InvalidPasswordResetToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_reset_with_an_expired_token() {
+            let sut = setup_sut().await;
+            let token = request_reset(&sut).await;
+
+            sut.clock.advance(Duration::hours(2));
+
+            let result = sut.use_case.reset_password(reset_input(&token, NEW_PASSWORD)).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidPasswordResetToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_invalidate_the_previous_reset_token() {
+            let sut = setup_sut().await;
+            let first = request_reset(&sut).await;
+            let second = request_reset(&sut).await;
+
+            assert!(sut.use_case.reset_password(reset_input(&first, NEW_PASSWORD)).await.is_err());
+            assert!(sut.use_case.reset_password(reset_input(&second, NEW_PASSWORD)).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_keep_the_token_when_the_new_password_is_too_short() {
+            let sut = setup_sut().await;
+            let token = request_reset(&sut).await;
+
+            let result = sut.use_case.reset_password(reset_input(&token, "short")).await;
+
+            match result.unwrap_err() {
+                AuthUseCaseError::Domain(error) => assert_eq!(error.errors.field("password").unwrap().code, "too_short"),
+                error => panic!("unexpected error: {:?}", error),
+            }
+
+            assert!(sut.use_case.reset_password(reset_input(&token, NEW_PASSWORD)).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_change_the_password_and_revoke_the_sessions() {
+            let sut = setup_sut().await;
+            let tokens = sign_in(&sut, PASSWORD).await.unwrap();
+
+            let input = ChangePasswordInput { current_password: PASSWORD.to_string(), password: NEW_PASSWORD.to_string() };
+            sut.use_case.change_password(&current_user(&sut), input).await.unwrap();
+
+            assert!(sign_in(&sut, NEW_PASSWORD).await.is_ok());
+
+            let refresh = sut.use_case.refresh(RefreshTokenInput { refresh_token: tokens.refresh_token }).await;
+            assert!(matches!(refresh.unwrap_err(), AuthUseCaseError::InvalidRefreshToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_change_the_password_when_the_current_one_is_wrong() {
+            let sut = setup_sut().await;
+
+            let input = ChangePasswordInput { current_password: "wrong-password".to_string(), password: NEW_PASSWORD.to_string() };
+            let result = sut.use_case.change_password(&current_user(&sut), input).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::IncorrectCurrentPassword));
+            assert!(sign_in(&sut, PASSWORD).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_change_to_a_password_that_is_too_short() {
+            let sut = setup_sut().await;
+
+            let input = ChangePasswordInput { current_password: PASSWORD.to_string(), password: "short".to_string() };
+            let result = sut.use_case.change_password(&current_user(&sut), input).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::Domain(_)));
+            assert!(sign_in(&sut, PASSWORD).await.is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_login_throttle {
+        use std::net::IpAddr;
+        use crate::application::services::login_throttle::{LoginAttemptKey, LoginThrottle};
+        use crate::application::usecases::authentication::{AuthTokens, SignInInput};
+        use super::*;
+
+        const CLIENT_IP: &str = "203.0.113.7";
+
+        fn client_ip() -> Option<IpAddr> {
+            CLIENT_IP.parse().ok()
+        }
+
+        async fn sign_in(sut: &Sut, email: &str, password: &str) -> Result<AuthTokens, AuthUseCaseError> {
+            let input = SignInInput { email: email.to_string(), password: password.to_string() };
+
+            sut.use_case.sign_in(input, client_ip()).await
+        }
+
+        #[tokio::test]
+        async fn it_should_answer_unknown_emails_and_wrong_passwords_the_same_way() {
+            let sut = setup_sut().await;
+            let email = sut.initial_user.email.to_string();
+
+            let unknown = sut.use_case.sign_in(SignInInput { email: "nobody@test.com".to_string(), password: PASSWORD.to_string() }, None).await;
+            let wrong = sut.use_case.sign_in(SignInInput { email, password: "wrong-password".to_string() }, None).await;
+
+            assert_eq!(format!("{:?}", unknown.unwrap_err()), format!("{:?}", wrong.unwrap_err()));
+        }
+
+        #[tokio::test]
+        async fn it_should_lock_the_email_after_too_many_failures() {
+            let sut = setup_sut().await;
+            let email = sut.initial_user.email.to_string();
+
+            for _ in 0..3 {
+                assert!(matches!(sign_in(&sut, &email, "wrong-password").await.unwrap_err(), AuthUseCaseError::InvalidCredentials));
+            }
+
+            match sign_in(&sut, &email, PASSWORD).await.unwrap_err() {
+                AuthUseCaseError::TooManyAttempts(retry_after) => assert!(retry_after > Duration::minutes(14)),
+                error => panic!("unexpected error: {:?}", error),
+            }
+
+            sut.clock.advance(Duration::minutes(15));
+
+            assert!(sign_in(&sut, &email, PASSWORD).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_track_unknown_emails_too() {
+            let sut = setup_sut().await;
+
+            for _ in 0..3 {
+                let _ = sign_in(&sut, "nobody@test.com", PASSWORD).await;
+            }
+
+            let result = sign_in(&sut, "NOBODY@test.com", PASSWORD).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::TooManyAttempts(_)));
+        }
+
+        #[tokio::test]
+        async fn it_should_back_off_exponentially_between_failures() {
+            let sut = setup_sut_with(account_policy(false), Duration::seconds(10)).await;
+            let email = sut.initial_user.email.to_string();
+
+            let _ = sign_in(&sut, &email, "wrong-password").await;
+
+            match sign_in(&sut, &email, PASSWORD).await.unwrap_err() {
+                AuthUseCaseError::TooManyAttempts(retry_after) => assert!(retry_after <= Duration::seconds(10)),
+                error => panic!("unexpected error: {:?}", error),
+            }
+
+            sut.clock.advance(Duration::seconds(10));
+            let _ = sign_in(&sut, &email, "wrong-password").await;
+
+            match sign_in(&sut, &email, PASSWORD).await.unwrap_err() {
+                AuthUseCaseError::TooManyAttempts(retry_after) => assert!(retry_after > Duration::seconds(10)),
+                error => panic!("unexpected error: {:?}", error),
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_reset_the_email_counter_after_a_successful_sign_in() {
+            let sut = setup_sut().await;
+            let email = sut.initial_user.email.to_string();
+
+            let _ = sign_in(&sut, &email, "wrong-password").await;
+            let _ = sign_in(&sut, &email, "wrong-password").await;
+
+            assert!(sign_in(&sut, &email, PASSWORD).await.is_ok());
+
+            let attempts = sut.login_throttle.attempts.lock().unwrap();
+            assert!(!attempts.contains_key(&LoginAttemptKey::email(&email)));
+            assert_eq!(attempts[&LoginAttemptKey::Ip(client_ip().unwrap())].failures, 2);
+        }
+
+        #[tokio::test]
+        async fn it_should_reserve_each_attempt_before_the_credentials_are_verified() {
+            let sut = setup_sut().await;
+            let keys = [LoginAttemptKey::email(&sut.initial_user.email.to_string())];
+
+            let admitted = (0..10).filter(|_| sut.login_throttle.check(&keys).is_ok()).count();
+
+            assert_eq!(admitted, 3);
+        }
+
+        #[tokio::test]
+        async fn it_should_release_the_reserved_ip_attempt_after_a_successful_sign_in() {
+            let sut = setup_sut().await;
+
+            for _ in 0..10 {
+                assert!(sign_in(&sut, &sut.initial_user.email.to_string(), PASSWORD).await.is_ok());
+            }
+
+            assert!(sut.login_throttle.attempts.lock().unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_lock_the_client_ip_across_emails() {
+            let sut = setup_sut().await;
+
+            for index in 0..5 {
+                let _ = sign_in(&sut, &format!("user{}@test.com", index), PASSWORD).await;
+            }
+
+            let result = sign_in(&sut, &sut.initial_user.email.to_string(), PASSWORD).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::TooManyAttempts(_)));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_errors {
+        use http::StatusCode;
+        use crate::application::services::tokens::TokenError;
+        use crate::domain::errors::app_error::AppError;
+        use super::*;
+
+        #[test]
+        fn it_should_not_expose_token_errors_in_the_response() {
+            let error = AppError::from(AuthUseCaseError::Token(TokenError::Signing("invalid key length".to_string())));
+
+            assert_eq!(error.status(), StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(error.message(), "Internal server error");
+        }
+    }
+
+    #[cfg(test)]
+    mod test_database_outage {
+        use std::net::IpAddr;
+        use http::StatusCode;
+        use sqlx::postgres::PgPoolOptions;
+        use crate::application::usecases::authentication::SignInInput;
+        use crate::domain::errors::app_error::AppError;
+        use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
+        use super::*;
+
+        fn setup_unavailable() -> (AuthUseCase, Arc<InMemoryLoginThrottle>) {
+            let pool = PgPoolOptions::new()
+                .acquire_timeout(std::time::Duration::from_millis(200))
+                .connect_lazy("postgres://localhost:1/unreachable")
+                .unwrap();
+            let login_throttle = login_throttle(Duration::ZERO, SystemClock::shared());
+
+            let use_case = AuthUseCase::new(
+                Arc::new(UsersRepositoryImpl::new(pool)),
+                Arc::new(RefreshTokensRepositoryInMemory::new()),
+                Arc::new(EmailVerificationsRepositoryInMemory::new()),
+                Arc::new(PasswordResetsRepositoryInMemory::new()),
+                Arc::new(UnitOfWorkInMemory::new()),
+                token_service(SystemClock::shared()),
+                Arc::new(MailerSpy::default()),
+                login_throttle.clone(),
+                account_policy(false),
+                SystemClock::shared(),
+            );
+
+            (use_case, login_throttle)
+        }
+
+        #[tokio::test]
+        async fn it_should_fail_the_sign_in_without_spending_an_attempt_when_the_database_is_down() {
+            let (use_case, login_throttle) = setup_unavailable();
+            let input = SignInInput { email: "doejoe@test.com".to_string(), password: PASSWORD.to_string() };
+
+            let error = use_case.sign_in(input, "203.0.113.7".parse::<IpAddr>().ok()).await.unwrap_err();
+
+            assert!(matches!(error, AuthUseCaseError::Infrastructure(_)));
+            assert_eq!(AppError::from(error).status(), StatusCode::INTERNAL_SERVER_ERROR);
+            assert!(login_throttle.attempts.lock().unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_sign_up_when_the_database_is_down() {
+            let (use_case, _) = setup_unavailable();
+
+            let result = use_case.sign_up(UsersInput {
+                name: "John Doe".to_string(),
+                email: "doejoe@test.com".to_string(),
+                password: PASSWORD.to_string(),
+            }).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::Infrastructure(_)));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_system_user {
+        use crate::application::usecases::authentication::{RefreshTokenInput, RequestPasswordResetInput, ResetPasswordInput, SignInInput};
+        use crate::domain::entities::password_resets::PasswordResets;
+        use crate::domain::entities::refresh_tokens::RefreshTokens;
+        use crate::domain::entities::users::SYSTEM_USER_ID;
+        use crate::domain::value_objects::ids::TokenFamilyId;
+        use super::*;
+
+        const SYSTEM_EMAIL: &str = "system@aluraflix.local";
+
+        async fn seed_system_user(sut: &Sut) -> Users {
+            let mut user = Users::new(&UsersInput {
+                name: "AluraFlix".to_string(),
+                email: SYSTEM_EMAIL.to_string(),
+                password: PASSWORD.to_string(),
+            }, NOW).unwrap();
+            user.id = SYSTEM_USER_ID.parse().unwrap();
+
+            sut.users_repository.save(user).await.unwrap()
+        }
+
+        #[tokio::test]
+        async fn it_should_not_sign_in_as_the_system_user() {
+            let sut = setup_sut().await;
+            seed_system_user(&sut).await;
+
+            let input = SignInInput { email: SYSTEM_EMAIL.to_string(), password: PASSWORD.to_string() };
+            let result = sut.use_case.sign_in(input, None).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidCredentials));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_refresh_a_session_of_the_system_user() {
+            let sut = setup_sut().await;
+            let user = seed_system_user(&sut).await;
+            let (refresh_token, token) = RefreshTokens::new(user.id, TokenFamilyId::generate(), Duration::days(30), NOW);
+            sut.refresh_tokens_repository.save(refresh_token).await.unwrap();
+
+            let result = sut.use_case.refresh(RefreshTokenInput { refresh_token: token }).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidRefreshToken));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_send_a_password_reset_to_the_system_user() {
+            let sut = setup_sut().await;
+            seed_system_user(&sut).await;
+
+            let input = RequestPasswordResetInput { email: SYSTEM_EMAIL.to_string() };
+            let result = sut.use_case.request_password_reset(input).await;
+            tokio::task::yield_now().await;
+
+            assert!(result.is_ok());
+            assert!(sut.password_resets_repository.password_resets.read().await.is_empty());
+            assert!(sut.mailer.mails.lock().unwrap().is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_reset_the_password_of_the_system_user() {
+            let sut = setup_sut().await;
+            let user = seed_system_user(&sut).await;
+            let (reset, token) = PasswordResets::new(user.id, Duration::hours(1), NOW);
+            sut.password_resets_repository.save(reset).await.unwrap();
+
+            let result = sut.use_case.reset_password(ResetPasswordInput { token, password: "87654321".to_string() }).await;
+
+            assert!(matches!(result.unwrap_err(), AuthUseCaseError::InvalidPasswordResetToken));
+        }
+    }
+}
@@ -0,0 +1,490 @@
+#[cfg(test)]
+mod test_categories_use_case {
+    use std::sync::Arc;
+    use axum::http::StatusCode;
+    use time::{Duration, OffsetDateTime};
+    use time::macros::datetime;
+    use crate::application::identity::CurrentUser;
+    use crate::application::repositories::{Repository, SoftDeleteRepository};
+    use crate::application::repositories::pagination::PageRequest;
+    use crate::application::repositories::unit_of_work::{finish, UnitOfWork};
+    use crate::application::services::clock::Clock;
+    use crate::application::usecases::categories::{CategoriesUseCase, CategoriesUseCaseError, DeleteCategoryStrategy};
+    use crate::domain::entities::categories::{Categories, CategoriesInput, DEFAULT_CATEGORY_ID};
+    use crate::domain::entities::videos::{Videos, VideosInput};
+    use crate::domain::errors::app_error::AppError;
+    use crate::domain::value_objects::role::Role;
+    use crate::domain::value_objects::ValueObjectTrait;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::persistence::in_memory::categories::CategoriesRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::unit_of_work::UnitOfWorkInMemory;
+    use crate::infrastructure::persistence::in_memory::videos::VideosRepositoryInMemory;
+
+    const USER_ID: &str = "018b33b3-6d70-7d94-9ecc-0cc5069b30ea";
+    const UNKNOWN_ID: &str = "018b33b3-6d70-7d94-9ecc-0cc5069b30eb";
+    const OTHER_USER_ID: &str = "018b33b3-6d70-7d94-9ecc-0cc5069b30ec";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    struct Sut {
+        categories_repository: Arc<CategoriesRepositoryInMemory>,
+        videos_repository: Arc<VideosRepositoryInMemory>,
+        unit_of_work: Arc<UnitOfWorkInMemory>,
+        clock: Arc<FixedClock>,
+        use_case: CategoriesUseCase,
+        initial_category: Categories,
+    }
+
+    fn category_input(name: &str) -> CategoriesInput {
+        CategoriesInput {
+            name: name.to_string(),
+            color: "#000000".to_string(),
+            user_id: USER_ID.to_string(),
+        }
+    }
+
+    fn owner() -> CurrentUser {
+        CurrentUser::new(USER_ID.parse().unwrap(), Role::Creator)
+    }
+
+    fn admin() -> CurrentUser {
+        CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Admin)
+    }
+
+    async fn add_video(sut: &Sut, category: &Categories) -> Videos {
+        let video = Videos::new(&VideosInput {
+            title: "Video title".to_string(),
+            description: "Video description".to_string(),
+            url: "https://www.youtube.com/watch?v=6n3pFFPSlW4".to_string(),
+            category_id: Some(category.id.to_string()),
+            user_id: USER_ID.to_string(),
+        }, sut.clock.now()).unwrap();
+
+        sut.videos_repository.save(video).await.unwrap()
+    }
+
+    async fn add_category(sut: &Sut, name: &str) -> Categories {
+        let category = Categories::new(&category_input(name), sut.clock.now()).unwrap();
+
+        sut.categories_repository.save(category).await.unwrap()
+    }
+
+    async fn setup_sut() -> Sut {
+        let clock = Arc::new(FixedClock::new(NOW));
+        let categories_repository = Arc::new(CategoriesRepositoryInMemory::new().with_clock(clock.clone()));
+        let videos_repository = Arc::new(VideosRepositoryInMemory::new().with_clock(clock.clone()));
+
+        let initial_category = Categories::new(&category_input("Initial category"), NOW).unwrap();
+
+        categories_repository
+            .save(initial_category.clone())
+            .await
+            .unwrap();
+
+        let unit_of_work = Arc::new(UnitOfWorkInMemory::new()
+            .with_categories(categories_repository.clone())
+            .with_videos(videos_repository.clone()));
+
+        let use_case = CategoriesUseCase::new(categories_repository.clone(), videos_repository.clone(), unit_of_work.clone(), clock.clone());
+
+        Sut {
+            categories_repository,
+            videos_repository,
+            unit_of_work,
+            clock,
+            use_case,
+            initial_category,
+        }
+    }
+
+    #[cfg(test)]
+    mod test_crud {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_create_a_category() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.create(&owner(), category_input("New category")).await;
+
+            assert!(result.is_ok());
+            assert_eq!(sut.categories_repository.categories.read().await.len(), 2);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_create_a_category_with_invalid_data() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.create(&owner(), category_input("a")).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::Domain(_)));
+        }
+
+        #[tokio::test]
+        async fn it_should_find_a_category_by_id() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_by_id(&sut.initial_category.id.to_string()).await;
+
+            assert_eq!(result.unwrap().name, "Initial category");
+        }
+
+        #[tokio::test]
+        async fn it_should_not_find_a_category_that_does_not_exist() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_by_id(UNKNOWN_ID).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::CategoriesNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_list_all_categories() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_all(PageRequest::default()).await;
+
+            assert_eq!(result.unwrap().items.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_update_a_category() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+
+            let result = sut.use_case.update(&owner(), &id, category_input("Updated category"), None).await;
+
+            assert_eq!(result.unwrap().name, "Updated category");
+        }
+
+        #[tokio::test]
+        async fn it_should_not_update_a_category_owned_by_another_user() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+            let stranger = CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Creator);
+
+            let result = sut.use_case.update(&stranger, &id, category_input("Updated category"), None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::Forbidden));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_update_a_category_with_a_stale_expected_version() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+
+            let category = sut.use_case.update(&owner(), &id, category_input("First writer"), Some(1)).await.unwrap();
+            let result = sut.use_case.update(&owner(), &id, category_input("Second writer"), Some(1)).await;
+
+            assert_eq!(category.version, 2);
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::PreconditionFailed));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_category_with_a_stale_expected_version() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+
+            sut.use_case.update(&owner(), &id, category_input("First writer"), Some(1)).await.unwrap();
+            let result = sut.use_case.delete(&owner(), &id, DeleteCategoryStrategy::Reject, Some(1)).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::PreconditionFailed));
+            assert!(sut.use_case.find_by_id(&id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_category_changed_after_it_was_read() {
+            let sut = setup_sut().await;
+
+            sut.use_case.update(&owner(), &sut.initial_category.id.to_string(), category_input("First writer"), None).await.unwrap();
+            let result = sut.categories_repository.delete_at_version(sut.initial_category.id, sut.initial_category.version).await;
+
+            assert!(matches!(CategoriesUseCaseError::from(result.unwrap()), CategoriesUseCaseError::Conflict));
+            assert!(sut.categories_repository.find_by_id(sut.initial_category.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_answer_conflicts_with_409_and_failed_preconditions_with_412() {
+            assert_eq!(AppError::from(CategoriesUseCaseError::Conflict).status(), StatusCode::CONFLICT);
+            assert_eq!(AppError::from(CategoriesUseCaseError::PreconditionFailed).status(), StatusCode::PRECONDITION_FAILED);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_category_owned_by_another_user() {
+            let sut = setup_sut().await;
+            let stranger = CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Creator);
+
+            let result = sut.use_case.delete(&stranger, &sut.initial_category.id.to_string(), DeleteCategoryStrategy::Reject, None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::Forbidden));
+            assert_eq!(sut.categories_repository.categories.read().await.len(), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_find_videos {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_list_the_videos_of_a_category() {
+            let sut = setup_sut().await;
+            let other_category = add_category(&sut, "Other category").await;
+
+            add_video(&sut, &sut.initial_category).await;
+            add_video(&sut, &other_category).await;
+
+            let result = sut.use_case.find_videos(&sut.initial_category.id.to_string(), PageRequest::default()).await;
+
+            assert_eq!(result.unwrap().items.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_list_videos_of_a_category_that_does_not_exist() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_videos(UNKNOWN_ID, PageRequest::default()).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::CategoriesNotFound));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_delete {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_delete_a_category_without_videos() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.delete(&owner(), &sut.initial_category.id.to_string(), DeleteCategoryStrategy::Reject, None).await;
+
+            assert!(result.is_ok());
+            assert!(sut.categories_repository.categories.read().await[0].is_deleted());
+            assert!(sut.categories_repository.find_by_id(sut.initial_category.id).await.is_err());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_category_with_videos_by_default() {
+            let sut = setup_sut().await;
+            add_video(&sut, &sut.initial_category).await;
+
+            let result = sut.use_case.delete(&owner(), &sut.initial_category.id.to_string(), DeleteCategoryStrategy::Reject, None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::CategoryHasVideos));
+            assert_eq!(sut.categories_repository.categories.read().await.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_reassign_the_videos_when_deleting() {
+            let sut = setup_sut().await;
+            let target = add_category(&sut, "Target category").await;
+            let video = add_video(&sut, &sut.initial_category).await;
+
+            let strategy = DeleteCategoryStrategy::Reassign(target.id.to_string());
+            let result = sut.use_case.delete(&owner(), &sut.initial_category.id.to_string(), strategy, None).await;
+
+            assert!(result.is_ok());
+
+            let video = sut.videos_repository.find_by_id(video.id).await.unwrap();
+            assert_eq!(video.category_id, target.id);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_reassign_the_videos_to_the_deleted_category() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+            add_video(&sut, &sut.initial_category).await;
+
+            let result = sut.use_case.delete(&owner(), &id, DeleteCategoryStrategy::Reassign(id.clone()), None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::Domain(_)));
+        }
+
+        #[tokio::test]
+        async fn it_should_delete_the_videos_when_cascading() {
+            let sut = setup_sut().await;
+            add_video(&sut, &sut.initial_category).await;
+
+            let result = sut.use_case.delete(&owner(), &sut.initial_category.id.to_string(), DeleteCategoryStrategy::Cascade, None).await;
+
+            assert!(result.is_ok());
+            assert!(sut.videos_repository.videos.read().await.iter().all(|video| video.is_deleted()));
+            assert!(sut.categories_repository.categories.read().await.iter().all(|category| category.is_deleted()));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_cascade_over_videos_owned_by_another_user() {
+            let sut = setup_sut().await;
+            let mut video = add_video(&sut, &sut.initial_category).await;
+            video.user_id = OTHER_USER_ID.parse().unwrap();
+            let video = sut.videos_repository.update(video).await.unwrap();
+
+            let result = sut.use_case.delete(&owner(), &sut.initial_category.id.to_string(), DeleteCategoryStrategy::Cascade, None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::Forbidden));
+            assert!(sut.videos_repository.find_by_id(video.id).await.is_ok());
+            assert!(sut.categories_repository.find_by_id(sut.initial_category.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_reassign_the_videos_to_a_category_owned_by_another_user() {
+            let sut = setup_sut().await;
+            let mut target = Categories::new(&category_input("Foreign category"), NOW).unwrap();
+            target.user_id = OTHER_USER_ID.parse().unwrap();
+            let target = sut.categories_repository.save(target).await.unwrap();
+            let video = add_video(&sut, &sut.initial_category).await;
+
+            let strategy = DeleteCategoryStrategy::Reassign(target.id.to_string());
+            let result = sut.use_case.delete(&owner(), &sut.initial_category.id.to_string(), strategy, None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::Forbidden));
+            assert_eq!(sut.videos_repository.find_by_id(video.id).await.unwrap().category_id, sut.initial_category.id);
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_the_videos_and_the_category_when_the_transaction_fails() {
+            let sut = setup_sut().await;
+            let video = add_video(&sut, &sut.initial_category).await;
+
+            let transaction = sut.unit_of_work.begin().await.unwrap();
+
+            assert!(transaction.videos().delete(video.id).await.is_none());
+            assert!(transaction.categories().delete(sut.initial_category.id).await.is_none());
+
+            let result: Result<(), CategoriesUseCaseError> = finish(transaction, Err(CategoriesUseCaseError::CategoryHasVideos)).await;
+
+            assert!(result.is_err());
+            assert!(sut.videos_repository.find_by_id(video.id).await.is_ok());
+            assert!(sut.categories_repository.find_by_id(sut.initial_category.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_keep_the_changes_when_the_transaction_succeeds() {
+            let sut = setup_sut().await;
+            let video = add_video(&sut, &sut.initial_category).await;
+
+            let transaction = sut.unit_of_work.begin().await.unwrap();
+
+            assert!(transaction.videos().delete(video.id).await.is_none());
+
+            let result: Result<(), CategoriesUseCaseError> = finish(transaction, Ok(())).await;
+
+            assert!(result.is_ok());
+            assert!(sut.videos_repository.find_by_id(video.id).await.is_err());
+        }
+    }
+
+    #[cfg(test)]
+    mod test_default_category {
+        use super::*;
+
+        async fn add_default_category(sut: &Sut) -> Categories {
+            sut.categories_repository.save(Categories::default_category(NOW)).await.unwrap()
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_the_default_category() {
+            let sut = setup_sut().await;
+            add_default_category(&sut).await;
+
+            let result = sut.use_case.delete(&admin(), DEFAULT_CATEGORY_ID, DeleteCategoryStrategy::Cascade, None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::DefaultCategoryProtected));
+            assert_eq!(sut.categories_repository.categories.read().await.len(), 2);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_recolor_the_default_category() {
+            let sut = setup_sut().await;
+            add_default_category(&sut).await;
+
+            let result = sut.use_case.update(&admin(), DEFAULT_CATEGORY_ID, category_input("LIVRE"), None).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::DefaultCategoryProtected));
+            assert_eq!(sut.use_case.find_by_id(DEFAULT_CATEGORY_ID).await.unwrap().color.to_string(), "#ffffff");
+        }
+
+        #[tokio::test]
+        async fn it_should_rename_the_default_category_keeping_its_color() {
+            let sut = setup_sut().await;
+            add_default_category(&sut).await;
+
+            let mut input = category_input("Livre para todos");
+            input.color = "#FFFFFF".to_string();
+
+            let result = sut.use_case.update(&admin(), DEFAULT_CATEGORY_ID, input, None).await;
+
+            assert_eq!(result.unwrap().name, "Livre para todos");
+        }
+
+        #[tokio::test]
+        async fn it_should_reassign_the_videos_to_the_default_category() {
+            let sut = setup_sut().await;
+            let default_category = add_default_category(&sut).await;
+            let video = add_video(&sut, &sut.initial_category).await;
+            sut.clock.advance(Duration::minutes(5));
+
+            let strategy = DeleteCategoryStrategy::Reassign(DEFAULT_CATEGORY_ID.to_string());
+            let result = sut.use_case.delete(&owner(), &sut.initial_category.id.to_string(), strategy, None).await;
+
+            assert!(result.is_ok());
+
+            let video = sut.videos_repository.find_by_id(video.id).await.unwrap();
+            assert_eq!(video.category_id, default_category.id);
+            assert_eq!(video.updated_at, NOW + Duration::minutes(5));
+        }
+    }
+    #[cfg(test)]
+    mod test_restore {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_restore_a_deleted_category() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+            sut.use_case.delete(&owner(), &id, DeleteCategoryStrategy::Reject, None).await.unwrap();
+
+            let category = sut.use_case.restore(&owner(), &id).await.unwrap();
+
+            assert!(!category.is_deleted());
+            assert!(sut.use_case.find_by_id(&id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_purge_a_category_with_its_deleted_videos() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+            add_video(&sut, &sut.initial_category).await;
+            sut.use_case.delete(&owner(), &id, DeleteCategoryStrategy::Cascade, None).await.unwrap();
+
+            let result = sut.use_case.purge(&admin(), &id).await;
+
+            assert!(result.is_ok());
+            assert!(sut.videos_repository.videos.read().await.is_empty());
+            assert!(sut.categories_repository.categories.read().await.is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_purge_a_category_that_still_has_videos() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+            sut.use_case.delete(&owner(), &id, DeleteCategoryStrategy::Reject, None).await.unwrap();
+            add_video(&sut, &sut.initial_category).await;
+
+            let result = sut.use_case.purge(&admin(), &id).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::CategoryHasVideos));
+            assert_eq!(sut.categories_repository.categories.read().await.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_only_let_an_admin_purge_a_category() {
+            let sut = setup_sut().await;
+            let id = sut.initial_category.id.to_string();
+            sut.use_case.delete(&owner(), &id, DeleteCategoryStrategy::Reject, None).await.unwrap();
+
+            let result = sut.use_case.purge(&owner(), &id).await;
+
+            assert!(matches!(result.unwrap_err(), CategoriesUseCaseError::Forbidden));
+        }
+    }
+}
@@ -1,2 +1,4 @@
 mod authentication;
 mod videos;
+mod categories;
+mod users;mod retention;
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod test_retention_use_case {
+    use std::sync::Arc;
+    use time::{Duration, OffsetDateTime};
+    use time::macros::datetime;
+    use crate::application::repositories::Repository;
+    use crate::application::usecases::retention::{PurgeSummary, RetentionPolicy, RetentionUseCase};
+    use crate::domain::entities::categories::{Categories, CategoriesInput};
+    use crate::domain::entities::users::{Users, UsersInput};
+    use crate::domain::entities::videos::{Videos, VideosInput};
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::persistence::in_memory::categories::CategoriesRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::unit_of_work::UnitOfWorkInMemory;
+    use crate::infrastructure::persistence::in_memory::users::UsersRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::videos::VideosRepositoryInMemory;
+
+    const USER_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a8";
+    const OTHER_USER_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a9";
+    const CATEGORY_ID: &str = "018b33b7-5b9a-72a7-942f-8c46275aeacd";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    struct Sut {
+        users_repository: Arc<UsersRepositoryInMemory>,
+        videos_repository: Arc<VideosRepositoryInMemory>,
+        categories_repository: Arc<CategoriesRepositoryInMemory>,
+        clock: Arc<FixedClock>,
+        use_case: RetentionUseCase,
+    }
+
+    fn deleted_days_ago(days: i64) -> Option<OffsetDateTime> {
+        Some(NOW - Duration::days(days))
+    }
+
+    fn video(deleted_at: Option<OffsetDateTime>) -> Videos {
+        let mut video = Videos::new(&VideosInput {
+            title: "Video title".to_string(),
+            description: "Video description".to_string(),
+            url: "https://www.youtube.com/watch?v=6n3pFFPSlW4".to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+
+        video.deleted_at = deleted_at;
+        video
+    }
+
+    fn setup_sut() -> Sut {
+        let clock = Arc::new(FixedClock::new(NOW));
+        let users_repository = Arc::new(UsersRepositoryInMemory::new());
+        let videos_repository = Arc::new(VideosRepositoryInMemory::new());
+        let categories_repository = Arc::new(CategoriesRepositoryInMemory::new());
+
+        let unit_of_work = Arc::new(UnitOfWorkInMemory::new()
+            .with_users(users_repository.clone())
+            .with_videos(videos_repository.clone())
+            .with_categories(categories_repository.clone()));
+
+        let policy = RetentionPolicy {
+            retention: Duration::days(30),
+            interval: Duration::hours(1),
+        };
+
+        Sut {
+            users_repository,
+            videos_repository,
+            categories_repository,
+            clock: clock.clone(),
+            use_case: RetentionUseCase::new(unit_of_work, policy, clock),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_purge_videos_deleted_before_the_retention_period() {
+        let sut = setup_sut();
+        let recent = video(deleted_days_ago(1));
+        let live = video(None);
+
+        sut.videos_repository.save(video(deleted_days_ago(31))).await.unwrap();
+        sut.videos_repository.save(recent.clone()).await.unwrap();
+        sut.videos_repository.save(live.clone()).await.unwrap();
+
+        let summary = sut.use_case.purge_expired().await.unwrap();
+
+        assert_eq!(summary, PurgeSummary { videos: 1, categories: 0, users: 0 });
+
+        let ids: Vec<_> = sut.videos_repository.videos.read().await.iter().map(|video| video.id).collect();
+        assert_eq!(ids, vec![recent.id, live.id]);
+    }
+
+    #[tokio::test]
+    async fn it_should_purge_categories_and_users_deleted_before_the_retention_period() {
+        let sut = setup_sut();
+
+        let mut category = Categories::new(&CategoriesInput {
+            name: "Old category".to_string(),
+            color: "#000000".to_string(),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+        category.deleted_at = deleted_days_ago(45);
+
+        let mut user = Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: "doejoe@test.com".to_string(),
+            password: "12345678".to_string(),
+        }, NOW).unwrap();
+        user.deleted_at = deleted_days_ago(45);
+
+        sut.categories_repository.save(category).await.unwrap();
+        sut.users_repository.save(user).await.unwrap();
+
+        let summary = sut.use_case.purge_expired().await.unwrap();
+
+        assert_eq!(summary.total(), 2);
+        assert!(sut.categories_repository.categories.read().await.is_empty());
+        assert!(sut.users_repository.users.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_erase_the_content_of_an_expired_user_before_purging_it() {
+        let sut = setup_sut();
+
+        let mut user = Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: "doejoe@test.com".to_string(),
+            password: "12345678".to_string(),
+        }, NOW).unwrap();
+        user.deleted_at = deleted_days_ago(45);
+
+        let category = Categories::new(&CategoriesInput {
+            name: "Owned category".to_string(),
+            color: "#000000".to_string(),
+            user_id: user.id.to_string(),
+        }, NOW).unwrap();
+
+        let mut owned = video(deleted_days_ago(1));
+        owned.user_id = user.id;
+        owned.category_id = category.id;
+
+        let mut foreign = video(None);
+        foreign.user_id = OTHER_USER_ID.parse().unwrap();
+        foreign.category_id = category.id;
+
+        sut.users_repository.save(user).await.unwrap();
+        sut.categories_repository.save(category).await.unwrap();
+        sut.videos_repository.save(owned).await.unwrap();
+        sut.videos_repository.save(foreign.clone()).await.unwrap();
+
+        let summary = sut.use_case.purge_expired().await.unwrap();
+
+        assert_eq!(summary, PurgeSummary { videos: 1, categories: 1, users: 1 });
+        assert!(sut.users_repository.users.read().await.is_empty());
+        assert!(sut.categories_repository.categories.read().await.is_empty());
+
+        let videos = sut.videos_repository.videos.read().await;
+        assert_eq!(videos.len(), 1);
+        assert_eq!(videos[0].id, foreign.id);
+        assert_eq!(videos[0].category_id, Categories::default_id());
+    }
+
+    #[tokio::test]
+    async fn it_should_purge_a_video_once_the_retention_period_elapses() {
+        let sut = setup_sut();
+
+        sut.videos_repository.save(video(deleted_days_ago(29))).await.unwrap();
+
+        assert_eq!(sut.use_case.purge_expired().await.unwrap().total(), 0);
+
+        sut.clock.advance(Duration::days(2));
+
+        assert_eq!(sut.use_case.purge_expired().await.unwrap().videos, 1);
+        assert!(sut.videos_repository.videos.read().await.is_empty());
+    }
+}
@@ -0,0 +1,427 @@
+#[cfg(test)]
+mod test_users_use_case {
+    use std::sync::Arc;
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+    use crate::application::identity::CurrentUser;
+    use crate::application::repositories::{Repository, SoftDeleteRepository};
+    use crate::application::repositories::users::UsersRepository;
+    use crate::application::usecases::users::{ChangeRoleInput, UsersUseCase, UsersUseCaseError};
+    use crate::domain::entities::categories::{Categories, CategoriesInput};
+    use crate::domain::entities::refresh_tokens::RefreshTokens;
+    use crate::domain::entities::users::{Users, UsersInput, SYSTEM_USER_ID};
+    use crate::domain::entities::videos::{Videos, VideosInput};
+    use crate::domain::value_objects::role::Role;
+    use crate::domain::value_objects::ValueObjectTrait;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::persistence::in_memory::categories::CategoriesRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::refresh_tokens::RefreshTokensRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::unit_of_work::UnitOfWorkInMemory;
+    use crate::infrastructure::persistence::in_memory::users::UsersRepositoryInMemory;
+    use crate::infrastructure::persistence::in_memory::videos::VideosRepositoryInMemory;
+
+    const ADMIN_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a8";
+    const UNKNOWN_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a9";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    struct Sut {
+        users_repository: Arc<UsersRepositoryInMemory>,
+        refresh_tokens_repository: Arc<RefreshTokensRepositoryInMemory>,
+        videos_repository: Arc<VideosRepositoryInMemory>,
+        categories_repository: Arc<CategoriesRepositoryInMemory>,
+        clock: Arc<FixedClock>,
+        use_case: UsersUseCase,
+        initial_user: Users,
+    }
+
+    fn admin() -> CurrentUser {
+        CurrentUser::new(ADMIN_ID.parse().unwrap(), Role::Admin)
+    }
+
+    fn role_input(role: &str) -> ChangeRoleInput {
+        ChangeRoleInput { role: role.to_string() }
+    }
+
+    async fn setup_sut() -> Sut {
+        let clock = Arc::new(FixedClock::new(NOW));
+        let users_repository = Arc::new(UsersRepositoryInMemory::new().with_clock(clock.clone()));
+
+        let initial_user = Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: "doejoe@test.com".to_string(),
+            password: "12345678".to_string(),
+        }, NOW).unwrap();
+
+        users_repository.save(initial_user.clone()).await.unwrap();
+
+        let refresh_tokens_repository = Arc::new(RefreshTokensRepositoryInMemory::new().with_clock(clock.clone()));
+        let videos_repository = Arc::new(VideosRepositoryInMemory::new().with_clock(clock.clone()));
+        let categories_repository = Arc::new(CategoriesRepositoryInMemory::new().with_clock(clock.clone()));
+
+        categories_repository.save(Categories::default_category(NOW)).await.unwrap();
+
+        let unit_of_work = Arc::new(UnitOfWorkInMemory::new()
+            .with_users(users_repository.clone())
+            .with_refresh_tokens(refresh_tokens_repository.clone())
+            .with_videos(videos_repository.clone())
+            .with_categories(categories_repository.clone()));
+
+        let use_case = UsersUseCase::new(users_repository.clone(), unit_of_work, clock.clone());
+
+        Sut {
+            users_repository,
+            refresh_tokens_repository,
+            videos_repository,
+            categories_repository,
+            clock,
+            use_case,
+            initial_user,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_sign_up_users_as_creators() {
+        let sut = setup_sut().await;
+
+        assert_eq!(sut.initial_user.role.value(), &Role::Creator);
+    }
+
+    #[tokio::test]
+    async fn it_should_let_an_admin_promote_a_user() {
+        let sut = setup_sut().await;
+        let id = sut.initial_user.id.to_string();
+
+        let user = sut.use_case.change_role(&admin(), &id, role_input("moderator"), None).await.unwrap();
+
+        assert_eq!(user.role.value(), &Role::Moderator);
+
+        let stored = sut.users_repository.find_by_id(sut.initial_user.id).await.unwrap();
+        assert_eq!(stored.role.value(), &Role::Moderator);
+    }
+
+    #[tokio::test]
+    async fn it_should_let_an_admin_demote_a_user() {
+        let sut = setup_sut().await;
+        let id = sut.initial_user.id.to_string();
+
+        let user = sut.use_case.change_role(&admin(), &id, role_input("viewer"), None).await.unwrap();
+
+        assert_eq!(user.role.value(), &Role::Viewer);
+    }
+
+    #[tokio::test]
+    async fn it_should_revoke_the_sessions_of_a_user_whose_role_changed() {
+        let sut = setup_sut().await;
+        let (refresh_token, _) = RefreshTokens::new(sut.initial_user.id, UNKNOWN_ID.parse().unwrap(), time::Duration::days(1), NOW);
+        sut.refresh_tokens_repository.save(refresh_token).await.unwrap();
+
+        sut.use_case.change_role(&admin(), &sut.initial_user.id.to_string(), role_input("viewer"), None).await.unwrap();
+
+        assert!(sut.refresh_tokens_repository.refresh_tokens.read().await.iter().all(|token| token.revoked_at.is_some()));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_change_the_role_of_the_system_user() {
+        let sut = setup_sut().await;
+
+        let result = sut.use_case.change_role(&admin(), SYSTEM_USER_ID, role_input("admin"), None).await;
+
+        assert!(matches!(result.unwrap_err(), UsersUseCaseError::UserNotFound));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_let_a_moderator_change_roles() {
+        let sut = setup_sut().await;
+        let moderator = CurrentUser::new(ADMIN_ID.parse().unwrap(), Role::Moderator);
+
+        let result = sut.use_case.change_role(&moderator, &sut.initial_user.id.to_string(), role_input("admin"), None).await;
+
+        assert!(matches!(result.unwrap_err(), UsersUseCaseError::Forbidden));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_let_an_admin_change_their_own_role() {
+        let sut = setup_sut().await;
+
+        let result = sut.use_case.change_role(&admin(), ADMIN_ID, role_input("viewer"), None).await;
+
+        assert!(matches!(result.unwrap_err(), UsersUseCaseError::OwnRoleChange));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_assign_an_unknown_role() {
+        let sut = setup_sut().await;
+
+        let result = sut.use_case.change_role(&admin(), &sut.initial_user.id.to_string(), role_input("owner"), None).await;
+
+        assert!(matches!(result.unwrap_err(), UsersUseCaseError::Domain(_)));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_change_the_role_of_a_user_that_does_not_exist() {
+        let sut = setup_sut().await;
+
+        let result = sut.use_case.change_role(&admin(), UNKNOWN_ID, role_input("viewer"), None).await;
+
+        assert!(matches!(result.unwrap_err(), UsersUseCaseError::UserNotFound));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_change_the_role_with_a_stale_expected_version() {
+        let sut = setup_sut().await;
+        let id = sut.initial_user.id.to_string();
+
+        sut.use_case.change_role(&admin(), &id, role_input("moderator"), Some(1)).await.unwrap();
+        let result = sut.use_case.change_role(&admin(), &id, role_input("viewer"), Some(1)).await;
+
+        assert!(matches!(result.unwrap_err(), UsersUseCaseError::PreconditionFailed));
+
+        let stored = sut.users_repository.find_by_id(sut.initial_user.id).await.unwrap();
+        assert_eq!(stored.role.value(), &Role::Moderator);
+        assert_eq!(stored.version, 2);
+    }
+
+    #[cfg(test)]
+    mod test_assign_role {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_assign_a_role_by_email_and_revoke_the_sessions() {
+            let sut = setup_sut().await;
+            let (refresh_token, _) = RefreshTokens::new(sut.initial_user.id, UNKNOWN_ID.parse().unwrap(), time::Duration::days(1), NOW);
+            sut.refresh_tokens_repository.save(refresh_token).await.unwrap();
+
+            let user = sut.use_case.assign_role("doejoe@test.com", role_input("admin")).await.unwrap();
+
+            assert_eq!(user.role.value(), &Role::Admin);
+            assert_eq!(user.updated_at, NOW);
+            assert!(sut.refresh_tokens_repository.refresh_tokens.read().await.iter().all(|token| token.revoked_at.is_some()));
+
+            let stored = sut.users_repository.find_by_id(sut.initial_user.id).await.unwrap();
+            assert_eq!(stored.role.value(), &Role::Admin);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_assign_a_role_to_an_unknown_email() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.assign_role("nobody@test.com", role_input("admin")).await;
+
+            assert!(matches!(result.unwrap_err(), UsersUseCaseError::UserNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_assign_a_role_to_the_system_user() {
+            let sut = setup_sut().await;
+            let mut system = Users::new(&UsersInput {
+                name: "AluraFlix".to_string(),
+                email: "system@aluraflix.local".to_string(),
+                password: "12345678".to_string(),
+            }, NOW).unwrap();
+            system.id = SYSTEM_USER_ID.parse().unwrap();
+            sut.users_repository.save(system).await.unwrap();
+
+            let result = sut.use_case.assign_role("system@aluraflix.local", role_input("admin")).await;
+
+            assert!(matches!(result.unwrap_err(), UsersUseCaseError::UserNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_assign_an_unknown_role_by_email() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.assign_role("doejoe@test.com", role_input("owner")).await;
+
+            assert!(matches!(result.unwrap_err(), UsersUseCaseError::Domain(_)));
+
+            let stored = sut.users_repository.find_by_id(sut.initial_user.id).await.unwrap();
+            assert_eq!(stored.role.value(), &Role::Creator);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_delete {
+        use super::*;
+
+        fn owner(sut: &Sut) -> CurrentUser {
+            CurrentUser::new(sut.initial_user.id, Role::Creator)
+        }
+
+        async fn seed_content(sut: &Sut) -> (Categories, Videos, Videos) {
+            let category = Categories::new(&CategoriesInput {
+                name: "Owned category".to_string(),
+                color: "#000000".to_string(),
+                user_id: sut.initial_user.id.to_string(),
+            }, NOW).unwrap();
+
+            let video = |user_id: String| Videos::new(&VideosInput {
+                title: "Video title".to_string(),
+                description: "Video description".to_string(),
+                url: "https://www.youtube.com/watch?v=6n3pFFPSlW4".to_string(),
+                category_id: Some(category.id.to_string()),
+                user_id,
+            }, NOW).unwrap();
+
+            let owned = video(sut.initial_user.id.to_string());
+            let foreign = video(UNKNOWN_ID.to_string());
+
+            sut.categories_repository.save(category.clone()).await.unwrap();
+            sut.videos_repository.save(owned.clone()).await.unwrap();
+            sut.videos_repository.save(foreign.clone()).await.unwrap();
+
+            (category, owned, foreign)
+        }
+
+        #[tokio::test]
+        async fn it_should_let_a_user_delete_their_account_and_revoke_their_sessions() {
+            let sut = setup_sut().await;
+            let (refresh_token, _) = RefreshTokens::new(sut.initial_user.id, UNKNOWN_ID.parse().unwrap(), time::Duration::days(1), NOW);
+            sut.refresh_tokens_repository.save(refresh_token).await.unwrap();
+
+            let result = sut.use_case.delete(&owner(&sut), &sut.initial_user.id.to_string(), None).await;
+
+            assert!(result.is_ok());
+            assert!(sut.users_repository.find_by_id(sut.initial_user.id).await.is_err());
+            assert!(sut.users_repository.find_by_email(sut.initial_user.email.clone()).await.unwrap().is_none());
+            assert!(sut.refresh_tokens_repository.refresh_tokens.read().await.iter().all(|token| token.revoked_at.is_some()));
+        }
+
+        #[tokio::test]
+        async fn it_should_soft_delete_the_content_of_a_deleted_user() {
+            let sut = setup_sut().await;
+            let (category, owned, foreign) = seed_content(&sut).await;
+
+            sut.use_case.delete(&owner(&sut), &sut.initial_user.id.to_string(), None).await.unwrap();
+
+            assert!(sut.categories_repository.find_deleted_by_id(category.id).await.is_ok());
+            assert!(sut.videos_repository.find_deleted_by_id(owned.id).await.is_ok());
+
+            let foreign = sut.videos_repository.find_by_id(foreign.id).await.unwrap();
+            assert_eq!(foreign.category_id, Categories::default_id());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_user_with_a_stale_expected_version() {
+            let sut = setup_sut().await;
+            let id = sut.initial_user.id.to_string();
+
+            sut.use_case.change_role(&admin(), &id, role_input("moderator"), None).await.unwrap();
+            let result = sut.use_case.delete(&admin(), &id, Some(1)).await;
+
+            assert!(matches!(result.unwrap_err(), UsersUseCaseError::PreconditionFailed));
+            assert!(sut.users_repository.find_by_id(sut.initial_user.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_delete_a_user_with_the_current_expected_version() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.delete(&admin(), &sut.initial_user.id.to_string(), Some(1)).await;
+
+            assert!(result.is_ok());
+            assert!(sut.users_repository.find_deleted_by_id(sut.initial_user.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_let_a_user_delete_another_account() {
+            let sut = setup_sut().await;
+            let stranger = CurrentUser::new(UNKNOWN_ID.parse().unwrap(), Role::Moderator);
+
+            let result = sut.use_case.delete(&stranger, &sut.initial_user.id.to_string(), None).await;
+
+            assert!(matches!(result.unwrap_err(), UsersUseCaseError::Forbidden));
+        }
+
+        #[tokio::test]
+        async fn it_should_let_an_admin_restore_a_deleted_user() {
+            let sut = setup_sut().await;
+            let id = sut.initial_user.id.to_string();
+            sut.use_case.delete(&admin(), &id, None).await.unwrap();
+
+            let user = sut.use_case.restore(&admin(), &id).await.unwrap();
+
+            assert!(!user.is_deleted());
+            assert!(sut.users_repository.find_by_id(sut.initial_user.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_the_content_deleted_with_the_account() {
+            let sut = setup_sut().await;
+            let (category, owned, _) = seed_content(&sut).await;
+            let id = sut.initial_user.id.to_string();
+            sut.use_case.delete(&admin(), &id, None).await.unwrap();
+
+            sut.use_case.restore(&admin(), &id).await.unwrap();
+
+            assert!(sut.categories_repository.find_by_id(category.id).await.is_ok());
+            assert!(sut.videos_repository.find_by_id(owned.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_restore_content_deleted_before_the_account() {
+            let sut = setup_sut().await;
+            let (category, owned, _) = seed_content(&sut).await;
+            let id = sut.initial_user.id.to_string();
+            assert!(sut.videos_repository.delete(owned.id).await.is_none());
+
+            sut.clock.advance(time::Duration::hours(1));
+            sut.use_case.delete(&admin(), &id, None).await.unwrap();
+            sut.use_case.restore(&admin(), &id).await.unwrap();
+
+            assert!(sut.categories_repository.find_by_id(category.id).await.is_ok());
+            assert!(sut.videos_repository.find_deleted_by_id(owned.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_let_a_user_restore_their_own_account() {
+            let sut = setup_sut().await;
+            let id = sut.initial_user.id.to_string();
+            sut.use_case.delete(&owner(&sut), &id, None).await.unwrap();
+
+            let result = sut.use_case.restore(&owner(&sut), &id).await;
+
+            assert!(matches!(result.unwrap_err(), UsersUseCaseError::Forbidden));
+        }
+
+        #[tokio::test]
+        async fn it_should_let_an_admin_purge_a_deleted_user() {
+            let sut = setup_sut().await;
+            let id = sut.initial_user.id.to_string();
+            sut.use_case.delete(&owner(&sut), &id, None).await.unwrap();
+
+            let result = sut.use_case.purge(&admin(), &id).await;
+
+            assert!(result.is_ok());
+            assert!(sut.users_repository.users.read().await.is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_erase_the_content_of_a_purged_user() {
+            let sut = setup_sut().await;
+            let (_, _, foreign) = seed_content(&sut).await;
+            let id = sut.initial_user.id.to_string();
+            sut.use_case.delete(&owner(&sut), &id, None).await.unwrap();
+
+            sut.use_case.purge(&admin(), &id).await.unwrap();
+
+            let categories = sut.categories_repository.categories.read().await;
+            assert!(categories.iter().all(|category| category.is_default()));
+
+            let videos = sut.videos_repository.videos.read().await;
+            assert_eq!(videos.len(), 1);
+            assert_eq!(videos[0].id, foreign.id);
+            assert_eq!(videos[0].category_id, Categories::default_id());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_purge_a_user_that_is_not_deleted() {
+            let sut = setup_sut().await;
+            seed_content(&sut).await;
+
+            let result = sut.use_case.purge(&admin(), &sut.initial_user.id.to_string()).await;
+
+            assert!(matches!(result.unwrap_err(), UsersUseCaseError::UserNotFound));
+            assert_eq!(sut.videos_repository.videos.read().await.len(), 2);
+        }
+    }
+}
@@ -1,21 +1,84 @@
 #[cfg(test)]
 mod test_videos_use_case {
-    use std::sync::{Arc, Mutex};
-    use crate::application::usecases::videos::VideosUseCase;
+    use std::sync::Arc;
+    use axum::http::StatusCode;
+    use time::{Duration, OffsetDateTime};
+    use time::macros::datetime;
+    use crate::application::identity::CurrentUser;
+    use crate::application::repositories::{Repository, SoftDeleteRepository};
+    use crate::application::repositories::pagination::{PageRequest, SortDirection};
+    use crate::application::usecases::videos::{VideosUseCase, VideosUseCaseError};
+    use crate::domain::errors::app_error::AppError;
+    use crate::domain::entities::categories::{Categories, CategoriesInput, DEFAULT_CATEGORY_ID};
+    use crate::domain::entities::videos::{Videos, VideosInput, VideosPatchInput};
+    use crate::domain::value_objects::role::Role;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::persistence::in_memory::categories::CategoriesRepositoryInMemory;
     use crate::infrastructure::persistence::in_memory::videos::VideosRepositoryInMemory;
 
+    const URL: &str = "https://www.youtube.com/watch?v=6n3pFFPSlW4";
+    const CATEGORY_ID: &str = "018b33b7-5b9a-72a7-942f-8c46275aeacd";
+    const USER_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a8";
+    const UNKNOWN_ID: &str = "018b33b7-5b9a-72a7-942f-8c46275aeace";
+    const OTHER_USER_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a9";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
     struct Sut {
-        videos_repository: Arc<Mutex<VideosRepositoryInMemory>>,
+        videos_repository: Arc<VideosRepositoryInMemory>,
+        categories_repository: Arc<CategoriesRepositoryInMemory>,
+        clock: Arc<FixedClock>,
         use_case: VideosUseCase,
+        initial_video: Videos,
+    }
+
+    fn video_input(title: &str) -> VideosInput {
+        VideosInput {
+            title: title.to_string(),
+            description: "Description".to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        }
+    }
+
+    fn owner() -> CurrentUser {
+        CurrentUser::new(USER_ID.parse().unwrap(), Role::Creator)
+    }
+
+    fn stranger() -> CurrentUser {
+        CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Creator)
     }
 
     async fn setup_sut() -> Sut {
-        let videos_repository = Arc::new(Mutex::new(VideosRepositoryInMemory::new()));
-        let use_case = VideosUseCase::new(videos_repository.clone());
+        let clock = Arc::new(FixedClock::new(NOW));
+        let videos_repository = Arc::new(VideosRepositoryInMemory::new().with_clock(clock.clone()));
+        let categories_repository = Arc::new(CategoriesRepositoryInMemory::new().with_clock(clock.clone()));
+
+        let mut category = Categories::new(&CategoriesInput {
+            name: "Category".to_string(),
+            color: "#000000".to_string(),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+        category.id = CATEGORY_ID.parse().unwrap();
+
+        categories_repository.save(category).await.unwrap();
+        categories_repository.save(Categories::default_category(NOW)).await.unwrap();
+
+        let initial_video = Videos::new(&video_input("Initial video"), NOW).unwrap();
+
+        videos_repository
+            .save(initial_video.clone())
+            .await
+            .unwrap();
+
+        let use_case = VideosUseCase::new(videos_repository.clone(), categories_repository.clone(), clock.clone());
 
         Sut {
             videos_repository,
+            categories_repository,
+            clock,
             use_case,
+            initial_video,
         }
     }
 
@@ -26,4 +89,714 @@ mod test_videos_use_case {
             assert_eq!(1 + 1, 2);
         }
     }
-}
\ No newline at end of file
+
+    #[cfg(test)]
+    mod test_create {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_create_a_video() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.create(&owner(), video_input("New video")).await;
+
+            assert!(result.is_ok());
+            assert_eq!(sut.videos_repository.videos.read().await.len(), 2);
+        }
+
+        #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+        async fn it_should_create_videos_concurrently() {
+            let sut = setup_sut().await;
+            let use_case = Arc::new(sut.use_case);
+
+            let handles: Vec<_> = (0..10)
+                .map(|index| {
+                    let use_case = use_case.clone();
+
+                    tokio::spawn(async move { use_case.create(&owner(), video_input(&format!("Video {}", index))).await })
+                })
+                .collect();
+
+            for handle in handles {
+                assert!(handle.await.unwrap().is_ok());
+            }
+
+            assert_eq!(sut.videos_repository.videos.read().await.len(), 11);
+        }
+
+        #[tokio::test]
+        async fn it_should_create_a_video_owned_by_the_current_user() {
+            let sut = setup_sut().await;
+            let mut input = video_input("New video");
+            input.user_id = UNKNOWN_ID.to_string();
+            input.category_id = None;
+
+            let video = sut.use_case.create(&stranger(), input).await.unwrap();
+
+            assert_eq!(video.user_id.to_string(), OTHER_USER_ID);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_create_a_video_in_a_category_owned_by_another_user() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.create(&stranger(), video_input("New video")).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Forbidden));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_create_a_video_in_a_category_that_does_not_exist() {
+            let sut = setup_sut().await;
+            let mut input = video_input("New video");
+            input.category_id = Some(UNKNOWN_ID.to_string());
+
+            let result = sut.use_case.create(&owner(), input).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(error) if error.message == "Invalid category"));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_create_a_video_in_a_deleted_category() {
+            let sut = setup_sut().await;
+            sut.categories_repository.delete(CATEGORY_ID.parse().unwrap()).await;
+
+            let result = sut.use_case.create(&owner(), video_input("New video")).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(_)));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_let_a_viewer_create_a_video() {
+            let sut = setup_sut().await;
+            let viewer = CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Viewer);
+
+            let result = sut.use_case.create(&viewer, video_input("New video")).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Forbidden));
+        }
+
+        #[tokio::test]
+        async fn it_should_create_a_video_without_a_category_in_the_default_category() {
+            let sut = setup_sut().await;
+            let mut input = video_input("Uncategorized video");
+            input.category_id = None;
+
+            let video = sut.use_case.create(&owner(), input).await.unwrap();
+
+            assert_eq!(video.category_id.to_string(), DEFAULT_CATEGORY_ID);
+        }
+
+        #[tokio::test]
+        async fn it_should_create_a_video_with_a_blank_category_in_the_default_category() {
+            let sut = setup_sut().await;
+            let mut input = video_input("Uncategorized video");
+            input.category_id = Some(" ".to_string());
+
+            let video = sut.use_case.create(&owner(), input).await.unwrap();
+
+            assert_eq!(video.category_id.to_string(), DEFAULT_CATEGORY_ID);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_create_a_video_with_invalid_data() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.create(&owner(), video_input("a")).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(_)));
+            assert_eq!(sut.videos_repository.videos.read().await.len(), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_find {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_find_a_video_by_id() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_by_id(&sut.initial_video.id.to_string()).await;
+
+            assert_eq!(result.unwrap().title, "Initial video");
+        }
+
+        #[tokio::test]
+        async fn it_should_not_find_a_video_that_does_not_exist() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_by_id(UNKNOWN_ID).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::VideosNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_find_a_video_with_an_invalid_id() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_by_id("invalid").await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::VideosNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_list_all_videos() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.find_all(PageRequest::default()).await;
+
+            assert_eq!(result.unwrap().items.len(), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod test_find_all {
+        use super::*;
+
+        async fn add_videos(sut: &Sut, count: usize) {
+            for index in 0..count {
+                sut.use_case.create(&owner(), video_input(&format!("Video {:02}", index))).await.unwrap();
+            }
+        }
+
+        #[tokio::test]
+        async fn it_should_paginate_more_than_ten_videos() {
+            let sut = setup_sut().await;
+            add_videos(&sut, 14).await;
+
+            let page = sut.use_case.find_all(PageRequest::new(2, 5)).await.unwrap();
+
+            assert_eq!(page.total, 15);
+            assert_eq!(page.page, 2);
+            assert_eq!(page.items.len(), 5);
+            assert!(page.next_cursor.is_some());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_return_a_cursor_on_the_last_page() {
+            let sut = setup_sut().await;
+            add_videos(&sut, 3).await;
+
+            let page = sut.use_case.find_all(PageRequest::new(1, 4)).await.unwrap();
+
+            assert_eq!(page.items.len(), 4);
+            assert!(page.next_cursor.is_none());
+        }
+
+        #[tokio::test]
+        async fn it_should_walk_every_video_with_cursors() {
+            let sut = setup_sut().await;
+            add_videos(&sut, 11).await;
+
+            let mut titles = vec![];
+            let mut cursor = None;
+
+            loop {
+                let request = PageRequest::new(1, 5).sort_by("title", SortDirection::Desc).with_cursor(cursor);
+                let page = sut.use_case.find_all(request).await.unwrap();
+
+                titles.extend(page.items.into_iter().map(|video| video.title));
+
+                match page.next_cursor {
+                    Some(next) => cursor = Some(next),
+                    None => break,
+                }
+            }
+
+            let mut expected = titles.clone();
+            expected.sort();
+            expected.reverse();
+
+            assert_eq!(titles.len(), 12);
+            assert_eq!(titles, expected);
+        }
+
+        #[tokio::test]
+        async fn it_should_sort_timestamps_chronologically_across_fractional_seconds() {
+            let sut = setup_sut().await;
+
+            for (title, at) in [("Fractional", datetime!(2023-10-20 12:00:05.5 UTC)), ("Whole", datetime!(2023-10-20 12:00:05 UTC))] {
+                sut.clock.set(at);
+                sut.use_case.create(&owner(), video_input(title)).await.unwrap();
+            }
+
+            let mut titles = vec![];
+            let mut cursor = None;
+
+            loop {
+                let request = PageRequest::new(1, 1).sort_by("created_at", SortDirection::Asc).with_cursor(cursor);
+                let page = sut.use_case.find_all(request).await.unwrap();
+
+                titles.extend(page.items.into_iter().map(|video| video.title));
+
+                match page.next_cursor {
+                    Some(next) => cursor = Some(next),
+                    None => break,
+                }
+            }
+
+            assert_eq!(titles, vec!["Initial video", "Whole", "Fractional"]);
+        }
+
+        #[tokio::test]
+        async fn it_should_filter_videos() {
+            let sut = setup_sut().await;
+            add_videos(&sut, 2).await;
+
+            let request = PageRequest::default().filter("category_id", UNKNOWN_ID);
+            let page = sut.use_case.find_all(request).await.unwrap();
+
+            assert_eq!(page.total, 0);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_sort_by_an_unknown_field() {
+            let sut = setup_sut().await;
+
+            let request = PageRequest::default().sort_by("password", SortDirection::Asc);
+            let result = sut.use_case.find_all(request).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(_)));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_accept_an_invalid_cursor() {
+            let sut = setup_sut().await;
+
+            let request = PageRequest::default().with_cursor(Some("invalid".to_string()));
+            let result = sut.use_case.find_all(request).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(_)));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_search {
+        use super::*;
+
+        async fn add_video(sut: &Sut, title: &str, description: &str) {
+            let mut input = video_input(title);
+            input.description = description.to_string();
+
+            sut.use_case.create(&owner(), input).await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn it_should_search_ignoring_case_and_accents() {
+            let sut = setup_sut().await;
+            add_video(&sut, "Introdução ao Rust", "Primeiros passos").await;
+            add_video(&sut, "Receitas de pão", "Cozinha caseira").await;
+
+            let page = sut.use_case.search("INTRODUCAO", PageRequest::default()).await.unwrap();
+
+            assert_eq!(page.total, 1);
+            assert_eq!(page.items[0].title, "Introdução ao Rust");
+        }
+
+        #[tokio::test]
+        async fn it_should_rank_title_matches_first() {
+            let sut = setup_sut().await;
+            add_video(&sut, "Cozinha japonesa", "Aprenda a fazer sushi").await;
+            add_video(&sut, "Sushi em casa", "Receita completa").await;
+
+            let page = sut.use_case.search("sushi", PageRequest::default()).await.unwrap();
+
+            assert_eq!(page.total, 2);
+            assert_eq!(page.items[0].title, "Sushi em casa");
+        }
+
+        #[tokio::test]
+        async fn it_should_require_every_term_to_match() {
+            let sut = setup_sut().await;
+            add_video(&sut, "Rust avançado", "Traits e lifetimes").await;
+            add_video(&sut, "Rust básico", "Variáveis e funções").await;
+
+            let page = sut.use_case.search("rust traits", PageRequest::default()).await.unwrap();
+
+            assert_eq!(page.total, 1);
+            assert_eq!(page.items[0].title, "Rust avançado");
+        }
+
+        #[tokio::test]
+        async fn it_should_paginate_search_results() {
+            let sut = setup_sut().await;
+
+            for index in 0..7 {
+                add_video(&sut, &format!("Aula {}", index), "Curso de Rust").await;
+            }
+
+            let page = sut.use_case.search("rust", PageRequest::new(2, 5)).await.unwrap();
+
+            assert_eq!(page.total, 7);
+            assert_eq!(page.items.len(), 2);
+            assert!(page.next_cursor.is_none());
+        }
+
+        #[tokio::test]
+        async fn it_should_list_all_videos_for_a_blank_search() {
+            let sut = setup_sut().await;
+
+            let page = sut.use_case.search("  ", PageRequest::default()).await.unwrap();
+
+            assert_eq!(page.total, 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_search_with_a_cursor() {
+            let sut = setup_sut().await;
+
+            let request = PageRequest::default().with_cursor(Some("cursor".to_string()));
+            let result = sut.use_case.search("video", request).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(_)));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_update {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_update_a_video() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let result = sut.use_case.update(&owner(), &id, video_input("Updated video"), None).await;
+
+            assert!(result.is_ok());
+            assert_eq!(sut.use_case.find_by_id(&id).await.unwrap().title, "Updated video");
+        }
+
+        #[tokio::test]
+        async fn it_should_move_a_video_to_the_default_category_when_updating_without_one() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+            let mut input = video_input("Updated video");
+            input.category_id = None;
+
+            let video = sut.use_case.update(&owner(), &id, input, None).await.unwrap();
+
+            assert_eq!(video.category_id.to_string(), DEFAULT_CATEGORY_ID);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_update_a_video_with_invalid_data() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let result = sut.use_case.update(&owner(), &id, video_input("a"), None).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(_)));
+            assert_eq!(sut.use_case.find_by_id(&id).await.unwrap().title, "Initial video");
+        }
+
+        #[tokio::test]
+        async fn it_should_not_update_a_video_that_does_not_exist() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.update(&owner(), UNKNOWN_ID, video_input("Updated video"), None).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::VideosNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_update_a_video_owned_by_another_user() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let result = sut.use_case.update(&stranger(), &id, video_input("Updated video"), None).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Forbidden));
+            assert_eq!(sut.use_case.find_by_id(&id).await.unwrap().title, "Initial video");
+        }
+
+        #[tokio::test]
+        async fn it_should_let_a_moderator_update_any_video() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+            let moderator = CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Moderator);
+
+            let video = sut.use_case.update(&moderator, &id, video_input("Moderated video"), None).await.unwrap();
+
+            assert_eq!(video.title, "Moderated video");
+            assert_eq!(video.user_id.to_string(), USER_ID);
+        }
+
+        #[tokio::test]
+        async fn it_should_patch_a_video() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let input = VideosPatchInput {
+                title: None,
+                description: Some("Patched description".to_string()),
+                url: None,
+                category_id: None,
+            };
+
+            let video = sut.use_case.patch(&owner(), &id, input, None).await.unwrap();
+
+            assert_eq!(video.title, "Initial video");
+            assert_eq!(video.description, "Patched description");
+        }
+
+        #[tokio::test]
+        async fn it_should_not_move_a_video_to_a_category_that_does_not_exist() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+            let mut input = video_input("Updated video");
+            input.category_id = Some(UNKNOWN_ID.to_string());
+
+            let result = sut.use_case.update(&owner(), &id, input, None).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Domain(_)));
+            assert_eq!(sut.use_case.find_by_id(&id).await.unwrap().title, "Initial video");
+        }
+
+        #[tokio::test]
+        async fn it_should_patch_a_blank_category_into_the_default_category() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let input = VideosPatchInput {
+                title: None,
+                description: None,
+                url: None,
+                category_id: Some("  ".to_string()),
+            };
+
+            let video = sut.use_case.patch(&owner(), &id, input, None).await.unwrap();
+
+            assert_eq!(video.category_id.to_string(), DEFAULT_CATEGORY_ID);
+        }
+
+        #[tokio::test]
+        async fn it_should_bump_updated_at_and_keep_created_at_on_update() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            sut.clock.advance(Duration::hours(3));
+            let video = sut.use_case.update(&owner(), &id, video_input("Updated video"), None).await.unwrap();
+
+            assert_eq!(video.created_at, NOW);
+            assert_eq!(video.updated_at, NOW + Duration::hours(3));
+        }
+
+        #[tokio::test]
+        async fn it_should_increment_the_version_on_every_update() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let video = sut.use_case.update(&owner(), &id, video_input("Updated video"), Some(1)).await.unwrap();
+
+            assert_eq!(video.version, 2);
+            assert_eq!(sut.use_case.find_by_id(&id).await.unwrap().version, 2);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_update_a_video_with_a_stale_expected_version() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            sut.use_case.update(&owner(), &id, video_input("First writer"), Some(1)).await.unwrap();
+            let result = sut.use_case.update(&owner(), &id, video_input("Second writer"), Some(1)).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::PreconditionFailed));
+            assert_eq!(sut.use_case.find_by_id(&id).await.unwrap().title, "First writer");
+        }
+
+        #[tokio::test]
+        async fn it_should_reject_a_repository_update_from_a_stale_entity() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            sut.use_case.update(&owner(), &id, video_input("First writer"), None).await.unwrap();
+            let result = sut.videos_repository.update(sut.initial_video.clone()).await;
+
+            assert!(matches!(VideosUseCaseError::from(result.unwrap_err()), VideosUseCaseError::Conflict));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_delete {
+        use super::*;
+
+        #[tokio::test]
+        async fn it_should_delete_a_video() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.delete(&owner(), &sut.initial_video.id.to_string(), None).await;
+
+            assert!(result.is_ok());
+            assert!(sut.videos_repository.videos.read().await[0].is_deleted());
+            assert!(sut.videos_repository.find_by_id(sut.initial_video.id).await.is_err());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_video_with_a_stale_expected_version() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            sut.use_case.update(&owner(), &id, video_input("First writer"), Some(1)).await.unwrap();
+            let result = sut.use_case.delete(&owner(), &id, Some(1)).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::PreconditionFailed));
+            assert!(sut.use_case.find_by_id(&id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_video_changed_after_it_was_read() {
+            let sut = setup_sut().await;
+
+            sut.use_case.update(&owner(), &sut.initial_video.id.to_string(), video_input("First writer"), None).await.unwrap();
+            let result = sut.videos_repository.delete_at_version(sut.initial_video.id, sut.initial_video.version).await;
+
+            assert!(matches!(VideosUseCaseError::from(result.unwrap()), VideosUseCaseError::Conflict));
+            assert!(sut.videos_repository.find_by_id(sut.initial_video.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_answer_conflicts_with_409_and_failed_preconditions_with_412() {
+            assert_eq!(AppError::from(VideosUseCaseError::Conflict).status(), StatusCode::CONFLICT);
+            assert_eq!(AppError::from(VideosUseCaseError::PreconditionFailed).status(), StatusCode::PRECONDITION_FAILED);
+        }
+
+        #[tokio::test]
+        async fn it_should_delete_a_video_with_the_current_expected_version() {
+            let sut = setup_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let result = sut.use_case.delete(&owner(), &id, Some(1)).await;
+
+            assert!(result.is_ok());
+            assert!(sut.use_case.find_by_id(&id).await.is_err());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_video_that_does_not_exist() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.delete(&owner(), UNKNOWN_ID, None).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::VideosNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_delete_a_video_owned_by_another_user() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.delete(&stranger(), &sut.initial_video.id.to_string(), None).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Forbidden));
+            assert_eq!(sut.videos_repository.videos.read().await.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_let_an_admin_delete_any_video() {
+            let sut = setup_sut().await;
+            let admin = CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Admin);
+
+            let result = sut.use_case.delete(&admin, &sut.initial_video.id.to_string(), None).await;
+
+            assert!(result.is_ok());
+        }
+    }
+    #[cfg(test)]
+    mod test_restore {
+        use super::*;
+
+        fn admin() -> CurrentUser {
+            CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Admin)
+        }
+
+        async fn setup_deleted_sut() -> Sut {
+            let sut = setup_sut().await;
+
+            sut.use_case.delete(&owner(), &sut.initial_video.id.to_string(), None).await.unwrap();
+
+            sut
+        }
+
+        #[tokio::test]
+        async fn it_should_hide_a_deleted_video() {
+            let sut = setup_deleted_sut().await;
+
+            let page = sut.use_case.find_all(PageRequest::default()).await.unwrap();
+            let search = sut.use_case.search("initial", PageRequest::default()).await.unwrap();
+
+            assert!(page.items.is_empty());
+            assert!(search.items.is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_restore_a_deleted_video() {
+            let sut = setup_deleted_sut().await;
+            let id = sut.initial_video.id.to_string();
+
+            let video = sut.use_case.restore(&owner(), &id).await.unwrap();
+
+            assert!(!video.is_deleted());
+            assert!(sut.use_case.find_by_id(&id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_restore_a_video_whose_category_is_deleted() {
+            let sut = setup_deleted_sut().await;
+            sut.categories_repository.delete(sut.initial_video.category_id).await;
+
+            let result = sut.use_case.restore(&owner(), &sut.initial_video.id.to_string()).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::CategoryDeleted));
+            assert!(sut.videos_repository.find_deleted_by_id(sut.initial_video.id).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn it_should_not_restore_a_video_owned_by_another_user() {
+            let sut = setup_deleted_sut().await;
+
+            let result = sut.use_case.restore(&stranger(), &sut.initial_video.id.to_string()).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Forbidden));
+        }
+
+        #[tokio::test]
+        async fn it_should_not_restore_a_video_that_is_not_deleted() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.restore(&owner(), &sut.initial_video.id.to_string()).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::VideosNotFound));
+        }
+
+        #[tokio::test]
+        async fn it_should_let_an_admin_purge_a_deleted_video() {
+            let sut = setup_deleted_sut().await;
+
+            let result = sut.use_case.purge(&admin(), &sut.initial_video.id.to_string()).await;
+
+            assert!(result.is_ok());
+            assert!(sut.videos_repository.videos.read().await.is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_only_let_an_admin_purge_a_video() {
+            let sut = setup_deleted_sut().await;
+            let moderator = CurrentUser::new(OTHER_USER_ID.parse().unwrap(), Role::Moderator);
+
+            let result = sut.use_case.purge(&moderator, &sut.initial_video.id.to_string()).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::Forbidden));
+            assert_eq!(sut.videos_repository.videos.read().await.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn it_should_not_purge_a_video_that_is_not_deleted() {
+            let sut = setup_sut().await;
+
+            let result = sut.use_case.purge(&admin(), &sut.initial_video.id.to_string()).await;
+
+            assert!(matches!(result.unwrap_err(), VideosUseCaseError::VideosNotFound));
+        }
+    }
+}
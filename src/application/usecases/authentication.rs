@@ -1,35 +1,135 @@
 use std::fmt::{Debug, Formatter};
+use std::net::IpAddr;
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use time::Duration;
+use crate::application::identity::CurrentUser;
+use crate::application::repositories::email_verifications::{EmailVerificationsRepository, EmailVerificationsRepositoryContract};
+use crate::application::repositories::password_resets::PasswordResetsRepositoryContract;
+use crate::application::repositories::refresh_tokens::{RefreshTokensRepository, RefreshTokensRepositoryContract};
+use crate::application::repositories::unit_of_work::{finish, Transaction, UnitOfWorkContract};
 use crate::application::repositories::RepositoryError;
-use crate::application::repositories::users::{UsersRepository, UsersRepositoryContract};
+use crate::application::repositories::users::UsersRepositoryContract;
+use crate::application::services::clock::ClockContract;
+use crate::application::services::login_throttle::{LoginAttemptKey, LoginThrottleContract};
+use crate::application::services::mailer::{Mail, MailerContract};
+use crate::application::services::tokens::{TokenError, TokenServiceContract};
+use crate::domain::entities::email_verifications::EmailVerifications;
+use crate::domain::entities::password_resets::PasswordResets;
+use crate::domain::entities::refresh_tokens::RefreshTokens;
 use crate::domain::entities::users::{Users, UsersInput};
 use crate::domain::errors::app_error::AppError;
 use crate::domain::errors::domain_error::DomainError;
 use crate::domain::value_objects::email::EmailEntity;
+use crate::domain::value_objects::password::PasswordEntity;
+use crate::domain::value_objects::ids::{TokenFamilyId, UserId};
 use crate::domain::value_objects::ValueObjectTrait;
 
 pub struct AuthUseCase {
-    pub users_repository: UsersRepositoryContract
+    pub users_repository: UsersRepositoryContract,
+    pub refresh_tokens_repository: RefreshTokensRepositoryContract,
+    pub email_verifications_repository: EmailVerificationsRepositoryContract,
+    pub password_resets_repository: PasswordResetsRepositoryContract,
+    pub unit_of_work: UnitOfWorkContract,
+    pub token_service: TokenServiceContract,
+    pub mailer: MailerContract,
+    pub login_throttle: LoginThrottleContract,
+    pub policy: AccountPolicy,
+    pub clock: ClockContract,
 }
 
+pub struct AccountPolicy {
+    pub email_verification_required: bool,
+    pub email_verification_ttl: Duration,
+    pub password_reset_ttl: Duration,
+    pub link_base_url: String,
+}
+
+#[derive(Deserialize)]
+pub struct VerifyEmailInput {
+    pub token: String,
+}
+
+#[derive(Deserialize)]
+pub struct ResendVerificationInput {
+    pub email: String,
+}
+
+#[derive(Deserialize)]
+pub struct RequestPasswordResetInput {
+    pub email: String,
+}
+
+#[derive(Deserialize)]
+pub struct ResetPasswordInput {
+    pub token: String,
+    pub password: String,
+}
+
+#[derive(Deserialize)]
+pub struct ChangePasswordInput {
+    pub current_password: String,
+    pub password: String,
+}
+
+#[derive(Deserialize)]
 pub struct SignInInput {
     pub email: String,
     pub password: String,
 }
 
+#[derive(Deserialize)]
+pub struct RefreshTokenInput {
+    pub refresh_token: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct AuthTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
 pub enum AuthUseCaseError {
     UserNotFound,
-    InvalidPassword,
+    InvalidCredentials,
+    TooManyAttempts(Duration),
     UserAlreadyExists,
+    InvalidRefreshToken,
+    InvalidAccessToken,
+    InvalidVerificationToken,
+    InvalidPasswordResetToken,
+    IncorrectCurrentPassword,
+    EmailNotVerified,
+    Conflict,
+    Token(TokenError),
+    Infrastructure(String),
     Domain(DomainError),
 }
 
 impl From<AuthUseCaseError> for AppError {
     fn from(error: AuthUseCaseError) -> Self {
         match error {
-            AuthUseCaseError::UserNotFound => AppError::new("User not found", 404, None),
-            AuthUseCaseError::InvalidPassword => AppError::new("Invalid password", 401, None),
-            AuthUseCaseError::UserAlreadyExists => AppError::new("User already exists", 400, None),
-            AuthUseCaseError::Domain(domain) => AppError::new("User domain error", 442, Some(domain))
+            AuthUseCaseError::UserNotFound => AppError::new("User not found", StatusCode::NOT_FOUND, None),
+            AuthUseCaseError::InvalidCredentials => AppError::new("Invalid credentials", StatusCode::UNAUTHORIZED, None),
+            AuthUseCaseError::TooManyAttempts(retry_after) => AppError::new("Too many sign-in attempts, try again later", StatusCode::TOO_MANY_REQUESTS, None)
+                .with_retry_after(retry_after.whole_seconds() + 1),
+            AuthUseCaseError::UserAlreadyExists => AppError::new("User already exists", StatusCode::BAD_REQUEST, None),
+            AuthUseCaseError::InvalidRefreshToken => AppError::new("Invalid refresh token", StatusCode::UNAUTHORIZED, None),
+            AuthUseCaseError::InvalidAccessToken => AppError::new("Invalid access token", StatusCode::UNAUTHORIZED, None),
+            AuthUseCaseError::InvalidVerificationToken => AppError::new("Invalid or expired verification token", StatusCode::BAD_REQUEST, None),
+            AuthUseCaseError::InvalidPasswordResetToken => AppError::new("Invalid or expired password reset token", StatusCode::BAD_REQUEST, None),
+            AuthUseCaseError::IncorrectCurrentPassword => AppError::new("Current password is incorrect", StatusCode::FORBIDDEN, None),
+            AuthUseCaseError::EmailNotVerified => AppError::new("Email not verified", StatusCode::FORBIDDEN, None),
+            AuthUseCaseError::Conflict => AppError::new("Account was modified by another request, try again", StatusCode::CONFLICT, None),
+            AuthUseCaseError::Token(error) => {
+                eprintln!("Token error: {:?}", error);
+
+                AppError::new("Internal server error", StatusCode::INTERNAL_SERVER_ERROR, None)
+            }
+            AuthUseCaseError::Infrastructure(_) => AppError::new("Internal server error", StatusCode::INTERNAL_SERVER_ERROR, None),
+            AuthUseCaseError::Domain(domain) => AppError::new("User domain error", StatusCode::UNPROCESSABLE_ENTITY, Some(domain))
         }
     }
 }
@@ -39,6 +139,8 @@ impl From<RepositoryError> for AuthUseCaseError {
         match error {
             RepositoryError::NotFound(_) => AuthUseCaseError::UserNotFound,
             RepositoryError::AlreadyExists(_) => AuthUseCaseError::UserAlreadyExists,
+            RepositoryError::Conflict(_) => AuthUseCaseError::Conflict,
+            RepositoryError::Infrastructure(message) => AuthUseCaseError::Infrastructure(message),
             RepositoryError::Domain(error) => AuthUseCaseError::Domain(error),
         }
     }
@@ -48,91 +150,423 @@ impl Debug for AuthUseCaseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             AuthUseCaseError::UserNotFound => write!(f, "User not found"),
-            AuthUseCaseError::InvalidPassword => write!(f, "Invalid password"),
+            AuthUseCaseError::InvalidCredentials => write!(f, "Invalid credentials"),
+            AuthUseCaseError::TooManyAttempts(retry_after) => write!(f, "Too many sign-in attempts, retry after {}", retry_after),
             AuthUseCaseError::UserAlreadyExists => write!(f, "User already exists"),
+            AuthUseCaseError::InvalidRefreshToken => write!(f, "Invalid refresh token"),
+            AuthUseCaseError::InvalidAccessToken => write!(f, "Invalid access token"),
+            AuthUseCaseError::InvalidVerificationToken => write!(f, "Invalid or expired verification token"),
+            AuthUseCaseError::InvalidPasswordResetToken => write!(f, "Invalid or expired password reset token"),
+            AuthUseCaseError::IncorrectCurrentPassword => write!(f, "Current password is incorrect"),
+            AuthUseCaseError::EmailNotVerified => write!(f, "Email not verified"),
+            AuthUseCaseError::Conflict => write!(f, "Account was modified by another request, try again"),
+            AuthUseCaseError::Token(error) => write!(f, "{:?}", error),
+            AuthUseCaseError::Infrastructure(message) => write!(f, "Infrastructure error: {}", message),
             AuthUseCaseError::Domain(error) => write!(f, "{:?}", error),
         }
     }
 }
 
 impl AuthUseCase {
-    pub fn new(users_repository: UsersRepositoryContract) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        users_repository: UsersRepositoryContract,
+        refresh_tokens_repository: RefreshTokensRepositoryContract,
+        email_verifications_repository: EmailVerificationsRepositoryContract,
+        password_resets_repository: PasswordResetsRepositoryContract,
+        unit_of_work: UnitOfWorkContract,
+        token_service: TokenServiceContract,
+        mailer: MailerContract,
+        login_throttle: LoginThrottleContract,
+        policy: AccountPolicy,
+        clock: ClockContract,
+    ) -> Self {
         Self {
             users_repository,
+            refresh_tokens_repository,
+            email_verifications_repository,
+            password_resets_repository,
+            unit_of_work,
+            token_service,
+            mailer,
+            login_throttle,
+            policy,
+            clock,
         }
     }
 
-    pub async fn sign_in(&self, input: SignInInput) -> Result<String, AuthUseCaseError> {
-        let email = match EmailEntity::new(Some(&input.email)) {
-            Ok(email) => email,
-            Err(_) => return Err(AuthUseCaseError::UserNotFound),
+    async fn issue_verification(&self, repository: &dyn EmailVerificationsRepository, user: &Users) -> Result<String, AuthUseCaseError> {
+        if let Some(error) = repository.consume_for_user(user.id).await {
+            return Err(AuthUseCaseError::from(error));
+        }
+
+        let (verification, token) = EmailVerifications::new(user.id, self.policy.email_verification_ttl, self.clock.now());
+
+        if let Err(error) = repository.save(verification).await {
+            return Err(AuthUseCaseError::from(error));
+        }
+
+        Ok(token)
+    }
+
+    fn mail_verification(&self, user: &Users, token: &str) {
+        let mail = Mail {
+            to: user.email.to_string(),
+            subject: "Confirme seu email".to_string(),
+            body: format!(
+                "Olá, {}!\n\nConfirme seu email acessando o link abaixo:\n\n{}/auth/verify-email?token={}\n\nO link expira em {} horas.",
+                user.name,
+                self.policy.link_base_url,
+                token,
+                self.policy.email_verification_ttl.whole_hours(),
+            ),
         };
 
-        let users_repository = self.users_repository.clone();
+        let mailer = self.mailer.clone();
+
+        tokio::spawn(async move {
+            let to = mail.to.clone();
 
-        let user = match users_repository.lock() {
-            Ok(repo) => {
-                match repo.find_by_email(email).await {
-                    Some(user) => user,
-                    None => return Err(AuthUseCaseError::UserNotFound),
-                }
+            if let Err(error) = mailer.send(mail).await {
+                eprintln!("Error sending verification email to {}: {:?}", to, error);
             }
-            Err(err) => {
-                return Err(AuthUseCaseError::Domain(DomainError::new("Repository lock error", err.to_string().as_str())));
+        });
+    }
+
+    fn send_password_reset(&self, user: Users) {
+        let repository = self.password_resets_repository.clone();
+        let mailer = self.mailer.clone();
+        let ttl = self.policy.password_reset_ttl;
+        let link_base_url = self.policy.link_base_url.clone();
+        let now = self.clock.now();
+
+        tokio::spawn(async move {
+            if let Some(error) = repository.consume_for_user(user.id).await {
+                eprintln!("Error issuing password reset for {}: {:?}", user.email.to_string(), error);
+                return;
+            }
+
+            let (reset, token) = PasswordResets::new(user.id, ttl, now);
+
+            if let Err(error) = repository.save(reset).await {
+                eprintln!("Error issuing password reset for {}: {:?}", user.email.to_string(), error);
+                return;
             }
+
+            let mail = Mail {
+                to: user.email.to_string(),
+                subject: "Redefinição de senha".to_string(),
+                body: format!(
+                    "Olá, {}!\n\nRecebemos um pedido para redefinir a sua senha. Use o link abaixo para escolher uma nova:\n\n{}/auth/reset-password?token={}\n\nO link expira em {} minutos. Se você não fez este pedido, ignore este email.",
+                    user.name,
+                    link_base_url,
+                    token,
+                    ttl.whole_minutes(),
+                ),
+            };
+
+            if let Err(error) = mailer.send(mail).await {
+                eprintln!("Error sending password reset email to {}: {:?}", user.email.to_string(), error);
+            }
+        });
+    }
+
+    async fn revoke_sessions(&self, repository: &dyn RefreshTokensRepository, user_id: UserId) -> Result<(), AuthUseCaseError> {
+        match repository.revoke_for_user(user_id).await {
+            Some(error) => Err(AuthUseCaseError::from(error)),
+            None => Ok(()),
+        }
+    }
+
+    async fn issue_tokens(&self, user: &Users, family_id: TokenFamilyId) -> Result<AuthTokens, AuthUseCaseError> {
+        let access_token = match self.token_service.issue_access_token(&user.id, *user.role.value()) {
+            Ok(token) => token,
+            Err(error) => return Err(AuthUseCaseError::Token(error)),
         };
 
-        // if !user.password.is_valid(&input.password) {
-        //    return Err(AuthUseCaseError::InvalidPassword);
-        // }
+        let (refresh_token, token) = RefreshTokens::new(user.id, family_id, self.token_service.refresh_token_ttl(), self.clock.now());
 
-        if user.password != input.password {
-            return Err(AuthUseCaseError::InvalidPassword);
+        if let Err(error) = self.refresh_tokens_repository.save(refresh_token).await {
+            return Err(AuthUseCaseError::from(error));
         }
 
-        Ok("User logged in".to_string())
+        Ok(AuthTokens {
+            access_token,
+            refresh_token: token,
+            token_type: "Bearer".to_string(),
+            expires_in: self.token_service.access_token_ttl().whole_seconds(),
+        })
     }
 
-    pub async fn sign_up(&mut self, input: UsersInput) -> Result<Users, AuthUseCaseError> {
-        let email = match EmailEntity::new(Some(&input.email)) {
-            Ok(email) => email,
-            Err(error) => return Err(AuthUseCaseError::Domain(error)),
+    pub fn authenticate(&self, access_token: &str) -> Result<CurrentUser, AuthUseCaseError> {
+        let claims = match self.token_service.verify_access_token(access_token) {
+            Ok(claims) => claims,
+            Err(_) => return Err(AuthUseCaseError::InvalidAccessToken),
+        };
+
+        match claims.sub.parse::<UserId>() {
+            Ok(user_id) => Ok(CurrentUser::new(user_id, claims.role)),
+            Err(_) => Err(AuthUseCaseError::InvalidAccessToken),
+        }
+    }
+
+    pub async fn sign_in(&self, input: SignInInput, client_ip: Option<IpAddr>) -> Result<AuthTokens, AuthUseCaseError> {
+        let mut keys = vec![LoginAttemptKey::email(&input.email)];
+        keys.extend(client_ip.map(LoginAttemptKey::Ip));
+
+        if let Err(retry_after) = self.login_throttle.check(&keys) {
+            return Err(AuthUseCaseError::TooManyAttempts(retry_after));
+        }
+
+        let user = match EmailEntity::new(Some(&input.email)) {
+            Ok(email) => self.users_repository.find_by_email(email).await,
+            Err(_) => Ok(None),
         };
 
+        let user = match user {
+            Ok(user) => user,
+            Err(error) => {
+                self.login_throttle.release(&keys);
 
-        let user_already_exists = match self.users_repository.lock() {
-            Ok(repo) => {
-                match repo.find_by_email(email).await {
-                    Some(_) => true,
-                    None => false,
-                }
+                return Err(AuthUseCaseError::from(error));
             }
-            Err(err) => {
-                return Err(AuthUseCaseError::Domain(DomainError::new("Repository lock error", err.to_string().as_str())));
+        };
+
+        let mut user = match user.filter(|user| !user.is_system()) {
+            Some(user) if user.password.verify(&input.password) => user,
+            Some(_) => return Err(AuthUseCaseError::InvalidCredentials),
+            None => {
+                PasswordEntity::verify_dummy(&input.password);
+
+                return Err(AuthUseCaseError::InvalidCredentials);
             }
         };
 
+        self.login_throttle.record_success(&keys);
+
+        if self.policy.email_verification_required && !user.is_verified() {
+            return Err(AuthUseCaseError::EmailNotVerified);
+        }
+
+        if user.password.needs_rehash() {
+            if let Ok(password) = PasswordEntity::hash(&input.password) {
+                user.password = password;
+
+                let _ = self.users_repository.update(user.clone()).await;
+            }
+        }
+
+        self.issue_tokens(&user, TokenFamilyId::generate()).await
+    }
+
+    pub async fn refresh(&self, input: RefreshTokenInput) -> Result<AuthTokens, AuthUseCaseError> {
+        let token_hash = RefreshTokens::hash(&input.refresh_token);
+
+        let mut refresh_token = match self.refresh_tokens_repository.find_by_token_hash(&token_hash).await {
+            Some(refresh_token) => refresh_token,
+            None => return Err(AuthUseCaseError::InvalidRefreshToken),
+        };
+
+        if refresh_token.is_revoked() {
+            if let Some(error) = self.refresh_tokens_repository.revoke_family(refresh_token.family_id).await {
+                return Err(AuthUseCaseError::from(error));
+            }
+
+            return Err(AuthUseCaseError::InvalidRefreshToken);
+        }
+
+        if refresh_token.is_expired(self.clock.now()) {
+            return Err(AuthUseCaseError::InvalidRefreshToken);
+        }
+
+        refresh_token.revoke(self.clock.now());
+
+        let refresh_token = match self.refresh_tokens_repository.update(refresh_token).await {
+            Ok(refresh_token) => refresh_token,
+            Err(error) => return Err(AuthUseCaseError::from(error)),
+        };
+
+        let user = match self.users_repository.find_by_id(refresh_token.user_id).await {
+            Ok(user) if !user.is_system() => user,
+            _ => return Err(AuthUseCaseError::InvalidRefreshToken),
+        };
+
+        self.issue_tokens(&user, refresh_token.family_id).await
+    }
+
+    pub async fn sign_out(&self, input: RefreshTokenInput) -> Result<(), AuthUseCaseError> {
+        let token_hash = RefreshTokens::hash(&input.refresh_token);
+
+        if let Some(refresh_token) = self.refresh_tokens_repository.find_by_token_hash(&token_hash).await {
+            if let Some(error) = self.refresh_tokens_repository.revoke_family(refresh_token.family_id).await {
+                return Err(AuthUseCaseError::from(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn sign_up(&self, input: UsersInput) -> Result<Users, AuthUseCaseError> {
+        let email = match EmailEntity::new(Some(&input.email)) {
+            Ok(email) => email,
+            Err(error) => return Err(AuthUseCaseError::Domain(error)),
+        };
+
+        let user_already_exists = self.users_repository.find_by_email(email).await?.is_some();
+
         if user_already_exists {
             return Err(AuthUseCaseError::UserAlreadyExists);
         }
 
-        let user = match Users::new(&input) {
+        let user = match Users::new(&input, self.clock.now()) {
             Ok(user) => user,
             Err(error) => {
                 return Err(AuthUseCaseError::Domain(error));
             }
         };
 
-        match self.users_repository.lock() {
-            Ok(mut repo) => {
-                match repo.save(user).await {
-                    Ok(user) => Ok(user),
-                    Err(error) => Err(AuthUseCaseError::from(error)),
-                }
-            }
-            Err(err) => {
-                Err(AuthUseCaseError::Domain(DomainError::new("Repository lock error", err.to_string().as_str())))
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.register(transaction.as_ref(), user).await;
+        let (user, token) = finish(transaction, result).await?;
+
+        self.mail_verification(&user, &token);
+
+        Ok(user)
+    }
+
+    async fn register(&self, transaction: &dyn Transaction, user: Users) -> Result<(Users, String), AuthUseCaseError> {
+        let user = match transaction.users().save(user).await {
+            Ok(user) => user,
+            Err(error) => return Err(AuthUseCaseError::from(error)),
+        };
+
+        let token = self.issue_verification(transaction.email_verifications(), &user).await?;
+
+        Ok((user, token))
+    }
+
+    pub async fn verify_email(&self, input: VerifyEmailInput) -> Result<Users, AuthUseCaseError> {
+        let token_hash = EmailVerifications::hash(&input.token);
+
+        let verification = match self.email_verifications_repository.find_by_token_hash(&token_hash).await {
+            Some(verification) if verification.is_usable(self.clock.now()) => verification,
+            _ => return Err(AuthUseCaseError::InvalidVerificationToken),
+        };
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.confirm_email(transaction.as_ref(), verification).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn confirm_email(&self, transaction: &dyn Transaction, mut verification: EmailVerifications) -> Result<Users, AuthUseCaseError> {
+        verification.consume(self.clock.now());
+
+        if let Err(error) = transaction.email_verifications().update(verification.clone()).await {
+            return Err(AuthUseCaseError::from(error));
+        }
+
+        let mut user = match transaction.users().find_by_id(verification.user_id).await {
+            Ok(user) => user,
+            Err(_) => return Err(AuthUseCaseError::InvalidVerificationToken),
+        };
+
+        user.verify_email(self.clock.now());
+
+        match transaction.users().update(user).await {
+            Ok(user) => Ok(user),
+            Err(error) => Err(AuthUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn resend_verification(&self, input: ResendVerificationInput) -> Result<(), AuthUseCaseError> {
+        let email = match EmailEntity::new(Some(&input.email)) {
+            Ok(email) => email,
+            Err(error) => return Err(AuthUseCaseError::Domain(error)),
+        };
+
+        let user = self.users_repository.find_by_email(email).await?;
+
+        match user {
+            Some(user) if !user.is_verified() && !user.is_system() => {
+                let token = self.issue_verification(self.email_verifications_repository.as_ref(), &user).await?;
+
+                self.mail_verification(&user, &token);
+
+                Ok(())
             }
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn request_password_reset(&self, input: RequestPasswordResetInput) -> Result<(), AuthUseCaseError> {
+        let email = match EmailEntity::new(Some(&input.email)) {
+            Ok(email) => email,
+            Err(error) => return Err(AuthUseCaseError::Domain(error)),
+        };
+
+        let user = self.users_repository.find_by_email(email).await?;
+
+        if let Some(user) = user.filter(|user| !user.is_system()) {
+            self.send_password_reset(user);
         }
+
+        Ok(())
+    }
+
+    pub async fn reset_password(&self, input: ResetPasswordInput) -> Result<(), AuthUseCaseError> {
+        let token_hash = PasswordResets::hash(&input.token);
+
+        let reset = match self.password_resets_repository.find_by_token_hash(&token_hash).await {
+            Some(reset) if reset.is_usable(self.clock.now()) => reset,
+            _ => return Err(AuthUseCaseError::InvalidPasswordResetToken),
+        };
+
+        let mut user = match self.users_repository.find_by_id(reset.user_id).await {
+            Ok(user) if !user.is_system() => user,
+            _ => return Err(AuthUseCaseError::InvalidPasswordResetToken),
+        };
+
+        if let Err(error) = user.change_password(&input.password, self.clock.now()) {
+            return Err(AuthUseCaseError::Domain(error));
+        }
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.replace_password(transaction.as_ref(), user).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn replace_password(&self, transaction: &dyn Transaction, user: Users) -> Result<(), AuthUseCaseError> {
+        let user = match transaction.users().update(user).await {
+            Ok(user) => user,
+            Err(error) => return Err(AuthUseCaseError::from(error)),
+        };
+
+        if let Some(error) = transaction.password_resets().consume_for_user(user.id).await {
+            return Err(AuthUseCaseError::from(error));
+        }
+
+        self.revoke_sessions(transaction.refresh_tokens(), user.id).await
+    }
+
+    pub async fn change_password(&self, current_user: &CurrentUser, input: ChangePasswordInput) -> Result<(), AuthUseCaseError> {
+        let mut user = match self.users_repository.find_by_id(current_user.id).await {
+            Ok(user) => user,
+            Err(_) => return Err(AuthUseCaseError::UserNotFound),
+        };
+
+        if !user.password.verify(&input.current_password) {
+            return Err(AuthUseCaseError::IncorrectCurrentPassword);
+        }
+
+        if let Err(error) = user.change_password(&input.password, self.clock.now()) {
+            return Err(AuthUseCaseError::Domain(error));
+        }
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.replace_password(transaction.as_ref(), user).await;
+
+        finish(transaction, result).await
     }
 }
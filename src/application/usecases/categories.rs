@@ -1,23 +1,55 @@
 use std::fmt::{Debug, Formatter};
+use http::StatusCode;
+use crate::application::identity::CurrentUser;
 use crate::application::repositories::categories::CategoriesRepositoryContract;
 use crate::application::repositories::RepositoryError;
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::application::repositories::unit_of_work::{finish, Transaction, UnitOfWorkContract};
+use crate::application::repositories::videos::VideosRepositoryContract;
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::categories::{Categories, CategoriesInput};
+use crate::domain::entities::videos::Videos;
 use crate::domain::errors::app_error::AppError;
 use crate::domain::errors::domain_error::DomainError;
+use crate::domain::value_objects::role::Permission;
+use crate::domain::value_objects::ids::CategoryId;
+use crate::domain::value_objects::ValueObjectTrait;
 
 pub struct CategoriesUseCase {
     categories_repository: CategoriesRepositoryContract,
+    videos_repository: VideosRepositoryContract,
+    unit_of_work: UnitOfWorkContract,
+    clock: ClockContract,
 }
 
 pub enum CategoriesUseCaseError {
     CategoriesNotFound,
+    Forbidden,
+    CategoryHasVideos,
+    DefaultCategoryProtected,
+    Conflict,
+    PreconditionFailed,
+    Infrastructure(String),
     Domain(DomainError),
 }
 
+pub enum DeleteCategoryStrategy {
+    Reject,
+    Reassign(String),
+    Cascade,
+}
+
 impl From<CategoriesUseCaseError> for AppError {
     fn from(error: CategoriesUseCaseError) -> Self {
         match error {
-            CategoriesUseCaseError::CategoriesNotFound => AppError::new("Categories not found", 404, None),
-            CategoriesUseCaseError::Domain(domain) => AppError::new("Categories domain error", 442, Some(domain))
+            CategoriesUseCaseError::CategoriesNotFound => AppError::new("Categories not found", StatusCode::NOT_FOUND, None),
+            CategoriesUseCaseError::Forbidden => AppError::new("You do not have permission to manage this category", StatusCode::FORBIDDEN, None),
+            CategoriesUseCaseError::CategoryHasVideos => AppError::new("Category still has videos", StatusCode::CONFLICT, None),
+            CategoriesUseCaseError::DefaultCategoryProtected => AppError::new("The default category cannot be deleted or recolored", StatusCode::FORBIDDEN, None),
+            CategoriesUseCaseError::Conflict => AppError::new("Category was modified by another request", StatusCode::CONFLICT, None),
+            CategoriesUseCaseError::PreconditionFailed => AppError::new("Category does not match the expected version", StatusCode::PRECONDITION_FAILED, None),
+            CategoriesUseCaseError::Infrastructure(_) => AppError::new("Internal server error", StatusCode::INTERNAL_SERVER_ERROR, None),
+            CategoriesUseCaseError::Domain(domain) => AppError::new("Categories domain error", StatusCode::UNPROCESSABLE_ENTITY, Some(domain))
         }
     }
 }
@@ -28,6 +60,17 @@ impl From<RepositoryError> for CategoriesUseCaseError {
             RepositoryError::NotFound(_) => CategoriesUseCaseError::CategoriesNotFound,
             RepositoryError::Domain(error) => CategoriesUseCaseError::Domain(error),
             RepositoryError::AlreadyExists(_) => CategoriesUseCaseError::Domain(DomainError::new("Category already exists", "")),
+            RepositoryError::Conflict(_) => CategoriesUseCaseError::Conflict,
+            RepositoryError::Infrastructure(message) => CategoriesUseCaseError::Infrastructure(message),
+        }
+    }
+}
+
+impl CategoriesUseCaseError {
+    fn on_if_match(self, expected_version: Option<i32>) -> Self {
+        match self {
+            CategoriesUseCaseError::Conflict if expected_version.is_some() => CategoriesUseCaseError::PreconditionFailed,
+            error => error,
         }
     }
 }
@@ -36,15 +79,220 @@ impl Debug for CategoriesUseCaseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             CategoriesUseCaseError::CategoriesNotFound => write!(f, "Categories not found"),
+            CategoriesUseCaseError::Forbidden => write!(f, "You do not have permission to manage this category"),
+            CategoriesUseCaseError::CategoryHasVideos => write!(f, "Category still has videos"),
+            CategoriesUseCaseError::DefaultCategoryProtected => write!(f, "The default category cannot be deleted or recolored"),
+            CategoriesUseCaseError::Conflict => write!(f, "Category was modified by another request"),
+            CategoriesUseCaseError::PreconditionFailed => write!(f, "Category does not match the expected version"),
+            CategoriesUseCaseError::Infrastructure(message) => write!(f, "Infrastructure error: {}", message),
             CategoriesUseCaseError::Domain(error) => write!(f, "{:?}", error),
         }
     }
 }
 
 impl CategoriesUseCase {
-    pub fn new(categories_repository: CategoriesRepositoryContract) -> Self {
+    pub fn new(categories_repository: CategoriesRepositoryContract, videos_repository: VideosRepositoryContract, unit_of_work: UnitOfWorkContract, clock: ClockContract) -> Self {
         Self {
             categories_repository,
+            videos_repository,
+            unit_of_work,
+            clock,
+        }
+    }
+
+    async fn find_owned(&self, user: &CurrentUser, id: &str) -> Result<Categories, CategoriesUseCaseError> {
+        let category = self.find_by_id(id).await?;
+
+        if !user.can_modify(&category.user_id, Permission::ModifyOwnCategory, Permission::ModifyAnyCategory) {
+            return Err(CategoriesUseCaseError::Forbidden);
+        }
+
+        Ok(category)
+    }
+
+    pub async fn create(&self, user: &CurrentUser, mut input: CategoriesInput) -> Result<Categories, CategoriesUseCaseError> {
+        if !user.can(Permission::CreateCategory) {
+            return Err(CategoriesUseCaseError::Forbidden);
+        }
+
+        input.user_id = user.id.to_string();
+
+        let category = match Categories::new(&input, self.clock.now()) {
+            Ok(category) => category,
+            Err(error) => return Err(CategoriesUseCaseError::Domain(error)),
+        };
+
+        match self.categories_repository.save(category).await {
+            Ok(category) => Ok(category),
+            Err(error) => Err(CategoriesUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn find_by_id(&self, id: &str) -> Result<Categories, CategoriesUseCaseError> {
+        let id = match id.parse::<CategoryId>() {
+            Ok(id) => id,
+            Err(_) => return Err(CategoriesUseCaseError::CategoriesNotFound),
+        };
+
+        match self.categories_repository.find_by_id(id).await {
+            Ok(category) => Ok(category),
+            Err(error) => Err(CategoriesUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn find_all(&self, request: PageRequest) -> Result<PageResult<Categories>, CategoriesUseCaseError> {
+        match self.categories_repository.find_all(request).await {
+            Ok(page) => Ok(page),
+            Err(error) => Err(CategoriesUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn find_videos(&self, id: &str, request: PageRequest) -> Result<PageResult<Videos>, CategoriesUseCaseError> {
+        let category = self.find_by_id(id).await?;
+        let request = request.filter("category_id", &category.id.to_string());
+
+        match self.videos_repository.find_all(request).await {
+            Ok(page) => Ok(page),
+            Err(error) => Err(CategoriesUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn update(&self, user: &CurrentUser, id: &str, input: CategoriesInput, expected_version: Option<i32>) -> Result<Categories, CategoriesUseCaseError> {
+        let mut category = self.find_owned(user, id).await?;
+
+        if expected_version.is_some_and(|version| version != category.version) {
+            return Err(CategoriesUseCaseError::PreconditionFailed);
+        }
+
+        let color = category.color.to_string().to_lowercase();
+
+        if let Err(error) = category.update(&input, self.clock.now()) {
+            return Err(CategoriesUseCaseError::Domain(error));
+        }
+
+        if category.is_default() && category.color.to_string().to_lowercase() != color {
+            return Err(CategoriesUseCaseError::DefaultCategoryProtected);
+        }
+
+        match self.categories_repository.update(category).await {
+            Ok(category) => Ok(category),
+            Err(error) => Err(CategoriesUseCaseError::from(error).on_if_match(expected_version)),
+        }
+    }
+
+    pub async fn restore(&self, user: &CurrentUser, id: &str) -> Result<Categories, CategoriesUseCaseError> {
+        let id = match id.parse::<CategoryId>() {
+            Ok(id) => id,
+            Err(_) => return Err(CategoriesUseCaseError::CategoriesNotFound),
+        };
+
+        let category = self.categories_repository.find_deleted_by_id(id).await?;
+
+        if !user.can_modify(&category.user_id, Permission::ModifyOwnCategory, Permission::ModifyAnyCategory) {
+            return Err(CategoriesUseCaseError::Forbidden);
+        }
+
+        match self.categories_repository.restore(category.id).await {
+            Ok(category) => Ok(category),
+            Err(error) => Err(CategoriesUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn purge(&self, user: &CurrentUser, id: &str) -> Result<(), CategoriesUseCaseError> {
+        if !user.can(Permission::PurgeDeleted) {
+            return Err(CategoriesUseCaseError::Forbidden);
+        }
+
+        let id = match id.parse::<CategoryId>() {
+            Ok(id) => id,
+            Err(_) => return Err(CategoriesUseCaseError::CategoriesNotFound),
+        };
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.erase(transaction.as_ref(), id).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn erase(&self, transaction: &dyn Transaction, id: CategoryId) -> Result<(), CategoriesUseCaseError> {
+        let category = transaction.categories().find_deleted_by_id(id).await?;
+
+        if !transaction.videos().find_by_category_id(category.id).await?.is_empty() {
+            return Err(CategoriesUseCaseError::CategoryHasVideos);
+        }
+
+        transaction.videos().purge_deleted_by_category_id(category.id).await?;
+
+        match transaction.categories().purge(category.id).await {
+            Some(error) => Err(CategoriesUseCaseError::from(error)),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn delete(&self, user: &CurrentUser, id: &str, strategy: DeleteCategoryStrategy, expected_version: Option<i32>) -> Result<(), CategoriesUseCaseError> {
+        let category = self.find_owned(user, id).await?;
+
+        if expected_version.is_some_and(|version| version != category.version) {
+            return Err(CategoriesUseCaseError::PreconditionFailed);
+        }
+
+        if category.is_default() {
+            return Err(CategoriesUseCaseError::DefaultCategoryProtected);
+        }
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.remove(transaction.as_ref(), user, category, strategy, expected_version).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn remove(&self, transaction: &dyn Transaction, user: &CurrentUser, category: Categories, strategy: DeleteCategoryStrategy, expected_version: Option<i32>) -> Result<(), CategoriesUseCaseError> {
+        let videos = transaction.videos().find_by_category_id(category.id).await?;
+
+        if !videos.is_empty() {
+            let touches_foreign_videos = videos.iter().any(|video| !user.can_modify(&video.user_id, Permission::ModifyOwnVideo, Permission::ModifyAnyVideo));
+
+            if touches_foreign_videos && !matches!(strategy, DeleteCategoryStrategy::Reject) {
+                return Err(CategoriesUseCaseError::Forbidden);
+            }
+
+            match strategy {
+                DeleteCategoryStrategy::Reject => return Err(CategoriesUseCaseError::CategoryHasVideos),
+                DeleteCategoryStrategy::Reassign(target_id) => {
+                    let target = match target_id.parse::<CategoryId>() {
+                        Ok(target_id) => transaction.categories().find_by_id(target_id).await?,
+                        Err(_) => return Err(CategoriesUseCaseError::CategoriesNotFound),
+                    };
+
+                    if target.id == category.id {
+                        return Err(CategoriesUseCaseError::Domain(DomainError::new("Invalid reassign", "Videos cannot be reassigned to the category being deleted")));
+                    }
+
+                    if !target.is_default() && !user.can_modify(&target.user_id, Permission::ModifyOwnCategory, Permission::ModifyAnyCategory) {
+                        return Err(CategoriesUseCaseError::Forbidden);
+                    }
+
+                    for mut video in videos {
+                        video.move_to_category(target.id, self.clock.now());
+
+                        if let Err(error) = transaction.videos().update(video).await {
+                            return Err(CategoriesUseCaseError::from(error));
+                        }
+                    }
+                }
+                DeleteCategoryStrategy::Cascade => {
+                    for video in videos {
+                        if let Some(error) = transaction.videos().delete(video.id).await {
+                            return Err(CategoriesUseCaseError::from(error));
+                        }
+                    }
+                }
+            }
+        }
+
+        match transaction.categories().delete_at_version(category.id, category.version).await {
+            Some(error) => Err(CategoriesUseCaseError::from(error).on_if_match(expected_version)),
+            None => Ok(()),
         }
     }
 }
\ No newline at end of file
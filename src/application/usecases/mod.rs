@@ -1,5 +1,7 @@
 pub mod authentication;
 pub mod videos;
 pub mod categories;
+pub mod users;
+pub mod retention;
 
-mod __tests__;
\ No newline at end of file
+mod __tests__;
@@ -0,0 +1,68 @@
+use time::{Duration, OffsetDateTime};
+use crate::application::repositories::RepositoryError;
+use crate::application::repositories::unit_of_work::{finish, Transaction, UnitOfWorkContract};
+use crate::application::services::clock::ClockContract;
+use crate::application::usecases::users::erase_user_content;
+
+#[derive(Debug, Clone)]
+pub struct RetentionPolicy {
+    pub retention: Duration,
+    pub interval: Duration,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PurgeSummary {
+    pub videos: u64,
+    pub categories: u64,
+    pub users: u64,
+}
+
+pub struct RetentionUseCase {
+    unit_of_work: UnitOfWorkContract,
+    policy: RetentionPolicy,
+    clock: ClockContract,
+}
+
+impl PurgeSummary {
+    pub fn total(&self) -> u64 {
+        self.videos + self.categories + self.users
+    }
+}
+
+impl RetentionUseCase {
+    pub fn new(unit_of_work: UnitOfWorkContract, policy: RetentionPolicy, clock: ClockContract) -> Self {
+        Self {
+            unit_of_work,
+            policy,
+            clock,
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.policy.interval
+    }
+
+    pub async fn purge_expired(&self) -> Result<PurgeSummary, RepositoryError> {
+        let cutoff = self.clock.now() - self.policy.retention;
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = Self::purge_before(transaction.as_ref(), cutoff).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn purge_before(transaction: &dyn Transaction, cutoff: OffsetDateTime) -> Result<PurgeSummary, RepositoryError> {
+        let mut videos = transaction.videos().purge_deleted_before(cutoff).await?;
+        let mut categories = transaction.categories().purge_deleted_before(cutoff).await?;
+
+        for user in transaction.users().find_deleted_before(cutoff).await? {
+            let (erased_videos, erased_categories) = erase_user_content(transaction, user.id).await?;
+            videos += erased_videos;
+            categories += erased_categories;
+        }
+
+        let users = transaction.users().purge_deleted_before(cutoff).await?;
+
+        Ok(PurgeSummary { videos, categories, users })
+    }
+}
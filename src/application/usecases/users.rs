@@ -0,0 +1,293 @@
+use std::fmt::{Debug, Formatter};
+use http::StatusCode;
+use serde::Deserialize;
+use crate::application::identity::CurrentUser;
+use crate::application::repositories::RepositoryError;
+use crate::application::repositories::unit_of_work::{finish, Transaction, UnitOfWorkContract};
+use crate::application::repositories::users::UsersRepositoryContract;
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::categories::Categories;
+use crate::domain::entities::users::{Users, SYSTEM_USER_ID};
+use crate::domain::errors::app_error::AppError;
+use crate::domain::errors::domain_error::DomainError;
+use crate::domain::value_objects::email::EmailEntity;
+use crate::domain::value_objects::role::{Permission, RoleEntity};
+use crate::domain::value_objects::ids::UserId;
+use crate::domain::value_objects::ValueObjectTrait;
+
+pub struct UsersUseCase {
+    users_repository: UsersRepositoryContract,
+    unit_of_work: UnitOfWorkContract,
+    clock: ClockContract,
+}
+
+#[derive(Deserialize)]
+pub struct ChangeRoleInput {
+    pub role: String,
+}
+
+pub enum UsersUseCaseError {
+    UserNotFound,
+    Forbidden,
+    OwnRoleChange,
+    Conflict,
+    PreconditionFailed,
+    Infrastructure(String),
+    Domain(DomainError),
+}
+
+impl From<UsersUseCaseError> for AppError {
+    fn from(error: UsersUseCaseError) -> Self {
+        match error {
+            UsersUseCaseError::UserNotFound => AppError::new("User not found", StatusCode::NOT_FOUND, None),
+            UsersUseCaseError::Forbidden => AppError::new("You do not have permission to manage users", StatusCode::FORBIDDEN, None),
+            UsersUseCaseError::OwnRoleChange => AppError::new("Admins cannot change their own role", StatusCode::FORBIDDEN, None),
+            UsersUseCaseError::Conflict => AppError::new("User was modified by another request", StatusCode::CONFLICT, None),
+            UsersUseCaseError::PreconditionFailed => AppError::new("User does not match the expected version", StatusCode::PRECONDITION_FAILED, None),
+            UsersUseCaseError::Infrastructure(_) => AppError::new("Internal server error", StatusCode::INTERNAL_SERVER_ERROR, None),
+            UsersUseCaseError::Domain(domain) => AppError::new("User domain error", StatusCode::UNPROCESSABLE_ENTITY, Some(domain))
+        }
+    }
+}
+
+impl From<RepositoryError> for UsersUseCaseError {
+    fn from(error: RepositoryError) -> Self {
+        match error {
+            RepositoryError::NotFound(_) => UsersUseCaseError::UserNotFound,
+            RepositoryError::Domain(error) => UsersUseCaseError::Domain(error),
+            RepositoryError::AlreadyExists(_) => UsersUseCaseError::Domain(DomainError::new("User already exists", "")),
+            RepositoryError::Conflict(_) => UsersUseCaseError::Conflict,
+            RepositoryError::Infrastructure(message) => UsersUseCaseError::Infrastructure(message),
+        }
+    }
+}
+
+impl UsersUseCaseError {
+    fn on_if_match(self, expected_version: Option<i32>) -> Self {
+        match self {
+            UsersUseCaseError::Conflict if expected_version.is_some() => UsersUseCaseError::PreconditionFailed,
+            error => error,
+        }
+    }
+}
+
+impl Debug for UsersUseCaseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UsersUseCaseError::UserNotFound => write!(f, "User not found"),
+            UsersUseCaseError::Forbidden => write!(f, "You do not have permission to manage users"),
+            UsersUseCaseError::OwnRoleChange => write!(f, "Admins cannot change their own role"),
+            UsersUseCaseError::Conflict => write!(f, "User was modified by another request"),
+            UsersUseCaseError::PreconditionFailed => write!(f, "User does not match the expected version"),
+            UsersUseCaseError::Infrastructure(message) => write!(f, "Infrastructure error: {}", message),
+            UsersUseCaseError::Domain(error) => write!(f, "{:?}", error),
+        }
+    }
+}
+
+impl UsersUseCase {
+    pub fn new(users_repository: UsersRepositoryContract, unit_of_work: UnitOfWorkContract, clock: ClockContract) -> Self {
+        Self {
+            users_repository,
+            unit_of_work,
+            clock,
+        }
+    }
+
+    fn parse_id(id: &str) -> Result<UserId, UsersUseCaseError> {
+        match id.parse::<UserId>() {
+            Ok(id) if id.to_string() != SYSTEM_USER_ID => Ok(id),
+            _ => Err(UsersUseCaseError::UserNotFound),
+        }
+    }
+
+    pub async fn change_role(&self, user: &CurrentUser, id: &str, input: ChangeRoleInput, expected_version: Option<i32>) -> Result<Users, UsersUseCaseError> {
+        if !user.can(Permission::ManageUsers) {
+            return Err(UsersUseCaseError::Forbidden);
+        }
+
+        let role = match RoleEntity::new(Some(&input.role)) {
+            Ok(role) => role,
+            Err(error) => return Err(UsersUseCaseError::Domain(error)),
+        };
+
+        let id = Self::parse_id(id)?;
+
+        if id == user.id {
+            return Err(UsersUseCaseError::OwnRoleChange);
+        }
+
+        let mut target = match self.users_repository.find_by_id(id).await {
+            Ok(target) => target,
+            Err(error) => return Err(UsersUseCaseError::from(error)),
+        };
+
+        if expected_version.is_some_and(|version| version != target.version) {
+            return Err(UsersUseCaseError::PreconditionFailed);
+        }
+
+        target.change_role(role, self.clock.now());
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.store_role(transaction.as_ref(), target).await;
+
+        finish(transaction, result).await.map_err(|error| error.on_if_match(expected_version))
+    }
+
+    pub async fn assign_role(&self, email: &str, input: ChangeRoleInput) -> Result<Users, UsersUseCaseError> {
+        let email = match EmailEntity::new(Some(email)) {
+            Ok(email) => email,
+            Err(error) => return Err(UsersUseCaseError::Domain(error)),
+        };
+
+        let role = match RoleEntity::new(Some(&input.role)) {
+            Ok(role) => role,
+            Err(error) => return Err(UsersUseCaseError::Domain(error)),
+        };
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.assign_role_by_email(transaction.as_ref(), email, role).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn assign_role_by_email(&self, transaction: &dyn Transaction, email: EmailEntity, role: RoleEntity) -> Result<Users, UsersUseCaseError> {
+        let mut target = match transaction.users().find_by_email(email).await? {
+            Some(target) if !target.is_system() => target,
+            _ => return Err(UsersUseCaseError::UserNotFound),
+        };
+
+        target.change_role(role, self.clock.now());
+
+        self.store_role(transaction, target).await
+    }
+
+    async fn store_role(&self, transaction: &dyn Transaction, target: Users) -> Result<Users, UsersUseCaseError> {
+        let target = transaction.users().update(target).await?;
+
+        match transaction.refresh_tokens().revoke_for_user(target.id).await {
+            Some(error) => Err(UsersUseCaseError::from(error)),
+            None => Ok(target),
+        }
+    }
+
+    pub async fn delete(&self, user: &CurrentUser, id: &str, expected_version: Option<i32>) -> Result<(), UsersUseCaseError> {
+        let id = Self::parse_id(id)?;
+
+        if id != user.id && !user.can(Permission::ManageUsers) {
+            return Err(UsersUseCaseError::Forbidden);
+        }
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = self.deactivate(transaction.as_ref(), id, expected_version).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn deactivate(&self, transaction: &dyn Transaction, id: UserId, expected_version: Option<i32>) -> Result<(), UsersUseCaseError> {
+        let deleted = match expected_version {
+            Some(version) => transaction.users().delete_at_version(id, version).await,
+            None => transaction.users().delete(id).await,
+        };
+
+        if let Some(error) = deleted {
+            return Err(UsersUseCaseError::from(error).on_if_match(expected_version));
+        }
+
+        if let Some(error) = transaction.refresh_tokens().revoke_for_user(id).await {
+            return Err(UsersUseCaseError::from(error));
+        }
+
+        transaction.videos().delete_by_user_id(id).await?;
+
+        for category in transaction.categories().find_by_user_id(id).await? {
+            transaction.videos().reassign_foreign_videos(category.id, id, Categories::default_id()).await?;
+
+            if let Some(error) = transaction.categories().delete(category.id).await {
+                return Err(UsersUseCaseError::from(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn restore(&self, user: &CurrentUser, id: &str) -> Result<Users, UsersUseCaseError> {
+        if !user.can(Permission::ManageUsers) {
+            return Err(UsersUseCaseError::Forbidden);
+        }
+
+        let id = Self::parse_id(id)?;
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = Self::reactivate(transaction.as_ref(), id).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn reactivate(transaction: &dyn Transaction, id: UserId) -> Result<Users, UsersUseCaseError> {
+        let deleted = transaction.users().find_deleted_by_id(id).await?;
+
+        let Some(deleted_at) = deleted.deleted_at else {
+            return Err(UsersUseCaseError::UserNotFound);
+        };
+
+        let user = transaction.users().restore(id).await?;
+
+        for category in transaction.categories().find_deleted_by_user_id(id).await? {
+            if category.deleted_at.is_some_and(|at| at >= deleted_at) {
+                transaction.categories().restore(category.id).await?;
+            }
+        }
+
+        transaction.videos().restore_by_user_id(id, deleted_at).await?;
+
+        Ok(user)
+    }
+
+    pub async fn purge(&self, user: &CurrentUser, id: &str) -> Result<(), UsersUseCaseError> {
+        if !user.can(Permission::PurgeDeleted) {
+            return Err(UsersUseCaseError::Forbidden);
+        }
+
+        let id = Self::parse_id(id)?;
+
+        self.users_repository.find_deleted_by_id(id).await?;
+
+        let transaction = self.unit_of_work.begin().await?;
+        let result = Self::erase(transaction.as_ref(), id).await;
+
+        finish(transaction, result).await
+    }
+
+    async fn erase(transaction: &dyn Transaction, id: UserId) -> Result<(), UsersUseCaseError> {
+        erase_user_content(transaction, id).await?;
+
+        match transaction.users().purge(id).await {
+            Some(error) => Err(UsersUseCaseError::from(error)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub async fn erase_user_content(transaction: &dyn Transaction, id: UserId) -> Result<(u64, u64), RepositoryError> {
+    let videos = transaction.videos().purge_by_user_id(id).await?;
+
+    let mut categories = transaction.categories().find_by_user_id(id).await?;
+    categories.extend(transaction.categories().find_deleted_by_user_id(id).await?);
+
+    for category in &categories {
+        transaction.videos().reassign_foreign_videos(category.id, id, Categories::default_id()).await?;
+
+        if !category.is_deleted() {
+            if let Some(error) = transaction.categories().delete(category.id).await {
+                return Err(error);
+            }
+        }
+
+        if let Some(error) = transaction.categories().purge(category.id).await {
+            return Err(error);
+        }
+    }
+
+    Ok((videos, categories.len() as u64))
+}
@@ -1,23 +1,44 @@
 use std::fmt::{Debug, Formatter};
+use http::StatusCode;
+use crate::application::identity::CurrentUser;
 use crate::application::repositories::RepositoryError;
+use crate::application::repositories::categories::CategoriesRepositoryContract;
+use crate::application::repositories::pagination::{PageRequest, PageResult};
 use crate::application::repositories::videos::VideosRepositoryContract;
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::categories::DEFAULT_CATEGORY_ID;
+use crate::domain::entities::videos::{Videos, VideosInput, VideosPatchInput};
 use crate::domain::errors::app_error::AppError;
 use crate::domain::errors::domain_error::DomainError;
+use crate::domain::value_objects::role::Permission;
+use crate::domain::value_objects::ids::{CategoryId, VideoId};
 
 pub struct VideosUseCase {
     videos_repository: VideosRepositoryContract,
+    categories_repository: CategoriesRepositoryContract,
+    clock: ClockContract,
 }
 
 pub enum VideosUseCaseError {
     VideosNotFound,
+    Forbidden,
+    Conflict,
+    PreconditionFailed,
+    CategoryDeleted,
+    Infrastructure(String),
     Domain(DomainError),
 }
 
 impl From<VideosUseCaseError> for AppError {
     fn from(error: VideosUseCaseError) -> Self {
         match error {
-            VideosUseCaseError::VideosNotFound => AppError::new("Videos not found", 404, None),
-            VideosUseCaseError::Domain(domain) => AppError::new("Videos domain error", 442, Some(domain))
+            VideosUseCaseError::VideosNotFound => AppError::new("Videos not found", StatusCode::NOT_FOUND, None),
+            VideosUseCaseError::Forbidden => AppError::new("You do not have permission to manage this video", StatusCode::FORBIDDEN, None),
+            VideosUseCaseError::Conflict => AppError::new("Video was modified by another request", StatusCode::CONFLICT, None),
+            VideosUseCaseError::PreconditionFailed => AppError::new("Video does not match the expected version", StatusCode::PRECONDITION_FAILED, None),
+            VideosUseCaseError::CategoryDeleted => AppError::new("The video's category is deleted", StatusCode::CONFLICT, None),
+            VideosUseCaseError::Infrastructure(_) => AppError::new("Internal server error", StatusCode::INTERNAL_SERVER_ERROR, None),
+            VideosUseCaseError::Domain(domain) => AppError::new("Videos domain error", StatusCode::UNPROCESSABLE_ENTITY, Some(domain))
         }
     }
 }
@@ -28,6 +49,17 @@ impl From<RepositoryError> for VideosUseCaseError {
             RepositoryError::NotFound(_) => VideosUseCaseError::VideosNotFound,
             RepositoryError::Domain(error) => VideosUseCaseError::Domain(error),
             RepositoryError::AlreadyExists(_) => VideosUseCaseError::Domain(DomainError::new("Video already exists","")),
+            RepositoryError::Conflict(_) => VideosUseCaseError::Conflict,
+            RepositoryError::Infrastructure(message) => VideosUseCaseError::Infrastructure(message),
+        }
+    }
+}
+
+impl VideosUseCaseError {
+    fn on_if_match(self, expected_version: Option<i32>) -> Self {
+        match self {
+            VideosUseCaseError::Conflict if expected_version.is_some() => VideosUseCaseError::PreconditionFailed,
+            error => error,
         }
     }
 }
@@ -36,15 +68,205 @@ impl Debug for VideosUseCaseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             VideosUseCaseError::VideosNotFound => write!(f, "Videos not found"),
+            VideosUseCaseError::Forbidden => write!(f, "You do not have permission to manage this video"),
+            VideosUseCaseError::Conflict => write!(f, "Video was modified by another request"),
+            VideosUseCaseError::PreconditionFailed => write!(f, "Video does not match the expected version"),
+            VideosUseCaseError::CategoryDeleted => write!(f, "The video's category is deleted"),
+            VideosUseCaseError::Infrastructure(message) => write!(f, "Infrastructure error: {}", message),
             VideosUseCaseError::Domain(error) => write!(f, "{:?}", error),
         }
     }
 }
 
 impl VideosUseCase {
-    pub fn new(videos_repository: VideosRepositoryContract) -> Self {
+    pub fn new(videos_repository: VideosRepositoryContract, categories_repository: CategoriesRepositoryContract, clock: ClockContract) -> Self {
         Self {
             videos_repository,
+            categories_repository,
+            clock,
+        }
+    }
+
+    fn resolve_input(user: &CurrentUser, mut input: VideosInput) -> VideosInput {
+        input.user_id = user.id.to_string();
+
+        if input.category_id.as_deref().is_none_or(|category_id| category_id.trim().is_empty()) {
+            input.category_id = Some(DEFAULT_CATEGORY_ID.to_string());
+        }
+
+        input
+    }
+
+    fn resolve_patch_input(mut input: VideosPatchInput) -> VideosPatchInput {
+        if input.category_id.as_deref().is_some_and(|category_id| category_id.trim().is_empty()) {
+            input.category_id = Some(DEFAULT_CATEGORY_ID.to_string());
+        }
+
+        input
+    }
+
+    async fn check_category(&self, user: &CurrentUser, category_id: CategoryId) -> Result<(), VideosUseCaseError> {
+        let category = match self.categories_repository.find_by_id(category_id).await {
+            Ok(category) => category,
+            Err(RepositoryError::NotFound(_)) => return Err(VideosUseCaseError::Domain(DomainError::new("Invalid category", "The category does not exist"))),
+            Err(error) => return Err(VideosUseCaseError::from(error)),
+        };
+
+        if !category.is_default() && !user.can_modify(&category.user_id, Permission::ModifyOwnCategory, Permission::ModifyAnyCategory) {
+            return Err(VideosUseCaseError::Forbidden);
+        }
+
+        Ok(())
+    }
+
+    async fn find_owned(&self, user: &CurrentUser, id: &str, expected_version: Option<i32>) -> Result<Videos, VideosUseCaseError> {
+        let video = self.find_by_id(id).await?;
+
+        if !user.can_modify(&video.user_id, Permission::ModifyOwnVideo, Permission::ModifyAnyVideo) {
+            return Err(VideosUseCaseError::Forbidden);
+        }
+
+        if expected_version.is_some_and(|version| version != video.version) {
+            return Err(VideosUseCaseError::PreconditionFailed);
+        }
+
+        Ok(video)
+    }
+
+    pub async fn create(&self, user: &CurrentUser, input: VideosInput) -> Result<Videos, VideosUseCaseError> {
+        if !user.can(Permission::CreateVideo) {
+            return Err(VideosUseCaseError::Forbidden);
+        }
+
+        let input = Self::resolve_input(user, input);
+
+        let video = match Videos::new(&input, self.clock.now()) {
+            Ok(video) => video,
+            Err(error) => return Err(VideosUseCaseError::Domain(error)),
+        };
+
+        self.check_category(user, video.category_id).await?;
+
+        match self.videos_repository.save(video).await {
+            Ok(video) => Ok(video),
+            Err(error) => Err(VideosUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn find_by_id(&self, id: &str) -> Result<Videos, VideosUseCaseError> {
+        let id = match id.parse::<VideoId>() {
+            Ok(id) => id,
+            Err(_) => return Err(VideosUseCaseError::VideosNotFound),
+        };
+
+        match self.videos_repository.find_by_id(id).await {
+            Ok(video) => Ok(video),
+            Err(error) => Err(VideosUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn find_all(&self, request: PageRequest) -> Result<PageResult<Videos>, VideosUseCaseError> {
+        match self.videos_repository.find_all(request).await {
+            Ok(page) => Ok(page),
+            Err(error) => Err(VideosUseCaseError::from(error)),
         }
     }
-}
\ No newline at end of file
+
+    pub async fn search(&self, query: &str, request: PageRequest) -> Result<PageResult<Videos>, VideosUseCaseError> {
+        if query.trim().is_empty() {
+            return self.find_all(request).await;
+        }
+
+        match self.videos_repository.search(query, request).await {
+            Ok(page) => Ok(page),
+            Err(error) => Err(VideosUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn update(&self, user: &CurrentUser, id: &str, input: VideosInput, expected_version: Option<i32>) -> Result<Videos, VideosUseCaseError> {
+        let mut video = self.find_owned(user, id, expected_version).await?;
+        let input = Self::resolve_input(user, input);
+        let category_id = video.category_id;
+
+        if let Err(error) = video.update(&input, self.clock.now()) {
+            return Err(VideosUseCaseError::Domain(error));
+        }
+
+        if video.category_id != category_id {
+            self.check_category(user, video.category_id).await?;
+        }
+
+        match self.videos_repository.update(video).await {
+            Ok(video) => Ok(video),
+            Err(error) => Err(VideosUseCaseError::from(error).on_if_match(expected_version)),
+        }
+    }
+
+    pub async fn patch(&self, user: &CurrentUser, id: &str, input: VideosPatchInput, expected_version: Option<i32>) -> Result<Videos, VideosUseCaseError> {
+        let mut video = self.find_owned(user, id, expected_version).await?;
+        let input = Self::resolve_patch_input(input);
+        let category_id = video.category_id;
+
+        if let Err(error) = video.patch(&input, self.clock.now()) {
+            return Err(VideosUseCaseError::Domain(error));
+        }
+
+        if video.category_id != category_id {
+            self.check_category(user, video.category_id).await?;
+        }
+
+        match self.videos_repository.update(video).await {
+            Ok(video) => Ok(video),
+            Err(error) => Err(VideosUseCaseError::from(error).on_if_match(expected_version)),
+        }
+    }
+
+    pub async fn delete(&self, user: &CurrentUser, id: &str, expected_version: Option<i32>) -> Result<(), VideosUseCaseError> {
+        let video = self.find_owned(user, id, expected_version).await?;
+
+        match self.videos_repository.delete_at_version(video.id, video.version).await {
+            Some(error) => Err(VideosUseCaseError::from(error).on_if_match(expected_version)),
+            None => Ok(()),
+        }
+    }
+
+    pub async fn restore(&self, user: &CurrentUser, id: &str) -> Result<Videos, VideosUseCaseError> {
+        let id = match id.parse::<VideoId>() {
+            Ok(id) => id,
+            Err(_) => return Err(VideosUseCaseError::VideosNotFound),
+        };
+
+        let video = self.videos_repository.find_deleted_by_id(id).await?;
+
+        if !user.can_modify(&video.user_id, Permission::ModifyOwnVideo, Permission::ModifyAnyVideo) {
+            return Err(VideosUseCaseError::Forbidden);
+        }
+
+        match self.categories_repository.find_by_id(video.category_id).await {
+            Ok(_) => {}
+            Err(RepositoryError::NotFound(_)) => return Err(VideosUseCaseError::CategoryDeleted),
+            Err(error) => return Err(VideosUseCaseError::from(error)),
+        }
+
+        match self.videos_repository.restore(video.id).await {
+            Ok(video) => Ok(video),
+            Err(error) => Err(VideosUseCaseError::from(error)),
+        }
+    }
+
+    pub async fn purge(&self, user: &CurrentUser, id: &str) -> Result<(), VideosUseCaseError> {
+        if !user.can(Permission::PurgeDeleted) {
+            return Err(VideosUseCaseError::Forbidden);
+        }
+
+        let id = match id.parse::<VideoId>() {
+            Ok(id) => id,
+            Err(_) => return Err(VideosUseCaseError::VideosNotFound),
+        };
+
+        match self.videos_repository.purge(id).await {
+            Some(error) => Err(VideosUseCaseError::from(error)),
+            None => Ok(()),
+        }
+    }
+}
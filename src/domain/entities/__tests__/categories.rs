@@ -1,10 +1,14 @@
 #[cfg(test)]
 mod test_categories_entity {
-    use crate::domain::entities::categories::{Categories, CategoriesInput};
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+    use crate::domain::entities::categories::{Categories, CategoriesInput, DEFAULT_CATEGORY_ID};
+    use crate::domain::value_objects::ValueObjectTrait;
 
     const NAME: &str = "Category name";
     const COLOR: &str = "#000000";
     const USER_ID: &str = "018b33b3-6d70-7d94-9ecc-0cc5069b30ea";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
 
     #[test]
     fn should_return_error_when_name_is_empty() {
@@ -14,7 +18,7 @@ mod test_categories_entity {
             user_id: USER_ID.to_string(),
         };
 
-        let result = Categories::new(&input);
+        let result = Categories::new(&input, NOW);
 
         assert!(result.is_err());
     }
@@ -27,7 +31,7 @@ mod test_categories_entity {
             user_id: USER_ID.to_string(),
         };
 
-        let result = Categories::new(&input);
+        let result = Categories::new(&input, NOW);
 
         assert!(result.is_err());
     }
@@ -40,7 +44,7 @@ mod test_categories_entity {
             user_id: USER_ID.to_string(),
         };
 
-        let result = Categories::new(&input);
+        let result = Categories::new(&input, NOW);
 
         assert!(result.is_err());
     }
@@ -53,7 +57,7 @@ mod test_categories_entity {
             user_id: "invalid".to_string(),
         };
 
-        let result = Categories::new(&input);
+        let result = Categories::new(&input, NOW);
 
         assert!(result.is_err());
     }
@@ -66,8 +70,78 @@ mod test_categories_entity {
             user_id: USER_ID.to_string(),
         };
 
-        let result = Categories::new(&input);
+        let result = Categories::new(&input, NOW);
 
         assert!(result.is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_update_a_category_keeping_its_owner() {
+        let mut category = Categories::new(&CategoriesInput {
+            name: NAME.to_string(),
+            color: COLOR.to_string(),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+
+        let result = category.update(&CategoriesInput {
+            name: "New name".to_string(),
+            color: "#ffffff".to_string(),
+            user_id: "018b33b3-6d70-7d94-9ecc-0cc5069b30eb".to_string(),
+        }, NOW);
+
+        assert!(result.is_ok());
+        assert_eq!(category.name, "New name");
+        assert_eq!(category.color.to_string(), "#ffffff");
+        assert_eq!(category.user_id.to_string(), USER_ID);
+    }
+
+    #[test]
+    fn should_return_error_when_updating_with_invalid_data() {
+        let mut category = Categories::new(&CategoriesInput {
+            name: NAME.to_string(),
+            color: COLOR.to_string(),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+
+        let result = category.update(&CategoriesInput {
+            name: NAME.to_string(),
+            color: "invalid".to_string(),
+            user_id: USER_ID.to_string(),
+        }, NOW);
+
+        assert!(result.is_err());
+        assert_eq!(category.color.to_string(), COLOR);
+    }
+
+    #[test]
+    fn should_build_the_default_category() {
+        let category = Categories::default_category(NOW);
+
+        assert!(category.is_default());
+        assert_eq!(category.id.to_string(), DEFAULT_CATEGORY_ID);
+        assert_eq!(category.name, "LIVRE");
+    }
+
+    #[test]
+    fn should_not_treat_other_categories_as_default() {
+        let category = Categories::new(&CategoriesInput {
+            name: NAME.to_string(),
+            color: COLOR.to_string(),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+
+        assert!(!category.is_default());
+    }
+
+    #[test]
+    fn should_attribute_value_object_errors_to_their_field() {
+        let error = Categories::new(&CategoriesInput {
+            name: NAME.to_string(),
+            color: "invalid".to_string(),
+            user_id: "invalid".to_string(),
+        }, NOW).unwrap_err();
+
+        assert_eq!(error.errors.field("color").unwrap().code, "invalid");
+        assert_eq!(error.errors.field("user_id").unwrap().message, "Invalid UUID");
+    }
+}
@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod test_user_entity {
+    use time::OffsetDateTime;
+    use time::macros::datetime;
     use crate::domain::entities::users::{Users, UsersInput};
 
     const NAME: &str = "John Doe";
     const EMAIL: &str = "doejoe@test.com";
     const PASSWORD: &str = "12345678";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
 
     #[test]
     fn it_should_create_a_new_user() {
@@ -14,7 +17,7 @@ mod test_user_entity {
             password: PASSWORD.to_string(),
         };
 
-        let user = Users::new(&data).unwrap();
+        let user = Users::new(&data, NOW).unwrap();
 
         assert_eq!(user.name, "John Doe".to_string());
     }
@@ -27,7 +30,7 @@ mod test_user_entity {
             password: PASSWORD.to_string(),
         };
 
-        let user = Users::new(&data);
+        let user = Users::new(&data, NOW);
 
         assert!(user.is_err());
     }
@@ -40,7 +43,7 @@ mod test_user_entity {
             password: PASSWORD.to_string(),
         };
 
-        let user = Users::new(&data);
+        let user = Users::new(&data, NOW);
 
         assert!(user.is_err());
     }
@@ -53,7 +56,7 @@ mod test_user_entity {
             password: PASSWORD.to_string(),
         };
 
-        let user = Users::new(&data);
+        let user = Users::new(&data, NOW);
 
         assert!(user.is_err());
     }
@@ -66,8 +69,25 @@ mod test_user_entity {
             password: "".to_string(),
         };
 
-        let user = Users::new(&data);
+        let user = Users::new(&data, NOW);
 
         assert!(user.is_err());
     }
+
+    #[test]
+    fn it_should_change_the_password_using_the_same_rules() {
+        let mut user = Users::new(&UsersInput {
+            name: NAME.to_string(),
+            email: EMAIL.to_string(),
+            password: PASSWORD.to_string(),
+        }, NOW).unwrap();
+
+        assert!(user.change_password("short", NOW).is_err());
+        assert!(user.password.verify(PASSWORD));
+
+        user.change_password("new-password", NOW).unwrap();
+
+        assert!(user.password.verify("new-password"));
+        assert!(!user.password.verify(PASSWORD));
+    }
 }
\ No newline at end of file
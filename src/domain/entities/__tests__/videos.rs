@@ -1,12 +1,16 @@
 #[cfg(test)]
 mod test_videos_entity {
-    use crate::domain::entities::videos::{Videos, VideosInput};
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+    use crate::domain::entities::videos::{Videos, VideosInput, VideosPatchInput};
+    use crate::domain::value_objects::ValueObjectTrait;
 
     const TITLE: &str = "Title";
     const DESCRIPTION: &str = "Description";
     const URL: &str = "https://www.youtube.com/watch?v=6n3pFFPSlW4";
     const CATEGORY_ID: &str = "018b33b7-5b9a-72a7-942f-8c46275aeacd";
     const USER_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a8";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
 
     #[test]
     fn should_create_a_new_video() {
@@ -14,11 +18,11 @@ mod test_videos_entity {
             title: TITLE.to_string(),
             description: DESCRIPTION.to_string(),
             url: URL.to_string(),
-            category_id: CATEGORY_ID.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
             user_id: USER_ID.to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_ok())
     }
@@ -29,11 +33,11 @@ mod test_videos_entity {
             title: "".to_string(),
             description: DESCRIPTION.to_string(),
             url: URL.to_string(),
-            category_id: CATEGORY_ID.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
             user_id: USER_ID.to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_err());
     }
@@ -44,11 +48,11 @@ mod test_videos_entity {
             title: "a".to_string(),
             description: DESCRIPTION.to_string(),
             url: URL.to_string(),
-            category_id: CATEGORY_ID.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
             user_id: USER_ID.to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_err());
     }
@@ -59,11 +63,11 @@ mod test_videos_entity {
             title: TITLE.to_string(),
             description: "".to_string(),
             url: URL.to_string(),
-            category_id: CATEGORY_ID.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
             user_id: USER_ID.to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_err());
     }
@@ -74,11 +78,11 @@ mod test_videos_entity {
             title: TITLE.to_string(),
             description: "a".to_string(),
             url: URL.to_string(),
-            category_id: CATEGORY_ID.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
             user_id: USER_ID.to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_err());
     }
@@ -89,11 +93,11 @@ mod test_videos_entity {
             title: TITLE.to_string(),
             description: DESCRIPTION.to_string(),
             url: "invalid_url".to_string(),
-            category_id: CATEGORY_ID.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
             user_id: USER_ID.to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_err());
     }
@@ -104,11 +108,11 @@ mod test_videos_entity {
             title: TITLE.to_string(),
             description: DESCRIPTION.to_string(),
             url: URL.to_string(),
-            category_id: "invalid_category_id".to_string(),
+            category_id: Some("invalid_category_id".to_string()),
             user_id: USER_ID.to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_err());
     }
@@ -119,12 +123,173 @@ mod test_videos_entity {
             title: TITLE.to_string(),
             description: DESCRIPTION.to_string(),
             url: URL.to_string(),
-            category_id: CATEGORY_ID.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
             user_id: "invalid_user_id".to_string(),
         };
 
-        let video = Videos::new(&video_input);
+        let video = Videos::new(&video_input, NOW);
 
         assert!(video.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_update_a_video_keeping_its_owner() {
+        let mut video = Videos::new(&VideosInput {
+            title: TITLE.to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+
+        let result = video.update(&VideosInput {
+            title: "New title".to_string(),
+            description: "New description".to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: "018b33b7-5b9a-72a7-942f-8c46275aeace".to_string(),
+        }, NOW);
+
+        assert!(result.is_ok());
+        assert_eq!(video.title, "New title");
+        assert_eq!(video.description, "New description");
+        assert_eq!(video.user_id.to_string(), USER_ID);
+    }
+
+    #[test]
+    fn should_return_an_error_when_updating_with_invalid_data() {
+        let mut video = Videos::new(&VideosInput {
+            title: TITLE.to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+
+        let result = video.update(&VideosInput {
+            title: "a".to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        }, NOW);
+
+        assert!(result.is_err());
+        assert_eq!(video.title, TITLE);
+    }
+
+    #[test]
+    fn should_patch_only_the_given_fields() {
+        let mut video = Videos::new(&VideosInput {
+            title: TITLE.to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap();
+
+        let result = video.patch(&VideosPatchInput {
+            title: Some("New title".to_string()),
+            description: None,
+            url: None,
+            category_id: None,
+        }, NOW);
+
+        assert!(result.is_ok());
+        assert_eq!(video.title, "New title");
+        assert_eq!(video.description, DESCRIPTION);
+        assert_eq!(video.url.to_string(), URL);
+    }
+
+    #[test]
+    fn should_return_an_error_when_category_id_is_missing() {
+        let video_input = VideosInput {
+            title: TITLE.to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: None,
+            user_id: USER_ID.to_string(),
+        };
+
+        let video = Videos::new(&video_input, NOW);
+
+        assert!(video.is_err());
+    }
+
+    #[test]
+    fn should_report_every_invalid_field() {
+        let video_input = VideosInput {
+            title: "a".to_string(),
+            description: "".to_string(),
+            url: "invalid".to_string(),
+            category_id: None,
+            user_id: USER_ID.to_string(),
+        };
+
+        let error = Videos::new(&video_input, NOW).unwrap_err();
+
+        assert_eq!(error.errors.len(), 4);
+
+        let title = error.errors.field("title").unwrap();
+        assert_eq!(title.code, "too_short");
+        assert_eq!(title.params.get("min"), Some(&serde_json::json!(4)));
+
+        assert_eq!(error.errors.field("description").unwrap().code, "required");
+        assert_eq!(error.errors.field("url").unwrap().code, "invalid");
+        assert_eq!(error.errors.field("category_id").unwrap().code, "required");
+    }
+
+    #[test]
+    fn should_serialize_validation_errors() {
+        let video_input = VideosInput {
+            title: "a".to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        };
+
+        let error = Videos::new(&video_input, NOW).unwrap_err();
+
+        assert_eq!(serde_json::to_value(&error.errors).unwrap(), serde_json::json!([{
+            "field": "title",
+            "code": "too_short",
+            "message": "Title must be at least 4 characters",
+            "params": { "min": 4 },
+        }]));
+    }
+
+    #[test]
+    fn should_soft_delete_and_restore_a_video() {
+        let video_input = VideosInput {
+            title: TITLE.to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        };
+
+        let mut video = Videos::new(&video_input, NOW).unwrap();
+
+        video.soft_delete(NOW);
+        assert!(video.is_deleted());
+
+        video.restore(NOW);
+        assert!(!video.is_deleted());
+    }
+
+    #[test]
+    fn should_start_a_new_video_at_version_1() {
+        let video_input = VideosInput {
+            title: TITLE.to_string(),
+            description: DESCRIPTION.to_string(),
+            url: URL.to_string(),
+            category_id: Some(CATEGORY_ID.to_string()),
+            user_id: USER_ID.to_string(),
+        };
+
+        let video = Videos::new(&video_input, NOW).unwrap();
+
+        assert_eq!(video.version, 1);
+    }
+}
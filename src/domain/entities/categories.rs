@@ -1,39 +1,49 @@
 use std::fmt::{Debug};
-use time::{Date, OffsetDateTime};
+use time::OffsetDateTime;
 use serde::{Serialize, Deserialize};
-use crate::domain::errors::domain_error::{as_descriptions, DomainError};
+use crate::domain::entities::users::SYSTEM_USER_ID;
+use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::{ValidationError, ValidationErrors};
 use crate::domain::value_objects::color::ColorEntity;
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::ids::{CategoryId, UserId};
 use crate::domain::value_objects::ValueObjectTrait;
+use crate::infrastructure::persistence::database::categories::CategoriesModel;
+
+pub const DEFAULT_CATEGORY_ID: &str = "00000000-0000-0000-0000-000000000001";
+pub const DEFAULT_CATEGORY_NAME: &str = "LIVRE";
+pub const DEFAULT_CATEGORY_COLOR: &str = "#ffffff";
 
 #[derive(Deserialize)]
 pub struct CategoriesInput {
     pub name: String,
     pub color: String,
+    #[serde(skip_deserializing)]
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Categories {
-    pub id: UniqueEntityID,
+    pub id: CategoryId,
     pub name: String,
     pub color: ColorEntity,
-    pub user_id: UniqueEntityID,
-    pub created_at: Date,
-    pub updated_at: Date,
+    pub user_id: UserId,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub deleted_at: Option<OffsetDateTime>,
+    pub version: i32,
 }
 
 impl Categories {
-    pub fn new(data: &CategoriesInput) -> Result<Self, DomainError> {
-        let mut errors: Vec<DomainError> = vec![];
+    pub fn new(data: &CategoriesInput, now: OffsetDateTime) -> Result<Self, DomainError> {
+        let mut errors = ValidationErrors::new();
 
         let name = match data.name.len() {
             0 => {
-                errors.push(DomainError::new("Name is required", ""));
+                errors.add(ValidationError::required("name", "Name is required"));
                 None
             }
-            1 | 2 | 3 => {
-                errors.push(DomainError::new("Name must be at least 4 characters", ""));
+            1..=3 => {
+                errors.add(ValidationError::too_short("name", 4, "Name must be at least 4 characters"));
                 None
             }
             _ => Some(data.name.to_string())
@@ -42,35 +52,100 @@ impl Categories {
         let color = match ColorEntity::new(Some(data.color.as_str())) {
             Ok(color) => Some(color),
             Err(error) => {
-                errors.push(error);
+                errors.extend(error.into_validation("color"));
                 None
             }
         };
 
-        let user_id = match UniqueEntityID::new(Some(data.user_id.as_str())) {
+        let user_id = match data.user_id.parse::<UserId>() {
             Ok(user_id) => Some(user_id),
             Err(error) => {
-                errors.push(error);
+                errors.extend(error.into_validation("user_id"));
                 None
             }
         };
 
-        if errors.len() > 0 {
-            let description = as_descriptions(errors);
-
-            return Err(DomainError::new("Invalid data", description.as_str()))
+        if !errors.is_empty() {
+            return Err(DomainError::validation(errors))
         }
 
-
-        let now = OffsetDateTime::now_utc().date();
-
         Ok(Categories {
-            id: UniqueEntityID::new(None).unwrap(),
+            id: CategoryId::generate(),
             name: name.unwrap(),
             color: color.unwrap(),
             user_id: user_id.unwrap(),
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            version: 1,
         })
     }
-}
\ No newline at end of file
+
+    pub fn default_id() -> CategoryId {
+        DEFAULT_CATEGORY_ID.parse().unwrap()
+    }
+
+    pub fn default_category(now: OffsetDateTime) -> Self {
+        Categories {
+            id: Self::default_id(),
+            name: DEFAULT_CATEGORY_NAME.to_string(),
+            color: ColorEntity::new(Some(DEFAULT_CATEGORY_COLOR)).unwrap(),
+            user_id: SYSTEM_USER_ID.parse().unwrap(),
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            version: 1,
+        }
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.id.to_string() == DEFAULT_CATEGORY_ID
+    }
+
+    pub fn update(&mut self, data: &CategoriesInput, now: OffsetDateTime) -> Result<(), DomainError> {
+        let category = Categories::new(&CategoriesInput {
+            name: data.name.to_string(),
+            color: data.color.to_string(),
+            user_id: self.user_id.to_string(),
+        }, now)?;
+
+        self.name = category.name;
+        self.color = category.color;
+        self.updated_at = category.updated_at;
+
+        Ok(())
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    pub fn soft_delete(&mut self, now: OffsetDateTime) {
+        if self.deleted_at.is_none() {
+            self.deleted_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    pub fn restore(&mut self, now: OffsetDateTime) {
+        if self.deleted_at.is_some() {
+            self.deleted_at = None;
+            self.updated_at = now;
+        }
+    }
+}
+
+impl From<CategoriesModel> for Categories {
+    fn from(model: CategoriesModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            color: ColorEntity::from(model.color),
+            user_id: model.user_id,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
+            version: model.version,
+        }
+    }
+}
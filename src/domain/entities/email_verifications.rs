@@ -0,0 +1,66 @@
+use time::{Duration, OffsetDateTime};
+use crate::domain::entities::tokens;
+use crate::domain::value_objects::ids::{EmailVerificationId, UserId};
+use crate::infrastructure::persistence::database::email_verifications::EmailVerificationsModel;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailVerifications {
+    pub id: EmailVerificationId,
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl EmailVerifications {
+    pub fn new(user_id: UserId, ttl: Duration, now: OffsetDateTime) -> (Self, String) {
+        let token = tokens::generate();
+
+        let verification = EmailVerifications {
+            id: EmailVerificationId::generate(),
+            user_id,
+            token_hash: tokens::hash(&token),
+            expires_at: now + ttl,
+            used_at: None,
+            created_at: now,
+        };
+
+        (verification, token)
+    }
+
+    pub fn hash(token: &str) -> String {
+        tokens::hash(token)
+    }
+
+    pub fn is_expired(&self, now: OffsetDateTime) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+
+    pub fn is_usable(&self, now: OffsetDateTime) -> bool {
+        !self.is_used() && !self.is_expired(now)
+    }
+
+    pub fn consume(&mut self, now: OffsetDateTime) {
+        if self.used_at.is_none() {
+            self.used_at = Some(now);
+        }
+    }
+}
+
+impl From<EmailVerificationsModel> for EmailVerifications {
+    fn from(model: EmailVerificationsModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at,
+            used_at: model.used_at,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -1,5 +1,9 @@
 pub mod videos;
 pub mod categories;
 pub mod users;
+pub mod refresh_tokens;
+pub mod email_verifications;
+pub mod password_resets;
+pub mod tokens;
 
 mod __tests__;
\ No newline at end of file
@@ -0,0 +1,66 @@
+use time::{Duration, OffsetDateTime};
+use crate::domain::entities::tokens;
+use crate::domain::value_objects::ids::{PasswordResetId, UserId};
+use crate::infrastructure::persistence::database::password_resets::PasswordResetsModel;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordResets {
+    pub id: PasswordResetId,
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl PasswordResets {
+    pub fn new(user_id: UserId, ttl: Duration, now: OffsetDateTime) -> (Self, String) {
+        let token = tokens::generate();
+
+        let reset = PasswordResets {
+            id: PasswordResetId::generate(),
+            user_id,
+            token_hash: tokens::hash(&token),
+            expires_at: now + ttl,
+            used_at: None,
+            created_at: now,
+        };
+
+        (reset, token)
+    }
+
+    pub fn hash(token: &str) -> String {
+        tokens::hash(token)
+    }
+
+    pub fn is_expired(&self, now: OffsetDateTime) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_at.is_some()
+    }
+
+    pub fn is_usable(&self, now: OffsetDateTime) -> bool {
+        !self.is_used() && !self.is_expired(now)
+    }
+
+    pub fn consume(&mut self, now: OffsetDateTime) {
+        if self.used_at.is_none() {
+            self.used_at = Some(now);
+        }
+    }
+}
+
+impl From<PasswordResetsModel> for PasswordResets {
+    fn from(model: PasswordResetsModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at,
+            used_at: model.used_at,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use time::{Duration, OffsetDateTime};
+use crate::domain::entities::tokens;
+use crate::domain::value_objects::ids::{RefreshTokenId, TokenFamilyId, UserId};
+use crate::infrastructure::persistence::database::refresh_tokens::RefreshTokensModel;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshTokens {
+    pub id: RefreshTokenId,
+    pub user_id: UserId,
+    pub family_id: TokenFamilyId,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub revoked_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl RefreshTokens {
+    pub fn new(user_id: UserId, family_id: TokenFamilyId, ttl: Duration, now: OffsetDateTime) -> (Self, String) {
+        let token = tokens::generate();
+
+        let refresh_token = RefreshTokens {
+            id: RefreshTokenId::generate(),
+            user_id,
+            family_id,
+            token_hash: RefreshTokens::hash(&token),
+            expires_at: now + ttl,
+            revoked_at: None,
+            created_at: now,
+        };
+
+        (refresh_token, token)
+    }
+
+    pub fn hash(token: &str) -> String {
+        tokens::hash(token)
+    }
+
+    pub fn is_expired(&self, now: OffsetDateTime) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn revoke(&mut self, now: OffsetDateTime) {
+        if self.revoked_at.is_none() {
+            self.revoked_at = Some(now);
+        }
+    }
+}
+
+impl From<RefreshTokensModel> for RefreshTokens {
+    fn from(model: RefreshTokensModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            family_id: model.family_id,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at,
+            revoked_at: model.revoked_at,
+            created_at: model.created_at,
+        }
+    }
+}
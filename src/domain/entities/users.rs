@@ -1,12 +1,17 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use time::{Date, OffsetDateTime};
-use crate::domain::errors::domain_error::{as_descriptions, DomainError};
+use time::OffsetDateTime;
+use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::{ValidationError, ValidationErrors};
 use crate::domain::value_objects::email::EmailEntity;
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::password::PasswordEntity;
+use crate::domain::value_objects::role::RoleEntity;
+use crate::domain::value_objects::ids::UserId;
 use crate::domain::value_objects::ValueObjectTrait;
 use crate::infrastructure::persistence::database::users::UsersModel;
 
+pub const SYSTEM_USER_ID: &str = "00000000-0000-0000-0000-000000000000";
+
 #[derive(Deserialize)]
 pub struct UsersInput {
     pub name: String,
@@ -14,31 +19,33 @@ pub struct UsersInput {
     pub password: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow, PartialEq)]
 pub struct Users {
-    pub id: UniqueEntityID,
+    pub id: UserId,
     pub name: String,
     pub email: EmailEntity,
-    pub password: String,
-    pub created_at: Date,
-    pub updated_at: Date,
+    pub password: PasswordEntity,
+    pub role: RoleEntity,
+    pub email_verified_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub deleted_at: Option<OffsetDateTime>,
+    pub version: i32,
 }
 
 impl Users {
-    pub fn new(data: &UsersInput) -> Result<Self, DomainError> {
-        let id = UniqueEntityID::new(None).unwrap();
+    pub fn new(data: &UsersInput, now: OffsetDateTime) -> Result<Self, DomainError> {
+        let id = UserId::generate();
 
-        let now = OffsetDateTime::now_utc().date();
-
-        let mut errors: Vec<DomainError> = vec![];
+        let mut errors = ValidationErrors::new();
 
         let name = match data.name.len() {
             0 => {
-                errors.push(DomainError::new("Name is required", ""));
+                errors.add(ValidationError::required("name", "Name is required"));
                 None
             },
-            1 | 2 | 3 => {
-                errors.push(DomainError::new("Name must be at least 4 characters", ""));
+            1..=3 => {
+                errors.add(ValidationError::too_short("name", 4, "Name must be at least 4 characters"));
                 None
             },
             _ => Some(data.name.to_string())
@@ -47,27 +54,21 @@ impl Users {
         let email = match EmailEntity::new(Some(data.email.as_str())) {
             Ok(email) => Some(email),
             Err(error) => {
-                errors.push(error);
+                errors.extend(error.into_validation("email"));
                 None
             }
         };
 
-        let password = match data.password.len() {
-            0 => {
-                errors.push(DomainError::new("Password is required", ""));
-                None
-            },
-            1 | 2 | 3 | 4 | 5 | 6 | 7  => {
-                errors.push(DomainError::new("Password must be at least 8 characters", ""));
+        let password = match PasswordEntity::new(Some(data.password.as_str())) {
+            Ok(password) => Some(password),
+            Err(error) => {
+                errors.extend(error.into_validation("password"));
                 None
-            },
-            _ => Some(data.password.to_string())
+            }
         };
 
-        if errors.len() > 0 {
-            let description = as_descriptions(errors);
-
-            return Err(DomainError::new("Invalid data", description.as_str()))
+        if !errors.is_empty() {
+            return Err(DomainError::validation(errors))
         }
 
         Ok(Users {
@@ -75,21 +76,74 @@ impl Users {
             name: name.unwrap(),
             email: email.unwrap(),
             password: password.unwrap(),
+            role: RoleEntity::new(None).unwrap(),
+            email_verified_at: None,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            version: 1,
         })
     }
+
+    pub fn is_system(&self) -> bool {
+        self.id.to_string() == SYSTEM_USER_ID
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
+
+    pub fn verify_email(&mut self, now: OffsetDateTime) {
+        if self.email_verified_at.is_none() {
+            self.email_verified_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    pub fn change_password(&mut self, password: &str, now: OffsetDateTime) -> Result<(), DomainError> {
+        self.password = PasswordEntity::new(Some(password))?;
+        self.updated_at = now;
+
+        Ok(())
+    }
+
+    pub fn change_role(&mut self, role: RoleEntity, now: OffsetDateTime) {
+        self.role = role;
+        self.updated_at = now;
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    pub fn soft_delete(&mut self, now: OffsetDateTime) {
+        if self.deleted_at.is_none() {
+            self.deleted_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    pub fn restore(&mut self, now: OffsetDateTime) {
+        if self.deleted_at.is_some() {
+            self.deleted_at = None;
+            self.updated_at = now;
+        }
+    }
 }
 
 impl From<UsersModel> for Users {
     fn from(model: UsersModel) -> Self {
         Self {
-            id: UniqueEntityID::new(Some(model.id.to_string().as_str())).unwrap(),
+            id: model.id,
             name: model.name,
             email: EmailEntity::new(Some(model.email.as_str())).unwrap(),
-            password: model.password,
+            password: PasswordEntity::from_hash(model.password.as_str()),
+            role: RoleEntity::new(Some(model.role.as_str())).unwrap(),
+            email_verified_at: model.email_verified_at,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
+            version: model.version,
         }
     }
 }
\ No newline at end of file
@@ -1,44 +1,57 @@
 use std::fmt::{Debug};
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::ids::{CategoryId, UserId, VideoId};
 use crate::domain::value_objects::url::UrlEntity;
-use crate::domain::errors::domain_error::{as_descriptions, DomainError};
+use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::{ValidationError, ValidationErrors};
 
-use time::{Date, OffsetDateTime};
+use time::OffsetDateTime;
 use serde::{Serialize, Deserialize};
 use crate::domain::value_objects::ValueObjectTrait;
+use crate::infrastructure::persistence::database::videos::VideosModel;
 
 #[derive(Deserialize)]
 pub struct VideosInput {
     pub title: String,
     pub description: String,
     pub url: String,
-    pub category_id: String,
+    pub category_id: Option<String>,
+    #[serde(skip_deserializing)]
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Deserialize)]
+pub struct VideosPatchInput {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub category_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Videos {
-    pub id: UniqueEntityID,
+    pub id: VideoId,
     pub title: String,
     pub description: String,
     pub url: UrlEntity,
-    pub category_id: UniqueEntityID,
-    pub user_id: UniqueEntityID,
-    pub created_at: Date,
-    pub updated_at: Date,
+    pub category_id: CategoryId,
+    pub user_id: UserId,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub deleted_at: Option<OffsetDateTime>,
+    pub version: i32,
 }
 
 impl Videos {
-    pub fn new(data: &VideosInput) -> Result<Self, DomainError> {
-        let mut errors: Vec<DomainError> = vec![];
+    pub fn new(data: &VideosInput, now: OffsetDateTime) -> Result<Self, DomainError> {
+        let mut errors = ValidationErrors::new();
 
         let title = match data.title.len() {
             0 => {
-                errors.push(DomainError::new("Title is required", ""));
+                errors.add(ValidationError::required("title", "Title is required"));
                 None
             },
-            1 | 2 | 3 => {
-                errors.push(DomainError::new("Title must be at least 4 characters", ""));
+            1..=3 => {
+                errors.add(ValidationError::too_short("title", 4, "Title must be at least 4 characters"));
                 None
             },
             _ => Some(data.title.to_string())
@@ -46,11 +59,11 @@ impl Videos {
 
         let description = match data.description.len() {
             0 => {
-                errors.push(DomainError::new("Description is required", ""));
+                errors.add(ValidationError::required("description", "Description is required"));
                 None
             },
-            1 | 2 | 3 | 4 => {
-                errors.push(DomainError::new("Description must be at least 5 characters", ""));
+            1..=4 => {
+                errors.add(ValidationError::too_short("description", 5, "Description must be at least 5 characters"));
                 None
             },
             _ => Some(data.description.to_string())
@@ -59,38 +72,39 @@ impl Videos {
         let url = match UrlEntity::new(Some(data.url.as_str())) {
             Ok(url) => Some(url),
             Err(error) => {
-                errors.push(error);
+                errors.extend(error.into_validation("url"));
                 None
             }
         };
 
-        let category_id = match UniqueEntityID::new(Some(data.category_id.as_str())) {
-            Ok(category_id) => Some(category_id),
-            Err(error) => {
-                errors.push(error);
+        let category_id = match data.category_id.as_deref() {
+            None => {
+                errors.add(ValidationError::required("category_id", "Category is required"));
                 None
+            },
+            Some(category_id) => match category_id.parse::<CategoryId>() {
+                Ok(category_id) => Some(category_id),
+                Err(error) => {
+                    errors.extend(error.into_validation("category_id"));
+                    None
+                }
             }
         };
 
-        let user_id = match UniqueEntityID::new(Some(data.user_id.as_str())) {
+        let user_id = match data.user_id.parse::<UserId>() {
             Ok(user_id) => Some(user_id),
             Err(error) => {
-                errors.push(error);
+                errors.extend(error.into_validation("user_id"));
                 None
             }
         };
 
-        if errors.len() > 0 {
-            let description = as_descriptions(errors);
-
-            return Err(DomainError::new("Invalid data", description.as_str()));
+        if !errors.is_empty() {
+            return Err(DomainError::validation(errors));
         }
 
-
-        let now = OffsetDateTime::now_utc().date();
-
         Ok(Videos {
-            id: UniqueEntityID::new(None).unwrap(),
+            id: VideoId::generate(),
             title: title.unwrap(),
             description: description.unwrap(),
             url: url.unwrap(),
@@ -98,6 +112,78 @@ impl Videos {
             user_id: user_id.unwrap(),
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            version: 1,
         })
     }
+
+    pub fn update(&mut self, data: &VideosInput, now: OffsetDateTime) -> Result<(), DomainError> {
+        let video = Videos::new(&VideosInput {
+            title: data.title.to_string(),
+            description: data.description.to_string(),
+            url: data.url.to_string(),
+            category_id: data.category_id.clone(),
+            user_id: self.user_id.to_string(),
+        }, now)?;
+
+        self.title = video.title;
+        self.description = video.description;
+        self.url = video.url;
+        self.category_id = video.category_id;
+        self.updated_at = video.updated_at;
+
+        Ok(())
+    }
+
+    pub fn patch(&mut self, data: &VideosPatchInput, now: OffsetDateTime) -> Result<(), DomainError> {
+        let input = VideosInput {
+            title: data.title.clone().unwrap_or(self.title.to_string()),
+            description: data.description.clone().unwrap_or(self.description.to_string()),
+            url: data.url.clone().unwrap_or(self.url.to_string()),
+            category_id: Some(data.category_id.clone().unwrap_or(self.category_id.to_string())),
+            user_id: self.user_id.to_string(),
+        };
+
+        self.update(&input, now)
+    }
+
+    pub fn move_to_category(&mut self, category_id: CategoryId, now: OffsetDateTime) {
+        self.category_id = category_id;
+        self.updated_at = now;
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    pub fn soft_delete(&mut self, now: OffsetDateTime) {
+        if self.deleted_at.is_none() {
+            self.deleted_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    pub fn restore(&mut self, now: OffsetDateTime) {
+        if self.deleted_at.is_some() {
+            self.deleted_at = None;
+            self.updated_at = now;
+        }
+    }
+}
+
+impl From<VideosModel> for Videos {
+    fn from(model: VideosModel) -> Self {
+        Self {
+            id: model.id,
+            title: model.title,
+            description: model.description,
+            url: UrlEntity::from(model.url),
+            category_id: model.category_id,
+            user_id: model.user_id,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
+            version: model.version,
+        }
+    }
 }
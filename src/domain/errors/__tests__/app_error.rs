@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod test_app_error {
+    use http::StatusCode;
+    use serde_json::json;
+    use crate::domain::errors::app_error::AppError;
+    use crate::domain::errors::domain_error::DomainError;
+    use crate::domain::errors::validation_errors::ValidationError;
+
+    #[test]
+    fn it_should_build_a_problem_from_the_status() {
+        let error = AppError::new("Videos not found", StatusCode::NOT_FOUND, None);
+
+        let problem = error.problem();
+
+        assert_eq!(problem.problem_type, "about:blank");
+        assert_eq!(problem.title, "Not Found");
+        assert_eq!(problem.status, 404);
+        assert_eq!(problem.detail, "Videos not found");
+        assert!(problem.instance.is_none());
+        assert!(problem.errors.is_empty());
+    }
+
+    #[test]
+    fn it_should_serialize_field_errors_as_an_extension() {
+        let domain = DomainError::from(ValidationError::too_short("title", 4, "Title must be at least 4 characters"));
+        let error = AppError::new("Videos domain error", StatusCode::UNPROCESSABLE_ENTITY, Some(domain));
+
+        let problem = error.problem().with_instance("/videos");
+
+        assert_eq!(serde_json::to_value(&problem).unwrap(), json!({
+            "type": "about:blank",
+            "title": "Unprocessable Entity",
+            "status": 422,
+            "detail": "Videos domain error: Title must be at least 4 characters",
+            "instance": "/videos",
+            "errors": [{
+                "field": "title",
+                "code": "too_short",
+                "message": "Title must be at least 4 characters",
+                "params": { "min": 4 },
+            }],
+        }));
+    }
+
+    #[test]
+    fn it_should_omit_empty_extensions() {
+        let error = AppError::new("Invalid sort order", StatusCode::BAD_REQUEST, None);
+
+        let problem = serde_json::to_value(error.problem()).unwrap();
+
+        assert!(problem.get("instance").is_none());
+        assert!(problem.get("errors").is_none());
+    }
+
+    #[test]
+    fn it_should_keep_retry_after_out_of_the_problem_body() {
+        let error = AppError::new("Too many sign-in attempts, try again later", StatusCode::TOO_MANY_REQUESTS, None)
+            .with_retry_after(0);
+
+        assert_eq!(error.retry_after(), Some(1));
+        assert!(serde_json::to_value(error.problem()).unwrap().get("retry_after").is_none());
+    }
+}
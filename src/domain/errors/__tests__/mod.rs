@@ -0,0 +1 @@
+mod app_error;
@@ -1,29 +1,88 @@
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
+use http::StatusCode;
+use serde::Serialize;
 use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::ValidationErrors;
+
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+pub const DEFAULT_PROBLEM_TYPE: &str = "about:blank";
 
 pub struct AppError {
     message: String,
     domain: Option<DomainError>,
-    code: u32,
+    status: StatusCode,
+    retry_after: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Problem {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(skip_serializing_if = "ValidationErrors::is_empty")]
+    pub errors: ValidationErrors,
 }
 
 impl AppError {
-    pub fn new(message: &str, code: u32, domain: Option<DomainError>) -> AppError {
+    pub fn new(message: &str, status: StatusCode, domain: Option<DomainError>) -> AppError {
         AppError {
             message: message.to_string(),
             domain,
-            code,
+            status,
+            retry_after: None,
         }
     }
 
+    pub fn with_retry_after(mut self, seconds: i64) -> AppError {
+        self.retry_after = Some(seconds.max(1));
+        self
+    }
+
+    pub fn retry_after(&self) -> Option<i64> {
+        self.retry_after
+    }
+
     pub fn message(&self) -> &str {
         &self.message
     }
 
-    pub fn code(&self) -> u32 {
-        self.code
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn domain(&self) -> Option<&DomainError> {
+        self.domain.as_ref()
+    }
+
+    pub fn problem(&self) -> Problem {
+        let mut detail = vec![self.message.clone()];
+
+        if let Some(ref domain) = self.domain {
+            detail.push(domain.message.clone());
+            detail.extend(domain.description.clone());
+        }
+
+        Problem {
+            problem_type: DEFAULT_PROBLEM_TYPE.to_string(),
+            title: self.status.canonical_reason().unwrap_or("Unknown Error").to_string(),
+            status: self.status.as_u16(),
+            detail: detail.join(": "),
+            instance: None,
+            errors: self.domain.as_ref().map(|domain| domain.errors.clone()).unwrap_or_default(),
+        }
+    }
+}
+
+impl Problem {
+    pub fn with_instance(mut self, instance: &str) -> Problem {
+        self.instance = Some(instance.to_string());
+        self
     }
 }
 
@@ -31,12 +90,12 @@ impl Error for AppError {}
 
 impl Display for AppError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Application Error: {}, code: {}", self.message, self.code)
+        write!(f, "Application Error: {}, status: {}", self.message, self.status)
     }
 }
 
 impl Debug for AppError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "Application Error: {}, code: {}", self.message, self.code)
+        write!(f, "Application Error: {}, status: {}", self.message, self.status)
     }
 }
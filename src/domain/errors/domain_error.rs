@@ -1,10 +1,12 @@
 use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
+use crate::domain::errors::validation_errors::{ValidationError, ValidationErrors};
 
 pub struct DomainError {
     pub message: String,
     pub description: Option<String>,
+    pub errors: ValidationErrors,
 }
 
 impl DomainError {
@@ -12,8 +14,44 @@ impl DomainError {
         DomainError {
             message: message.to_string(),
             description: if description.is_empty() { None } else { Some(description.to_string()) },
+            errors: ValidationErrors::new(),
         }
     }
+
+    pub fn validation(errors: ValidationErrors) -> DomainError {
+        DomainError {
+            message: "Invalid data".to_string(),
+            description: Some(errors.to_string()),
+            errors,
+        }
+    }
+
+    pub fn is_validation(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    pub fn into_validation(self, field: &str) -> ValidationErrors {
+        match self.errors.is_empty() {
+            true => ValidationErrors::from(ValidationError::invalid(field, &self.message)),
+            false => self.errors.with_field(field),
+        }
+    }
+}
+
+impl From<ValidationError> for DomainError {
+    fn from(error: ValidationError) -> Self {
+        DomainError {
+            message: error.message.clone(),
+            description: None,
+            errors: ValidationErrors::from(error),
+        }
+    }
+}
+
+impl From<ValidationErrors> for DomainError {
+    fn from(errors: ValidationErrors) -> Self {
+        DomainError::validation(errors)
+    }
 }
 
 impl Error for DomainError {}
@@ -35,30 +73,3 @@ impl Debug for DomainError {
         }
     }
 }
-
-pub fn as_descriptions(errors: Vec<DomainError>) -> String {
-    let mut description = String::from("");
-
-    for (i, error) in errors.iter().enumerate() {
-        if i == 0 {
-            if errors.len() == 1 {
-                description = error.to_string();
-            } else {
-                description.push_str("[\n");
-                description.push_str("  ");
-                description.push_str(error.to_string().as_str());
-                description.push_str(",\n");
-            }
-        } else if i == errors.len() - 1 {
-            description.push_str("  ");
-            description.push_str(error.to_string().as_str());
-            description.push_str("\n]");
-        } else {
-            description.push_str("  ");
-            description.push_str(error.to_string().as_str());
-            description.push_str(",\n");
-        }
-    }
-
-    description
-}
\ No newline at end of file
@@ -1,2 +1,5 @@
+mod __tests__;
+
 pub mod app_error;
-pub mod domain_error;
\ No newline at end of file
+pub mod domain_error;
+pub mod validation_errors;
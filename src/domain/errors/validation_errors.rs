@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use serde::Serialize;
+use serde_json::Value;
+
+pub const REQUIRED: &str = "required";
+pub const TOO_SHORT: &str = "too_short";
+pub const INVALID: &str = "invalid";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidationError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, Value>,
+}
+
+impl ValidationError {
+    pub fn new(field: &str, code: &str, message: &str) -> ValidationError {
+        ValidationError {
+            field: field.to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    pub fn required(field: &str, message: &str) -> ValidationError {
+        ValidationError::new(field, REQUIRED, message)
+    }
+
+    pub fn too_short(field: &str, min: usize, message: &str) -> ValidationError {
+        ValidationError::new(field, TOO_SHORT, message).with_param("min", min)
+    }
+
+    pub fn invalid(field: &str, message: &str) -> ValidationError {
+        ValidationError::new(field, INVALID, message)
+    }
+
+    pub fn with_param<V: Into<Value>>(mut self, key: &str, value: V) -> ValidationError {
+        self.params.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> ValidationErrors {
+        ValidationErrors { errors: vec![] }
+    }
+
+    pub fn add(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    pub fn extend(&mut self, errors: ValidationErrors) {
+        self.errors.extend(errors.errors);
+    }
+
+    pub fn with_field(mut self, field: &str) -> ValidationErrors {
+        for error in self.errors.iter_mut() {
+            error.field = field.to_string();
+        }
+
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    pub fn field(&self, field: &str) -> Option<&ValidationError> {
+        self.errors.iter().find(|error| error.field == field)
+    }
+}
+
+impl From<ValidationError> for ValidationErrors {
+    fn from(error: ValidationError) -> Self {
+        ValidationErrors { errors: vec![error] }
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let errors: Vec<String> = self.errors.iter().map(|error| error.to_string()).collect();
+
+        write!(f, "{}", errors.join("; "))
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod test_typed_ids {
+    use std::str::FromStr;
+    use uuid::Uuid;
+    use crate::domain::value_objects::ids::{CategoryId, VideoId};
+
+    const VALID_UUID: &str = "018b33fc-e22c-79a9-9fae-2f50e95e125b";
+
+    #[test]
+    fn it_should_generate_a_uuid_v7() {
+        let id = VideoId::generate();
+
+        assert_eq!(id.value().get_version_num(), 7);
+    }
+
+    #[test]
+    fn it_should_parse_a_valid_uuid() {
+        let id = VideoId::from_str(VALID_UUID);
+
+        assert_eq!(id.unwrap().to_string(), VALID_UUID);
+    }
+
+    #[test]
+    fn it_should_not_parse_an_invalid_uuid() {
+        let id = "invalid-uuid".parse::<VideoId>();
+
+        assert!(id.is_err());
+    }
+
+    #[test]
+    fn it_should_have_the_same_size_as_a_uuid() {
+        assert_eq!(std::mem::size_of::<VideoId>(), std::mem::size_of::<Uuid>());
+    }
+
+    #[test]
+    fn it_should_serialize_as_a_plain_string() {
+        let id: CategoryId = VALID_UUID.parse().unwrap();
+
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, format!("\"{}\"", VALID_UUID));
+        assert_eq!(serde_json::from_str::<CategoryId>(&json).unwrap(), id);
+    }
+}
@@ -1,4 +1,6 @@
-mod unique_id;
+mod ids;
 mod email;
 mod url;
-mod color;
\ No newline at end of file
+mod color;
+mod password;
+mod role;
\ No newline at end of file
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod test_password_value_object {
+    use crate::domain::value_objects::password::{Password, PasswordEntity, DISABLED_PASSWORD_HASH};
+    use crate::domain::value_objects::ValueObjectTrait;
+
+    const VALID_PASSWORD: &str = "12345678";
+
+    #[test]
+    fn it_should_hash_the_password_with_argon2id() {
+        let password = PasswordEntity::new(Some(VALID_PASSWORD)).unwrap();
+
+        assert!(matches!(password.value(), Password::Hashed(_)));
+        assert!(password.to_string().starts_with("$argon2id$"));
+        assert_ne!(password.to_string(), VALID_PASSWORD);
+    }
+
+    #[test]
+    fn it_should_not_create_a_password_shorter_than_8_characters() {
+        let password = PasswordEntity::new(Some("1234567"));
+
+        assert!(password.is_err());
+    }
+
+    #[test]
+    fn it_should_not_create_an_empty_password() {
+        let password = PasswordEntity::new(Some(""));
+
+        assert!(password.is_err());
+    }
+
+    #[test]
+    fn it_should_verify_a_hashed_password() {
+        let password = PasswordEntity::new(Some(VALID_PASSWORD)).unwrap();
+
+        assert!(password.verify(VALID_PASSWORD));
+        assert!(!password.verify("87654321"));
+        assert!(!password.needs_rehash());
+    }
+
+    #[test]
+    fn it_should_load_a_stored_hash() {
+        let hash = PasswordEntity::new(Some(VALID_PASSWORD)).unwrap().to_string();
+
+        let password = PasswordEntity::from_hash(&hash);
+
+        assert!(matches!(password.value(), Password::Hashed(_)));
+        assert!(password.verify(VALID_PASSWORD));
+    }
+
+    #[test]
+    fn it_should_verify_and_flag_a_legacy_plaintext_password() {
+        let password = PasswordEntity::from_hash(VALID_PASSWORD);
+
+        assert!(matches!(password.value(), Password::Plain(_)));
+        assert!(password.verify(VALID_PASSWORD));
+        assert!(!password.verify("123456789"));
+        assert!(password.needs_rehash());
+    }
+
+    #[test]
+    fn it_should_never_verify_a_disabled_password() {
+        let password = PasswordEntity::from_hash(DISABLED_PASSWORD_HASH);
+
+        assert!(matches!(password.value(), Password::Hashed(_)));
+        assert!(!password.verify(DISABLED_PASSWORD_HASH));
+        assert!(!password.verify("disabled"));
+        assert!(!password.verify(""));
+    }
+
+    #[test]
+    fn it_should_not_leak_the_hash_when_debugging() {
+        let password = PasswordEntity::new(Some(VALID_PASSWORD)).unwrap();
+
+        assert_eq!(format!("{:?}", password), "********");
+    }
+
+    #[test]
+    fn it_should_report_the_minimum_length() {
+        let error = PasswordEntity::new(Some("1234567")).unwrap_err();
+
+        let field = error.errors.field("password").unwrap();
+
+        assert_eq!(field.code, "too_short");
+        assert_eq!(field.params.get("min"), Some(&serde_json::json!(8)));
+    }
+}
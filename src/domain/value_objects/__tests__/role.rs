@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod test_role_value_object {
+    use crate::domain::value_objects::role::{Permission, Role, RoleEntity, DEFAULT_ROLE};
+    use crate::domain::value_objects::ValueObjectTrait;
+
+    #[test]
+    fn it_should_parse_a_role_ignoring_case() {
+        let role = RoleEntity::new(Some(" Moderator ")).unwrap();
+
+        assert_eq!(role.value(), &Role::Moderator);
+        assert_eq!(role.to_string(), "moderator");
+    }
+
+    #[test]
+    fn it_should_use_the_default_role_when_none_is_given() {
+        let role = RoleEntity::new(None).unwrap();
+
+        assert_eq!(role.value(), &DEFAULT_ROLE);
+    }
+
+    #[test]
+    fn it_should_not_create_an_unknown_role() {
+        let error = RoleEntity::new(Some("owner")).unwrap_err();
+
+        assert_eq!(error.errors.field("role").unwrap().code, "invalid");
+    }
+
+    #[test]
+    fn it_should_not_let_a_viewer_write() {
+        assert!(Role::Viewer.permissions().is_empty());
+    }
+
+    #[test]
+    fn it_should_only_let_moderators_and_admins_modify_any_video() {
+        assert!(!Role::Creator.can(Permission::ModifyAnyVideo));
+        assert!(Role::Moderator.can(Permission::ModifyAnyVideo));
+        assert!(Role::Admin.can(Permission::ModifyAnyVideo));
+    }
+
+    #[test]
+    fn it_should_only_let_admins_manage_users() {
+        assert!(!Role::Moderator.can(Permission::ManageUsers));
+        assert!(Role::Admin.can(Permission::ManageUsers));
+    }
+
+    #[test]
+    fn it_should_only_let_admins_purge_deleted_records() {
+        assert!(!Role::Moderator.can(Permission::PurgeDeleted));
+        assert!(Role::Admin.can(Permission::PurgeDeleted));
+    }
+}
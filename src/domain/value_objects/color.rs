@@ -3,6 +3,7 @@ use lazy_static::lazy_static;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::ValidationError;
 use crate::domain::value_objects::{ValueObject, ValueObjectTrait};
 
 lazy_static! {
@@ -11,7 +12,8 @@ lazy_static! {
     ).unwrap();
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub enum Color {
     RGB {
         red: u8,
@@ -40,16 +42,16 @@ pub enum Color {
     },
 }
 
-impl ToString for Color {
-    fn to_string(&self) -> String {
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Color::RGB { red, green, blue } => format!("rgb({}, {}, {})", red, green, blue),
-            Color::RGBA { red, green, blue, alpha } => format!("rgba({}, {}, {}, {})", red, green, blue, alpha),
-            Color::Transparent => String::from("transparent"),
-            Color::Hex3(value) => value.to_string(),
-            Color::Hex6(value) => value.to_string(),
-            Color::HSL { hue, saturation, lightness } => format!("hsl({}, {}%, {}%)", hue, saturation, lightness),
-            Color::HSLA { hue, saturation, lightness, alpha } => format!("hsla({}, {}%, {}%, {})", hue, saturation, lightness, alpha),
+            Color::RGB { red, green, blue } => write!(f, "rgb({}, {}, {})", red, green, blue),
+            Color::RGBA { red, green, blue, alpha } => write!(f, "rgba({}, {}, {}, {})", red, green, blue, alpha),
+            Color::Transparent => write!(f, "transparent"),
+            Color::Hex3(value) => write!(f, "{}", value),
+            Color::Hex6(value) => write!(f, "{}", value),
+            Color::HSL { hue, saturation, lightness } => write!(f, "hsl({}, {}%, {}%)", hue, saturation, lightness),
+            Color::HSLA { hue, saturation, lightness, alpha } => write!(f, "hsla({}, {}%, {}%, {})", hue, saturation, lightness, alpha),
         }
     }
 }
@@ -131,15 +133,15 @@ impl ValueObjectTrait<Color> for ColorEntity {
 
                                 Ok(ColorEntity { value: Color::HSLA { hue, saturation, lightness, alpha } })
                             } else {
-                                Err(DomainError::new("Invalid color", ""))
+                                Err(DomainError::from(ValidationError::invalid("color", "Invalid color")))
                             }
                         }
                     }
                 } else {
-                    Err(DomainError::new("Invalid color", ""))
+                    Err(DomainError::from(ValidationError::invalid("color", "Invalid color")))
                 }
             },
-            None => Err(DomainError::new("Color is required", ""))
+            None => Err(DomainError::from(ValidationError::required("color", "Color is required")))
         }
     }
 
@@ -3,6 +3,7 @@ use regex::Regex;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::ValidationError;
 use crate::domain::value_objects::{ValueObject, ValueObjectTrait};
 
 lazy_static! {
@@ -63,10 +64,10 @@ impl ValueObjectTrait<Email> for EmailEntity {
                         value: Email::Email(value.to_string())
                     })
                 } else {
-                    Err(DomainError::new("Invalid email", ""))
+                    Err(DomainError::from(ValidationError::invalid("email", "Invalid email")))
                 }
             },
-            None => Err(DomainError::new("Email is required", ""))
+            None => Err(DomainError::from(ValidationError::required("email", "Email is required")))
         }
     }
 
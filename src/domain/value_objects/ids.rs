@@ -0,0 +1,73 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+use uuid::Uuid;
+use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::ValidationError;
+
+macro_rules! typed_id {
+    ($name:ident) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type)]
+        #[serde(transparent)]
+        #[sqlx(transparent)]
+        #[repr(transparent)]
+        pub struct $name(Uuid);
+
+        impl $name {
+            pub fn generate() -> Self {
+                Self(Uuid::now_v7())
+            }
+
+            pub const fn from_uuid(value: Uuid) -> Self {
+                Self(value)
+            }
+
+            pub fn value(&self) -> &Uuid {
+                &self.0
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = DomainError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match Uuid::parse_str(value) {
+                    Ok(value) => Ok(Self(value)),
+                    Err(_) => Err(DomainError::from(ValidationError::invalid("id", "Invalid UUID"))),
+                }
+            }
+        }
+
+        impl From<Uuid> for $name {
+            fn from(value: Uuid) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+typed_id!(VideoId);
+typed_id!(CategoryId);
+typed_id!(UserId);
+typed_id!(RefreshTokenId);
+typed_id!(TokenFamilyId);
+typed_id!(EmailVerificationId);
+typed_id!(PasswordResetId);
@@ -2,19 +2,22 @@ use crate::domain::errors::domain_error::DomainError;
 
 mod __tests__;
 
-pub mod unique_id;
+pub mod ids;
 pub mod url;
 pub mod color;
 pub mod email;
+pub mod password;
+pub mod role;
 
 use serde::{Serialize, Deserialize};
-use sqlx::{FromRow, Type};
+use sqlx::FromRow;
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, FromRow)]
 pub struct ValueObject<T> {
     value: T,
 }
 
+#[allow(clippy::new_ret_no_self)]
 pub trait ValueObjectTrait<T> {
     fn new(value: Option<&str>) -> Result<ValueObject<T>, DomainError>;
     fn value(&self) -> &T;
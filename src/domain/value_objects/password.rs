@@ -0,0 +1,129 @@
+use std::fmt::{Debug, Formatter};
+use argon2::{Algorithm, Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::ValidationError;
+use crate::domain::value_objects::{ValueObject, ValueObjectTrait};
+
+pub const PASSWORD_MIN_LENGTH: usize = 8;
+pub const DISABLED_PASSWORD_HASH: &str = "$disabled";
+
+lazy_static! {
+    static ref DUMMY_PASSWORD: PasswordEntity = PasswordEntity::hash("dummy-password").unwrap();
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub enum Password {
+    Hashed(String),
+    Plain(String),
+}
+
+pub type PasswordEntity = ValueObject<Password>;
+
+impl Debug for PasswordEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "********")
+    }
+}
+
+impl TryFrom<PasswordEntity> for String {
+    type Error = DomainError;
+
+    fn try_from(value: PasswordEntity) -> Result<Self, Self::Error> {
+        Ok(value.to_string())
+    }
+}
+
+impl From<String> for PasswordEntity {
+    fn from(value: String) -> Self {
+        PasswordEntity::from_hash(value.as_str())
+    }
+}
+
+impl PasswordEntity {
+    pub fn validate(value: &str) -> Result<(), DomainError> {
+        match value.len() {
+            0 => Err(DomainError::from(ValidationError::required("password", "Password is required"))),
+            length if length < PASSWORD_MIN_LENGTH => Err(DomainError::from(ValidationError::too_short("password", PASSWORD_MIN_LENGTH, "Password must be at least 8 characters"))),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn hash(value: &str) -> Result<PasswordEntity, DomainError> {
+        let salt = SaltString::generate(&mut OsRng);
+
+        match Argon2::default().hash_password(value.as_bytes(), &salt) {
+            Ok(hash) => Ok(PasswordEntity { value: Password::Hashed(hash.to_string()) }),
+            Err(error) => Err(DomainError::new("Password hashing error", error.to_string().as_str())),
+        }
+    }
+
+    pub fn from_hash(value: &str) -> PasswordEntity {
+        match PasswordHash::new(value) {
+            Ok(_) => PasswordEntity { value: Password::Hashed(value.to_string()) },
+            Err(_) => PasswordEntity { value: Password::Plain(value.to_string()) },
+        }
+    }
+
+    pub fn verify(&self, value: &str) -> bool {
+        match &self.value {
+            Password::Hashed(hash) => match PasswordHash::new(hash) {
+                Ok(hash) => Argon2::default().verify_password(value.as_bytes(), &hash).is_ok(),
+                Err(_) => false,
+            },
+            Password::Plain(plain) => constant_time_eq(plain.as_bytes(), value.as_bytes()),
+        }
+    }
+
+    pub fn verify_dummy(value: &str) -> bool {
+        DUMMY_PASSWORD.verify(value)
+    }
+
+    pub fn needs_rehash(&self) -> bool {
+        match &self.value {
+            Password::Hashed(hash) => match PasswordHash::new(hash) {
+                Ok(hash) => hash.algorithm != Algorithm::Argon2id.ident(),
+                Err(_) => true,
+            },
+            Password::Plain(_) => true,
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+impl ValueObjectTrait<Password> for PasswordEntity {
+    fn new(value: Option<&str>) -> Result<PasswordEntity, DomainError> {
+        match value {
+            Some(value) => {
+                PasswordEntity::validate(value)?;
+                PasswordEntity::hash(value)
+            },
+            None => Err(DomainError::from(ValidationError::required("password", "Password is required")))
+        }
+    }
+
+    fn value(&self) -> &Password {
+        &self.value
+    }
+
+    fn equals(&self, other: &PasswordEntity) -> bool {
+        self.value == other.value
+    }
+
+    fn to_string(&self) -> String {
+        match &self.value {
+            Password::Hashed(hash) => hash.to_string(),
+            Password::Plain(plain) => plain.to_string(),
+        }
+    }
+}
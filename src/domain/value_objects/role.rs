@@ -0,0 +1,129 @@
+use std::fmt::{Debug, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::ValidationError;
+use crate::domain::value_objects::{ValueObject, ValueObjectTrait};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Viewer,
+    Creator,
+    Moderator,
+    Admin,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Permission {
+    CreateVideo,
+    ModifyOwnVideo,
+    ModifyAnyVideo,
+    CreateCategory,
+    ModifyOwnCategory,
+    ModifyAnyCategory,
+    ManageUsers,
+    PurgeDeleted,
+}
+
+pub const DEFAULT_ROLE: Role = Role::Creator;
+
+const CREATOR_PERMISSIONS: &[Permission] = &[
+    Permission::CreateVideo,
+    Permission::ModifyOwnVideo,
+    Permission::CreateCategory,
+    Permission::ModifyOwnCategory,
+];
+
+const MODERATOR_PERMISSIONS: &[Permission] = &[
+    Permission::CreateVideo,
+    Permission::ModifyOwnVideo,
+    Permission::ModifyAnyVideo,
+    Permission::CreateCategory,
+    Permission::ModifyOwnCategory,
+    Permission::ModifyAnyCategory,
+];
+
+const ADMIN_PERMISSIONS: &[Permission] = &[
+    Permission::CreateVideo,
+    Permission::ModifyOwnVideo,
+    Permission::ModifyAnyVideo,
+    Permission::CreateCategory,
+    Permission::ModifyOwnCategory,
+    Permission::ModifyAnyCategory,
+    Permission::ManageUsers,
+    Permission::PurgeDeleted,
+];
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Creator => "creator",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        }
+    }
+
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Role::Viewer => &[],
+            Role::Creator => CREATOR_PERMISSIONS,
+            Role::Moderator => MODERATOR_PERMISSIONS,
+            Role::Admin => ADMIN_PERMISSIONS,
+        }
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.permissions().contains(&permission)
+    }
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+pub type RoleEntity = ValueObject<Role>;
+
+impl Debug for RoleEntity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl From<Role> for RoleEntity {
+    fn from(value: Role) -> Self {
+        RoleEntity { value }
+    }
+}
+
+impl ValueObjectTrait<Role> for RoleEntity {
+    fn new(value: Option<&str>) -> Result<RoleEntity, DomainError> {
+        let value = match value.map(|value| value.trim().to_lowercase()) {
+            None => DEFAULT_ROLE,
+            Some(value) => match value.as_str() {
+                "viewer" => Role::Viewer,
+                "creator" => Role::Creator,
+                "moderator" => Role::Moderator,
+                "admin" => Role::Admin,
+                "" => return Err(DomainError::from(ValidationError::required("role", "Role is required"))),
+                _ => return Err(DomainError::from(ValidationError::invalid("role", "Invalid role").with_param("expected", "viewer, creator, moderator or admin"))),
+            },
+        };
+
+        Ok(RoleEntity { value })
+    }
+
+    fn value(&self) -> &Role {
+        &self.value
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+    fn to_string(&self) -> String {
+        self.value.to_string()
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display, Formatter};
 use lazy_static::lazy_static;
 use crate::domain::errors::domain_error::DomainError;
+use crate::domain::errors::validation_errors::ValidationError;
 use crate::domain::value_objects::{ValueObject, ValueObjectTrait};
 use regex::Regex;
 
@@ -42,20 +43,20 @@ impl ValueObjectTrait<String> for UrlEntity {
 
                     if let Some(path) = path {
                         if !path.as_str().contains(".com") {
-                            return Err(DomainError::new("Can't commercial URL", ""))
+                            return Err(DomainError::from(ValidationError::new("url", "invalid_domain", "Can't commercial URL").with_param("expected", ".com")))
                         }
                     } else {
-                        return Err(DomainError::new("Invalid URL", ""))
+                        return Err(DomainError::from(ValidationError::invalid("url", "Invalid URL")))
                     }
 
                     Ok(UrlEntity {
                         value: value.to_string()
                     })
                 } else {
-                    Err(DomainError::new("Invalid URL", ""))
+                    Err(DomainError::from(ValidationError::invalid("url", "Invalid URL")))
                 }
             },
-            None => Err(DomainError::new("URL is required", ""))
+            None => Err(DomainError::from(ValidationError::required("url", "URL is required")))
         }
     }
 
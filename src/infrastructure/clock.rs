@@ -0,0 +1,43 @@
+use std::sync::{Arc, Mutex};
+use time::{Duration, OffsetDateTime};
+use crate::application::services::clock::{Clock, ClockContract};
+
+pub struct SystemClock;
+
+pub struct FixedClock {
+    now: Mutex<OffsetDateTime>,
+}
+
+impl SystemClock {
+    pub fn shared() -> ClockContract {
+        Arc::new(SystemClock)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        let now = OffsetDateTime::now_utc();
+
+        now.replace_microsecond(now.microsecond()).unwrap_or(now)
+    }
+}
+
+impl FixedClock {
+    pub fn new(now: OffsetDateTime) -> Self {
+        Self { now: Mutex::new(now) }
+    }
+
+    pub fn set(&self, now: OffsetDateTime) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> OffsetDateTime {
+        *self.now.lock().unwrap()
+    }
+}
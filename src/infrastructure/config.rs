@@ -0,0 +1,67 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use time::Duration;
+use crate::application::usecases::authentication::AccountPolicy;
+use crate::application::usecases::retention::RetentionPolicy;
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    Missing(String),
+    Invalid { key: String, value: String, reason: String },
+}
+
+impl Error for ConfigError {}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Missing(key) => write!(f, "{} must be set", key),
+            ConfigError::Invalid { key, value, reason } => write!(f, "Invalid value \"{}\" for {}: {}", value, key, reason),
+        }
+    }
+}
+
+pub fn required(key: &str) -> Result<String, ConfigError> {
+    match env::var(key) {
+        Ok(value) if !value.is_empty() => Ok(value),
+        _ => Err(ConfigError::Missing(key.to_string())),
+    }
+}
+
+pub fn parse_or<T: FromStr>(key: &str, default: T, reason: &str) -> Result<T, ConfigError> {
+    match env::var(key) {
+        Ok(value) => value.trim().parse::<T>().map_err(|_| ConfigError::Invalid {
+            key: key.to_string(),
+            value,
+            reason: reason.to_string(),
+        }),
+        Err(_) => Ok(default),
+    }
+}
+
+pub fn account_policy_from_env() -> Result<AccountPolicy, ConfigError> {
+    let email_verification_required = parse_or("EMAIL_VERIFICATION_REQUIRED", false, "must be true or false")?;
+    let email_verification_ttl = parse_or("EMAIL_VERIFICATION_TTL", 86400i64, "must be a number of seconds")?;
+    let password_reset_ttl = parse_or("PASSWORD_RESET_TTL", 3600i64, "must be a number of seconds")?;
+    let link_base_url = parse_or("APP_URL", "http://localhost:6010".to_string(), "must be a URL")?;
+
+    Ok(AccountPolicy {
+        email_verification_required,
+        email_verification_ttl: Duration::seconds(email_verification_ttl),
+        password_reset_ttl: Duration::seconds(password_reset_ttl),
+        link_base_url: link_base_url.trim_end_matches('/').to_string(),
+    })
+}
+
+pub fn retention_policy_from_env() -> Result<RetentionPolicy, ConfigError> {
+    let retention = parse_or("SOFT_DELETE_RETENTION_DAYS", 30i64, "must be a number of days")?;
+    let interval = parse_or("RETENTION_INTERVAL", 3600i64, "must be a number of seconds")?;
+
+    Ok(RetentionPolicy {
+        retention: Duration::days(retention.max(0)),
+        interval: Duration::seconds(interval.max(1)),
+    })
+}
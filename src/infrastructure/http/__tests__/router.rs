@@ -0,0 +1,331 @@
+#[cfg(test)]
+mod test_router {
+    use std::sync::Arc;
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::{header, Method, Request, StatusCode};
+    use axum::Router;
+    use serde_json::{json, Value};
+    use sqlx::PgPool;
+    use time::Duration;
+    use tower::ServiceExt;
+    use crate::application::repositories::Repository;
+    use crate::application::usecases::authentication::{AccountPolicy, AuthUseCase};
+    use crate::application::usecases::categories::CategoriesUseCase;
+    use crate::application::usecases::users::UsersUseCase;
+    use crate::application::usecases::videos::VideosUseCase;
+    use crate::domain::entities::categories::Categories;
+    use crate::infrastructure::clock::SystemClock;
+    use crate::infrastructure::http::router;
+    use crate::infrastructure::http::state::AppState;
+    use crate::infrastructure::mail::log::LogMailer;
+    use crate::infrastructure::persistence::in_memory::unit_of_work::UnitOfWorkInMemory;
+    use crate::infrastructure::persistence::database::__tests__::support::TestDatabase;
+    use crate::infrastructure::persistence::database::connection::{DatabaseConfig, PostgresDatabase};
+    use crate::infrastructure::persistence::database::Database;
+    use crate::infrastructure::security::jwt::{JwtConfig, JwtTokenService};
+    use crate::infrastructure::security::login_throttle::{InMemoryLoginThrottle, LoginThrottleConfig};
+
+    async fn setup_state() -> AppState {
+        let clock = SystemClock::shared();
+        let unit_of_work = Arc::new(UnitOfWorkInMemory::new());
+
+        unit_of_work.categories.save(Categories::default_category(clock.now())).await.unwrap();
+
+        let auth = AuthUseCase::new(
+            unit_of_work.users.clone(),
+            unit_of_work.refresh_tokens.clone(),
+            unit_of_work.email_verifications.clone(),
+            unit_of_work.password_resets.clone(),
+            unit_of_work.clone(),
+            Arc::new(JwtTokenService::new(JwtConfig {
+                secret: "test-secret".to_string(),
+                access_token_ttl: Duration::minutes(15),
+                refresh_token_ttl: Duration::days(30),
+            }, clock.clone())),
+            Arc::new(LogMailer::new(Some(std::env::temp_dir().join("aluraflix-router-test-mail.log")))),
+            Arc::new(InMemoryLoginThrottle::new(LoginThrottleConfig {
+                max_attempts_per_email: 5,
+                max_attempts_per_ip: 50,
+                backoff_base: Duration::ZERO,
+                lockout: Duration::minutes(15),
+            }, clock.clone())),
+            AccountPolicy {
+                email_verification_required: false,
+                email_verification_ttl: Duration::hours(24),
+                password_reset_ttl: Duration::hours(1),
+                link_base_url: "http://localhost:6010".to_string(),
+            },
+            clock.clone(),
+        );
+
+        AppState::new(
+            auth,
+            VideosUseCase::new(unit_of_work.videos.clone(), unit_of_work.categories.clone(), clock.clone()),
+            CategoriesUseCase::new(unit_of_work.categories.clone(), unit_of_work.videos.clone(), unit_of_work.clone(), clock.clone()),
+            UsersUseCase::new(unit_of_work.users.clone(), unit_of_work.clone(), clock),
+        )
+    }
+
+    async fn setup_router() -> Router {
+        router(setup_state().await)
+    }
+
+    struct UnreachableDatabase {
+        pool: PgPool,
+    }
+
+    #[async_trait]
+    impl Database for UnreachableDatabase {
+        async fn connect(_: &DatabaseConfig) -> Result<Self, sqlx::Error> {
+            Err(sqlx::Error::PoolTimedOut)
+        }
+
+        async fn ping(&self) -> Result<(), sqlx::Error> {
+            Err(sqlx::Error::PoolTimedOut)
+        }
+
+        fn pool(&self) -> &PgPool {
+            &self.pool
+        }
+    }
+
+    async fn send(router: &Router, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+        let mut request = Request::builder().method(method).uri(uri);
+
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+
+        let request = match body {
+            Some(body) => request.header(header::CONTENT_TYPE, "application/json").body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        };
+
+        let response = router.clone().oneshot(request.unwrap()).await.unwrap();
+        let status = response.status();
+        let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    async fn send_raw(router: &Router, request: Request<Body>) -> (StatusCode, String, Value) {
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let content_type = response.headers().get(header::CONTENT_TYPE).and_then(|value| value.to_str().ok()).unwrap_or_default().to_string();
+        let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+
+        (status, content_type, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    async fn sign_in(router: &Router) -> String {
+        let credentials = json!({ "name": "John Doe", "email": "doejoe@test.com", "password": "12345678" });
+
+        send(router, Method::POST, "/auth/sign-up", None, Some(credentials.clone())).await;
+        let (_, body) = send(router, Method::POST, "/auth/sign-in", None, Some(credentials)).await;
+
+        body["access_token"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn it_should_mount_the_auth_routes() {
+        let router = setup_router().await;
+        let credentials = json!({ "name": "John Doe", "email": "doejoe@test.com", "password": "12345678" });
+
+        let (sign_up, _) = send(&router, Method::POST, "/auth/sign-up", None, Some(credentials.clone())).await;
+        let (sign_in, body) = send(&router, Method::POST, "/auth/sign-in", None, Some(credentials)).await;
+
+        assert_eq!(sign_up, StatusCode::CREATED);
+        assert_eq!(sign_in, StatusCode::OK);
+        assert!(body["access_token"].is_string());
+    }
+
+    #[tokio::test]
+    async fn it_should_mount_the_videos_routes() {
+        let router = setup_router().await;
+        let token = sign_in(&router).await;
+        let video = json!({ "title": "New video", "description": "Description", "url": "https://www.youtube.com/watch?v=6n3pFFPSlW4" });
+
+        let (created, body) = send(&router, Method::POST, "/videos", Some(&token), Some(video)).await;
+        let (found, _) = send(&router, Method::GET, &format!("/videos/{}", body["id"].as_str().unwrap()), None, None).await;
+        let (listed, list) = send(&router, Method::GET, "/videos", None, None).await;
+
+        assert_eq!(created, StatusCode::CREATED);
+        assert_eq!(found, StatusCode::OK);
+        assert_eq!(listed, StatusCode::OK);
+        assert_eq!(list["items"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_mount_the_categories_routes() {
+        let router = setup_router().await;
+        let token = sign_in(&router).await;
+        let category = json!({ "name": "New category", "color": "#ff0000" });
+
+        let (created, body) = send(&router, Method::POST, "/categorias", Some(&token), Some(category)).await;
+        let (found, _) = send(&router, Method::GET, &format!("/categorias/{}", body["id"].as_str().unwrap()), None, None).await;
+        let (listed, _) = send(&router, Method::GET, "/categorias", None, None).await;
+
+        assert_eq!(created, StatusCode::CREATED);
+        assert_eq!(found, StatusCode::OK);
+        assert_eq!(listed, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_the_videos_by_category_and_user() {
+        let router = setup_router().await;
+        let token = sign_in(&router).await;
+        let video = json!({ "title": "New video", "description": "Description", "url": "https://www.youtube.com/watch?v=6n3pFFPSlW4" });
+
+        let (_, body) = send(&router, Method::POST, "/videos", Some(&token), Some(video)).await;
+        let user_id = body["user_id"].as_str().unwrap();
+        let category_id = body["category_id"].as_str().unwrap();
+
+        let (_, mine) = send(&router, Method::GET, &format!("/videos?user_id={}&category_id={}", user_id, category_id), None, None).await;
+        let (_, others) = send(&router, Method::GET, "/videos?user_id=018b33b7-c8dd-76a2-98b5-d621862882a9", None, None).await;
+        let (invalid, _) = send(&router, Method::GET, "/videos?category_id=abc", None, None).await;
+        let (searched, _) = send(&router, Method::GET, &format!("/videos?search=video&user_id={}", user_id), None, None).await;
+
+        assert_eq!(mine["items"].as_array().unwrap().len(), 1);
+        assert_eq!(others["items"].as_array().unwrap().len(), 0);
+        assert_eq!(invalid, StatusCode::BAD_REQUEST);
+        assert_eq!(searched, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn it_should_filter_the_categories_by_user() {
+        let router = setup_router().await;
+        let token = sign_in(&router).await;
+        let category = json!({ "name": "New category", "color": "#ff0000" });
+
+        let (_, body) = send(&router, Method::POST, "/categorias", Some(&token), Some(category)).await;
+        let user_id = body["user_id"].as_str().unwrap();
+
+        let (_, mine) = send(&router, Method::GET, &format!("/categorias?user_id={}", user_id), None, None).await;
+        let (_, all) = send(&router, Method::GET, "/categorias", None, None).await;
+
+        assert_eq!(mine["items"].as_array().unwrap().len(), 1);
+        assert_eq!(all["items"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_writes_without_a_bearer_token() {
+        let router = setup_router().await;
+        let video = json!({ "title": "New video", "description": "Description", "url": "https://www.youtube.com/watch?v=6n3pFFPSlW4" });
+
+        let (status, _) = send(&router, Method::POST, "/videos", None, Some(video)).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn it_should_answer_unknown_routes_with_not_found() {
+        let router = setup_router().await;
+
+        let (status, _) = send(&router, Method::GET, "/unknown", None, None).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn it_should_answer_a_malformed_json_body_with_a_problem() {
+        let router = setup_router().await;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/auth/sign-in")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from("{\"email\":"))
+            .unwrap();
+
+        let (status, content_type, body) = send_raw(&router, request).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(content_type, "application/problem+json");
+        assert_eq!(body["status"], 400);
+        assert_eq!(body["instance"], "/auth/sign-in");
+    }
+
+    #[tokio::test]
+    async fn it_should_answer_a_json_body_of_the_wrong_shape_with_a_problem() {
+        let router = setup_router().await;
+
+        let (status, body) = send(&router, Method::POST, "/auth/sign-in", None, Some(json!({ "email": "doejoe@test.com" }))).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["status"], 422);
+        assert!(body["detail"].as_str().unwrap().contains("password"));
+    }
+
+    #[tokio::test]
+    async fn it_should_answer_a_missing_json_content_type_with_a_problem() {
+        let router = setup_router().await;
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/auth/sign-in")
+            .body(Body::from("{}"))
+            .unwrap();
+
+        let (status, content_type, body) = send_raw(&router, request).await;
+
+        assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(content_type, "application/problem+json");
+        assert_eq!(body["status"], 415);
+    }
+
+    #[tokio::test]
+    async fn it_should_answer_an_invalid_query_string_with_a_problem() {
+        let router = setup_router().await;
+        let request = Request::builder().uri("/videos?page=first").body(Body::empty()).unwrap();
+
+        let (status, content_type, body) = send_raw(&router, request).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(content_type, "application/problem+json");
+        assert_eq!(body["instance"], "/videos");
+    }
+
+    #[tokio::test]
+    async fn it_should_report_health_without_a_database() {
+        let router = setup_router().await;
+
+        let (status, body) = send(&router, Method::GET, "/health", None, None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "ok");
+        assert_eq!(body["database"], "disabled");
+    }
+
+    #[tokio::test]
+    async fn it_should_report_unavailable_when_the_database_ping_fails() {
+        let database = UnreachableDatabase { pool: PgPool::connect_lazy("postgres://localhost:1/unreachable").unwrap() };
+        let router = router(setup_state().await.with_database(Arc::new(database)));
+
+        let (status, body) = send(&router, Method::GET, "/health", None, None).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "unavailable");
+        assert_eq!(body["database"], "down");
+    }
+
+    #[tokio::test]
+    async fn it_should_report_a_reachable_database_as_up() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let connected = PostgresDatabase::connect(&DatabaseConfig {
+            url: database.url(),
+            pool_size: 1,
+            connect_timeout: std::time::Duration::from_secs(5),
+            acquire_timeout: std::time::Duration::from_secs(5),
+            connect_retries: 0,
+            retry_backoff: std::time::Duration::from_millis(10),
+        }).await.unwrap();
+        let router = router(setup_state().await.with_database(Arc::new(connected)));
+
+        let (status, body) = send(&router, Method::GET, "/health", None, None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["database"], "up");
+
+        drop(router);
+        database.drop().await;
+    }
+}
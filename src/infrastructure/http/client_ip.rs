@@ -0,0 +1,55 @@
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use axum::async_trait;
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::request::Parts;
+use crate::infrastructure::config::ConfigError;
+use crate::infrastructure::http::state::AppState;
+
+const FORWARDED_FOR: &str = "x-forwarded-for";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClientIpSource {
+    Peer,
+    ForwardedFor,
+}
+
+pub struct ClientIp(pub Option<IpAddr>);
+
+impl ClientIpSource {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        match std::env::var("CLIENT_IP_SOURCE").as_deref() {
+            Err(_) | Ok("peer") => Ok(ClientIpSource::Peer),
+            Ok("x-forwarded-for") => Ok(ClientIpSource::ForwardedFor),
+            Ok(value) => Err(ConfigError::Invalid {
+                key: "CLIENT_IP_SOURCE".to_string(),
+                value: value.to_string(),
+                reason: "must be peer or x-forwarded-for".to_string(),
+            }),
+        }
+    }
+}
+
+fn forwarded_for(parts: &Parts) -> Option<IpAddr> {
+    let header = parts.headers.get(FORWARDED_FOR)?.to_str().ok()?;
+
+    header.rsplit(',').next()?.trim().parse().ok()
+}
+
+fn peer(parts: &Parts) -> Option<IpAddr> {
+    parts.extensions.get::<ConnectInfo<SocketAddr>>().map(|ConnectInfo(address)| address.ip())
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let ip = match state.client_ip_source {
+            ClientIpSource::ForwardedFor => forwarded_for(parts).or_else(|| peer(parts)),
+            ClientIpSource::Peer => peer(parts),
+        };
+
+        Ok(ClientIp(ip))
+    }
+}
@@ -0,0 +1,54 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::http::request::Parts;
+use axum::response::{IntoResponseParts, ResponseParts};
+use crate::domain::errors::app_error::AppError;
+
+pub struct IfMatch(pub Option<i32>);
+
+pub struct ETag(pub i32);
+
+fn parse_version(value: &str) -> Option<i32> {
+    let value = value.trim();
+    let value = value.strip_prefix("W/").unwrap_or(value);
+
+    value.strip_prefix('"')?.strip_suffix('"')?.parse().ok()
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let value = match parts.headers.get(header::IF_MATCH) {
+            Some(value) => value,
+            None => return Ok(IfMatch(None)),
+        };
+
+        let invalid = || AppError::new("The If-Match header does not match the current version", StatusCode::PRECONDITION_FAILED, None);
+        let value = value.to_str().map_err(|_| invalid())?.trim();
+
+        if value == "*" {
+            return Ok(IfMatch(None));
+        }
+
+        match parse_version(value) {
+            Some(version) => Ok(IfMatch(Some(version))),
+            None => Err(invalid()),
+        }
+    }
+}
+
+impl IntoResponseParts for ETag {
+    type Error = AppError;
+
+    fn into_response_parts(self, mut parts: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let value = HeaderValue::from_str(&format!("\"{}\"", self.0))
+            .map_err(|_| AppError::new("Internal server error", StatusCode::INTERNAL_SERVER_ERROR, None))?;
+
+        parts.headers_mut().insert(header::ETAG, value);
+
+        Ok(parts)
+    }
+}
@@ -0,0 +1,54 @@
+use axum::body::Body;
+use axum::http::{header, HeaderValue, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use crate::domain::errors::app_error::{AppError, Problem, PROBLEM_CONTENT_TYPE};
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let mut response = problem_response(self.problem());
+
+        if let Some(seconds) = self.retry_after() {
+            response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+        }
+
+        response
+    }
+}
+
+pub async fn problem_instance(request: Request<Body>, next: Next<Body>) -> Response {
+    let instance = request.uri().path().to_string();
+
+    let response = next.run(request).await;
+
+    match response.extensions().get::<Problem>() {
+        Some(problem) if problem.instance.is_none() => {
+            let mut rebuilt = problem_response(problem.clone().with_instance(&instance));
+
+            for (name, value) in response.headers() {
+                if name != header::CONTENT_LENGTH {
+                    rebuilt.headers_mut().entry(name).or_insert_with(|| value.clone());
+                }
+            }
+
+            rebuilt
+        }
+        _ => response,
+    }
+}
+
+fn problem_response(problem: Problem) -> Response {
+    let status = StatusCode::from_u16(problem.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let body = serde_json::to_vec(&problem).unwrap_or_default();
+
+    let mut response = (status, body).into_response();
+
+    response.headers_mut().insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_CONTENT_TYPE));
+
+    if status == StatusCode::UNAUTHORIZED {
+        response.headers_mut().insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+    }
+    response.extensions_mut().insert(problem);
+
+    response
+}
@@ -0,0 +1,146 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Router;
+use axum::routing::{get, post, put};
+use time::format_description::well_known::Rfc3339;
+use serde::Serialize;
+use crate::application::identity::CurrentUser;
+use crate::application::usecases::authentication::{AuthTokens, ChangePasswordInput, RefreshTokenInput, RequestPasswordResetInput, ResendVerificationInput, ResetPasswordInput, SignInInput, VerifyEmailInput};
+use crate::domain::entities::users::{Users, UsersInput};
+use crate::domain::value_objects::ValueObjectTrait;
+use crate::domain::errors::app_error::AppError;
+use crate::infrastructure::http::client_ip::ClientIp;
+use crate::infrastructure::http::extract::{Json, Query};
+use crate::infrastructure::http::state::AppState;
+
+#[derive(Serialize)]
+pub struct UsersResponse {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+    pub email_verified: bool,
+    pub created_at: String,
+    pub updated_at: String,
+    pub version: i32,
+}
+
+impl From<Users> for UsersResponse {
+    fn from(user: Users) -> Self {
+        Self {
+            id: user.id.to_string(),
+            name: user.name,
+            email: user.email.to_string(),
+            role: user.role.to_string(),
+            email_verified: user.email_verified_at.is_some(),
+            created_at: user.created_at.format(&Rfc3339).unwrap_or_default(),
+            updated_at: user.updated_at.format(&Rfc3339).unwrap_or_default(),
+            version: user.version,
+        }
+    }
+}
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/sign-up", post(sign_up))
+        .route("/sign-in", post(sign_in))
+        .route("/refresh", post(refresh))
+        .route("/sign-out", post(sign_out))
+        .route("/verify-email", get(verify_email_link).post(verify_email))
+        .route("/resend-verification", post(resend_verification))
+        .route("/forgot-password", post(forgot_password))
+        .route("/reset-password", post(reset_password))
+        .route("/change-password", put(change_password))
+}
+
+async fn sign_up(
+    State(state): State<AppState>,
+    Json(input): Json<UsersInput>,
+) -> Result<(StatusCode, Json<UsersResponse>), AppError> {
+    let user = state.auth.sign_up(input).await?;
+
+    Ok((StatusCode::CREATED, Json(UsersResponse::from(user))))
+}
+
+async fn sign_in(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Json(input): Json<SignInInput>,
+) -> Result<Json<AuthTokens>, AppError> {
+    let tokens = state.auth.sign_in(input, client_ip).await?;
+
+    Ok(Json(tokens))
+}
+
+async fn refresh(
+    State(state): State<AppState>,
+    Json(input): Json<RefreshTokenInput>,
+) -> Result<Json<AuthTokens>, AppError> {
+    let tokens = state.auth.refresh(input).await?;
+
+    Ok(Json(tokens))
+}
+
+async fn sign_out(
+    State(state): State<AppState>,
+    Json(input): Json<RefreshTokenInput>,
+) -> Result<StatusCode, AppError> {
+    state.auth.sign_out(input).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn verify_email(
+    State(state): State<AppState>,
+    Json(input): Json<VerifyEmailInput>,
+) -> Result<Json<UsersResponse>, AppError> {
+    let user = state.auth.verify_email(input).await?;
+
+    Ok(Json(UsersResponse::from(user)))
+}
+
+async fn verify_email_link(
+    State(state): State<AppState>,
+    Query(input): Query<VerifyEmailInput>,
+) -> Result<Json<UsersResponse>, AppError> {
+    let user = state.auth.verify_email(input).await?;
+
+    Ok(Json(UsersResponse::from(user)))
+}
+
+async fn resend_verification(
+    State(state): State<AppState>,
+    Json(input): Json<ResendVerificationInput>,
+) -> Result<StatusCode, AppError> {
+    state.auth.resend_verification(input).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn forgot_password(
+    State(state): State<AppState>,
+    Json(input): Json<RequestPasswordResetInput>,
+) -> Result<StatusCode, AppError> {
+    state.auth.request_password_reset(input).await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn reset_password(
+    State(state): State<AppState>,
+    Json(input): Json<ResetPasswordInput>,
+) -> Result<StatusCode, AppError> {
+    state.auth.reset_password(input).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn change_password(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(input): Json<ChangePasswordInput>,
+) -> Result<StatusCode, AppError> {
+    state.auth.change_password(&user, input).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
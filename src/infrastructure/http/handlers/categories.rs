@@ -0,0 +1,165 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Router;
+use axum::routing::{delete, get, post};
+use time::format_description::well_known::Rfc3339;
+use serde::{Deserialize, Serialize};
+use crate::application::identity::CurrentUser;
+use crate::application::repositories::pagination::PageRequest;
+use crate::application::usecases::categories::DeleteCategoryStrategy;
+use crate::domain::entities::categories::{Categories, CategoriesInput, DEFAULT_CATEGORY_ID};
+use crate::domain::errors::app_error::AppError;
+use crate::domain::value_objects::ids::UserId;
+use crate::domain::value_objects::ValueObjectTrait;
+use crate::infrastructure::http::handlers::videos::VideosResponse;
+use crate::infrastructure::http::conditional::{ETag, IfMatch};
+use crate::infrastructure::http::extract::{Json, Path, Query};
+use crate::infrastructure::http::pagination::{filter_by_id, PageResponse, PaginationQuery};
+use crate::infrastructure::http::state::AppState;
+
+#[derive(Serialize)]
+pub struct CategoriesResponse {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+    pub user_id: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub version: i32,
+}
+
+impl From<Categories> for CategoriesResponse {
+    fn from(category: Categories) -> Self {
+        Self {
+            id: category.id.to_string(),
+            name: category.name,
+            color: category.color.to_string(),
+            user_id: category.user_id.to_string(),
+            created_at: category.created_at.format(&Rfc3339).unwrap_or_default(),
+            updated_at: category.updated_at.format(&Rfc3339).unwrap_or_default(),
+            version: category.version,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CategoriesFilterQuery {
+    pub user_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DeleteCategoryQuery {
+    pub strategy: Option<String>,
+    pub to: Option<String>,
+}
+
+impl TryFrom<DeleteCategoryQuery> for DeleteCategoryStrategy {
+    type Error = AppError;
+
+    fn try_from(query: DeleteCategoryQuery) -> Result<Self, Self::Error> {
+        match (query.strategy.as_deref(), query.to) {
+            (None, _) | (Some("reject"), _) => Ok(DeleteCategoryStrategy::Reject),
+            (Some("cascade"), _) => Ok(DeleteCategoryStrategy::Cascade),
+            (Some("reassign"), Some(to)) => Ok(DeleteCategoryStrategy::Reassign(to)),
+            (Some("reassign"), None) => Ok(DeleteCategoryStrategy::Reassign(DEFAULT_CATEGORY_ID.to_string())),
+            (Some(_), _) => Err(AppError::new("Invalid delete strategy", StatusCode::BAD_REQUEST, None)),
+        }
+    }
+}
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(find_all).post(create))
+        .route("/:id", get(find_by_id).put(update).delete(remove))
+        .route("/:id/restore", post(restore))
+        .route("/:id/purge", delete(purge))
+        .route("/:id/videos", get(find_videos))
+}
+
+async fn find_all(
+    State(state): State<AppState>,
+    Query(filter): Query<CategoriesFilterQuery>,
+    Query(query): Query<PaginationQuery>,
+) -> Result<Json<PageResponse<CategoriesResponse>>, AppError> {
+    let request = PageRequest::try_from(query)?;
+    let request = filter_by_id::<UserId>(request, "user_id", filter.user_id)?;
+    let categories = state.categories.find_all(request).await?;
+
+    Ok(Json(PageResponse::from(categories)))
+}
+
+async fn find_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<(ETag, Json<CategoriesResponse>), AppError> {
+    let category = state.categories.find_by_id(&id).await?;
+
+    Ok((ETag(category.version), Json(CategoriesResponse::from(category))))
+}
+
+async fn find_videos(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<PaginationQuery>,
+) -> Result<Json<PageResponse<VideosResponse>>, AppError> {
+    let request = PageRequest::try_from(query)?;
+    let videos = state.categories.find_videos(&id, request).await?;
+
+    Ok(Json(PageResponse::from(videos)))
+}
+
+async fn create(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(input): Json<CategoriesInput>,
+) -> Result<(StatusCode, ETag, Json<CategoriesResponse>), AppError> {
+    let category = state.categories.create(&user, input).await?;
+
+    Ok((StatusCode::CREATED, ETag(category.version), Json(CategoriesResponse::from(category))))
+}
+
+async fn update(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(version): IfMatch,
+    Json(input): Json<CategoriesInput>,
+) -> Result<(ETag, Json<CategoriesResponse>), AppError> {
+    let category = state.categories.update(&user, &id, input, version).await?;
+
+    Ok((ETag(category.version), Json(CategoriesResponse::from(category))))
+}
+
+async fn remove(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(version): IfMatch,
+    Query(query): Query<DeleteCategoryQuery>,
+) -> Result<StatusCode, AppError> {
+    let strategy = DeleteCategoryStrategy::try_from(query)?;
+
+    state.categories.delete(&user, &id, strategy, version).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn restore(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+) -> Result<(ETag, Json<CategoriesResponse>), AppError> {
+    let category = state.categories.restore(&user, &id).await?;
+
+    Ok((ETag(category.version), Json(CategoriesResponse::from(category))))
+}
+
+async fn purge(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.categories.purge(&user, &id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,37 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::{Json, Router};
+use axum::routing::get;
+use serde::Serialize;
+use crate::infrastructure::http::state::AppState;
+
+#[derive(Serialize)]
+pub struct HealthResponse {
+    pub status: String,
+    pub database: String,
+}
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(health))
+}
+
+async fn health(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+    let database = match &state.database {
+        Some(database) => match database.ping().await {
+            Ok(()) => "up",
+            Err(error) => {
+                eprintln!("Database health check failed: {}", error);
+                "down"
+            }
+        },
+        None => "disabled",
+    };
+
+    let (code, status) = match database {
+        "down" => (StatusCode::SERVICE_UNAVAILABLE, "unavailable"),
+        _ => (StatusCode::OK, "ok"),
+    };
+
+    (code, Json(HealthResponse { status: status.to_string(), database: database.to_string() }))
+}
@@ -0,0 +1,4 @@
+pub mod authentication;
+pub mod videos;
+pub mod categories;
+pub mod users;pub mod health;
@@ -0,0 +1,62 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Router;
+use axum::routing::{delete, post, put};
+use crate::application::identity::CurrentUser;
+use crate::application::usecases::users::ChangeRoleInput;
+use crate::domain::errors::app_error::AppError;
+use crate::infrastructure::http::conditional::{ETag, IfMatch};
+use crate::infrastructure::http::extract::{Json, Path};
+use crate::infrastructure::http::handlers::authentication::UsersResponse;
+use crate::infrastructure::http::state::AppState;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/:id", delete(remove))
+        .route("/:id/role", put(change_role))
+        .route("/:id/restore", post(restore))
+        .route("/:id/purge", delete(purge))
+}
+
+async fn change_role(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(version): IfMatch,
+    Json(input): Json<ChangeRoleInput>,
+) -> Result<(ETag, Json<UsersResponse>), AppError> {
+    let user = state.users.change_role(&user, &id, input, version).await?;
+
+    Ok((ETag(user.version), Json(UsersResponse::from(user))))
+}
+
+async fn remove(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(version): IfMatch,
+) -> Result<StatusCode, AppError> {
+    state.users.delete(&user, &id, version).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn restore(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+) -> Result<(ETag, Json<UsersResponse>), AppError> {
+    let user = state.users.restore(&user, &id).await?;
+
+    Ok((ETag(user.version), Json(UsersResponse::from(user))))
+}
+
+async fn purge(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.users.purge(&user, &id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,157 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Router;
+use axum::routing::{delete, get, post};
+use time::format_description::well_known::Rfc3339;
+use serde::{Deserialize, Serialize};
+use crate::application::identity::CurrentUser;
+use crate::application::repositories::pagination::PageRequest;
+use crate::domain::entities::videos::{Videos, VideosInput, VideosPatchInput};
+use crate::domain::value_objects::ids::{CategoryId, UserId};
+use crate::domain::value_objects::ValueObjectTrait;
+use crate::domain::errors::app_error::AppError;
+use crate::infrastructure::http::conditional::{ETag, IfMatch};
+use crate::infrastructure::http::extract::{Json, Path, Query};
+use crate::infrastructure::http::pagination::{filter_by_id, PageResponse, PaginationQuery};
+use crate::infrastructure::http::state::AppState;
+
+#[derive(Serialize)]
+pub struct VideosResponse {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub url: String,
+    pub category_id: String,
+    pub user_id: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub version: i32,
+}
+
+impl From<Videos> for VideosResponse {
+    fn from(video: Videos) -> Self {
+        Self {
+            id: video.id.to_string(),
+            title: video.title,
+            description: video.description,
+            url: video.url.to_string(),
+            category_id: video.category_id.to_string(),
+            user_id: video.user_id.to_string(),
+            created_at: video.created_at.format(&Rfc3339).unwrap_or_default(),
+            updated_at: video.updated_at.format(&Rfc3339).unwrap_or_default(),
+            version: video.version,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SearchQuery {
+    pub search: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct VideosFilterQuery {
+    pub category_id: Option<String>,
+    pub user_id: Option<String>,
+}
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(find_all).post(create))
+        .route("/:id", get(find_by_id).put(update).patch(patch).delete(remove))
+        .route("/:id/restore", post(restore))
+        .route("/:id/purge", delete(purge))
+}
+
+async fn find_all(
+    State(state): State<AppState>,
+    Query(search): Query<SearchQuery>,
+    Query(filter): Query<VideosFilterQuery>,
+    Query(query): Query<PaginationQuery>,
+) -> Result<Json<PageResponse<VideosResponse>>, AppError> {
+    let request = PageRequest::try_from(query)?;
+    let request = filter_by_id::<CategoryId>(request, "category_id", filter.category_id)?;
+    let request = filter_by_id::<UserId>(request, "user_id", filter.user_id)?;
+
+    let videos = match search.search {
+        Some(_) if !request.filters.is_empty() => return Err(AppError::new("Filters cannot be combined with search", StatusCode::BAD_REQUEST, None)),
+        Some(search) => state.videos.search(&search, request).await?,
+        None => state.videos.find_all(request).await?,
+    };
+
+    Ok(Json(PageResponse::from(videos)))
+}
+
+async fn find_by_id(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<(ETag, Json<VideosResponse>), AppError> {
+    let video = state.videos.find_by_id(&id).await?;
+
+    Ok((ETag(video.version), Json(VideosResponse::from(video))))
+}
+
+async fn create(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(input): Json<VideosInput>,
+) -> Result<(StatusCode, ETag, Json<VideosResponse>), AppError> {
+    let video = state.videos.create(&user, input).await?;
+
+    Ok((StatusCode::CREATED, ETag(video.version), Json(VideosResponse::from(video))))
+}
+
+async fn update(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(version): IfMatch,
+    Json(input): Json<VideosInput>,
+) -> Result<(ETag, Json<VideosResponse>), AppError> {
+    let video = state.videos.update(&user, &id, input, version).await?;
+
+    Ok((ETag(video.version), Json(VideosResponse::from(video))))
+}
+
+async fn patch(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(version): IfMatch,
+    Json(input): Json<VideosPatchInput>,
+) -> Result<(ETag, Json<VideosResponse>), AppError> {
+    let video = state.videos.patch(&user, &id, input, version).await?;
+
+    Ok((ETag(video.version), Json(VideosResponse::from(video))))
+}
+
+async fn remove(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(version): IfMatch,
+) -> Result<StatusCode, AppError> {
+    state.videos.delete(&user, &id, version).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn restore(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+) -> Result<(ETag, Json<VideosResponse>), AppError> {
+    let video = state.videos.restore(&user, &id).await?;
+
+    Ok((ETag(video.version), Json(VideosResponse::from(video))))
+}
+
+async fn purge(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    state.videos.purge(&user, &id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,32 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use crate::application::identity::CurrentUser;
+use crate::domain::errors::app_error::AppError;
+use crate::infrastructure::http::state::AppState;
+
+fn bearer_token(parts: &Parts) -> Option<&str> {
+    let header = parts.headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = header.split_once(' ')?;
+
+    match scheme.eq_ignore_ascii_case("bearer") && !token.trim().is_empty() {
+        true => Some(token.trim()),
+        false => None,
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let token = match bearer_token(parts) {
+            Some(token) => token,
+            None => return Err(AppError::new("Missing bearer token", StatusCode::UNAUTHORIZED, None)),
+        };
+
+        Ok(state.auth.authenticate(token)?)
+    }
+}
@@ -0,0 +1,40 @@
+pub mod state;
+pub mod client_ip;
+pub mod conditional;
+pub mod errors;
+pub mod extract;
+pub mod handlers;
+pub mod identity;
+pub mod pagination;
+
+mod __tests__;
+
+use std::env;
+use std::error::Error;
+use std::net::SocketAddr;
+use axum::{middleware, Router};
+use crate::infrastructure::http::state::AppState;
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .nest("/auth", handlers::authentication::routes())
+        .nest("/videos", handlers::videos::routes())
+        .nest("/categorias", handlers::categories::routes())
+        .nest("/users", handlers::users::routes())
+        .nest("/health", handlers::health::routes())
+        .layer(middleware::from_fn(errors::problem_instance))
+        .with_state(state)
+}
+
+pub async fn serve(state: AppState) -> Result<(), Box<dyn Error>> {
+    let port = env::var("PORT").unwrap_or("6010".to_string()).parse::<u16>()?;
+    let address = SocketAddr::from(([0, 0, 0, 0], port));
+
+    println!("Listening on {}", address);
+
+    axum::Server::bind(&address)
+        .serve(router(state).into_make_service_with_connect_info::<SocketAddr>())
+        .await?;
+
+    Ok(())
+}
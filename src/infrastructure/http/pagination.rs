@@ -0,0 +1,63 @@
+use std::str::FromStr;
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use crate::application::repositories::pagination::{PageRequest, PageResult, SortDirection, DEFAULT_PAGE_LIMIT, DEFAULT_SORT_FIELD};
+use crate::domain::errors::app_error::AppError;
+
+#[derive(Deserialize)]
+pub struct PaginationQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct PageResponse<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    pub page: u32,
+    pub limit: u32,
+    pub next_cursor: Option<String>,
+}
+
+impl TryFrom<PaginationQuery> for PageRequest {
+    type Error = AppError;
+
+    fn try_from(query: PaginationQuery) -> Result<Self, Self::Error> {
+        let direction = match query.order.as_deref() {
+            None | Some("asc") => SortDirection::Asc,
+            Some("desc") => SortDirection::Desc,
+            Some(_) => return Err(AppError::new("Invalid sort order", StatusCode::BAD_REQUEST, None)),
+        };
+
+        let sort = query.sort.unwrap_or(DEFAULT_SORT_FIELD.to_string());
+
+        Ok(PageRequest::new(query.page.unwrap_or(1), query.limit.unwrap_or(DEFAULT_PAGE_LIMIT))
+            .with_cursor(query.cursor)
+            .sort_by(&sort, direction))
+    }
+}
+
+pub fn filter_by_id<Id: FromStr + ToString>(request: PageRequest, field: &str, value: Option<String>) -> Result<PageRequest, AppError> {
+    match value.map(|value| value.parse::<Id>()) {
+        None => Ok(request),
+        Some(Ok(id)) => Ok(request.filter(field, &id.to_string())),
+        Some(Err(_)) => Err(AppError::new("Invalid filter value", StatusCode::BAD_REQUEST, None)),
+    }
+}
+
+impl<T, U: From<T>> From<PageResult<T>> for PageResponse<U> {
+    fn from(page: PageResult<T>) -> Self {
+        let page = page.map(U::from);
+
+        Self {
+            items: page.items,
+            total: page.total,
+            page: page.page,
+            limit: page.limit,
+            next_cursor: page.next_cursor,
+        }
+    }
+}
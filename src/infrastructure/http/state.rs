@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::application::usecases::authentication::AuthUseCase;
+use crate::application::usecases::categories::CategoriesUseCase;
+use crate::application::usecases::users::UsersUseCase;
+use crate::application::usecases::videos::VideosUseCase;
+use crate::infrastructure::http::client_ip::ClientIpSource;
+use crate::infrastructure::persistence::database::Database;
+
+#[derive(Clone)]
+pub struct AppState {
+    pub auth: Arc<AuthUseCase>,
+    pub videos: Arc<VideosUseCase>,
+    pub categories: Arc<CategoriesUseCase>,
+    pub users: Arc<UsersUseCase>,
+    pub client_ip_source: ClientIpSource,
+    pub database: Option<Arc<dyn Database + Send + Sync>>,
+}
+
+impl AppState {
+    pub fn new(auth: AuthUseCase, videos: VideosUseCase, categories: CategoriesUseCase, users: UsersUseCase) -> Self {
+        Self {
+            auth: Arc::new(auth),
+            videos: Arc::new(videos),
+            categories: Arc::new(categories),
+            users: Arc::new(users),
+            client_ip_source: ClientIpSource::Peer,
+            database: None,
+        }
+    }
+
+    pub fn with_client_ip_source(mut self, source: ClientIpSource) -> Self {
+        self.client_ip_source = source;
+        self
+    }
+
+    pub fn with_database(mut self, database: Arc<dyn Database + Send + Sync>) -> Self {
+        self.database = Some(database);
+        self
+    }
+}
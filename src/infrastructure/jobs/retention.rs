@@ -0,0 +1,26 @@
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, MissedTickBehavior};
+use crate::application::usecases::retention::RetentionUseCase;
+
+pub fn spawn(use_case: Arc<RetentionUseCase>) -> JoinHandle<()> {
+    let period = use_case.interval().unsigned_abs();
+
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match use_case.purge_expired().await {
+                Ok(summary) if summary.total() > 0 => println!(
+                    "Purged {} videos, {} categories and {} users past the retention period",
+                    summary.videos, summary.categories, summary.users,
+                ),
+                Ok(_) => {}
+                Err(error) => eprintln!("Error purging soft-deleted records: {:?}", error),
+            }
+        }
+    })
+}
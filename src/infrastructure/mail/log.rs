@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+use async_trait::async_trait;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+use crate::application::services::mailer::{Mail, Mailer, MailerError};
+
+pub struct LogMailer {
+    file: Option<PathBuf>,
+}
+
+impl LogMailer {
+    pub fn new(file: Option<PathBuf>) -> Self {
+        Self { file }
+    }
+
+    fn format(mail: &Mail) -> String {
+        format!("To: {}\nSubject: {}\n\n{}\n---\n", mail.to, mail.subject, mail.body)
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let message = LogMailer::format(&mail);
+
+        let path = match self.file {
+            Some(ref path) => path,
+            None => {
+                println!("{}", message);
+                return Ok(());
+            }
+        };
+
+        let mut file = match OpenOptions::new().create(true).append(true).open(path).await {
+            Ok(file) => file,
+            Err(error) => return Err(MailerError::Delivery(error.to_string())),
+        };
+
+        match file.write_all(message.as_bytes()).await {
+            Ok(()) => Ok(()),
+            Err(error) => Err(MailerError::Delivery(error.to_string())),
+        }
+    }
+}
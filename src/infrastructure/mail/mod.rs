@@ -0,0 +1,64 @@
+pub mod log;
+pub mod smtp;
+
+use std::env;
+use std::path::PathBuf;
+use std::sync::Arc;
+use crate::application::services::mailer::{MailerContract, MailerError};
+use crate::infrastructure::config::{parse_or, required, ConfigError};
+use crate::infrastructure::mail::log::LogMailer;
+use crate::infrastructure::mail::smtp::{SmtpConfig, SmtpMailer, SmtpSecurity};
+
+pub enum MailConfig {
+    Log(Option<PathBuf>),
+    Smtp(SmtpConfig),
+}
+
+impl MailConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let mailer = env::var("MAILER").unwrap_or("log".to_string());
+
+        match mailer.as_str() {
+            "log" => Ok(MailConfig::Log(env::var("MAIL_LOG_FILE").ok().filter(|path| !path.is_empty()).map(PathBuf::from))),
+            "smtp" => {
+                let security = match env::var("SMTP_SECURITY").unwrap_or("starttls".to_string()).as_str() {
+                    "none" => SmtpSecurity::None,
+                    "starttls" => SmtpSecurity::StartTls,
+                    "tls" => SmtpSecurity::Tls,
+                    value => return Err(ConfigError::Invalid {
+                        key: "SMTP_SECURITY".to_string(),
+                        value: value.to_string(),
+                        reason: "must be none, starttls or tls".to_string(),
+                    }),
+                };
+
+                let default_port = match security {
+                    SmtpSecurity::None => 25,
+                    SmtpSecurity::StartTls => 587,
+                    SmtpSecurity::Tls => 465,
+                };
+
+                Ok(MailConfig::Smtp(SmtpConfig {
+                    host: required("SMTP_HOST")?,
+                    port: parse_or("SMTP_PORT", default_port, "must be a port number")?,
+                    security,
+                    username: env::var("SMTP_USERNAME").ok().filter(|username| !username.is_empty()),
+                    password: env::var("SMTP_PASSWORD").ok(),
+                    from: required("MAIL_FROM")?,
+                }))
+            }
+            value => Err(ConfigError::Invalid {
+                key: "MAILER".to_string(),
+                value: value.to_string(),
+                reason: "must be log or smtp".to_string(),
+            }),
+        }
+    }
+}
+
+pub fn build_mailer(config: MailConfig) -> Result<MailerContract, MailerError> {
+    match config {
+        MailConfig::Log(file) => Ok(Arc::new(LogMailer::new(file))),
+        MailConfig::Smtp(config) => Ok(Arc::new(SmtpMailer::new(config)?)),
+    }
+}
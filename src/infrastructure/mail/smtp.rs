@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use crate::application::services::mailer::{Mail, Mailer, MailerError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmtpSecurity {
+    None,
+    StartTls,
+    Tls,
+}
+
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+}
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: SmtpConfig) -> Result<Self, MailerError> {
+        let from = match config.from.parse::<Mailbox>() {
+            Ok(from) => from,
+            Err(error) => return Err(MailerError::InvalidAddress(error.to_string())),
+        };
+
+        let builder = match config.security {
+            SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)),
+            SmtpSecurity::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host),
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host),
+        };
+
+        let mut builder = match builder {
+            Ok(builder) => builder.port(config.port),
+            Err(error) => return Err(MailerError::Delivery(error.to_string())),
+        };
+
+        if let (Some(username), Some(password)) = (config.username, config.password) {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let to = match mail.to.parse::<Mailbox>() {
+            Ok(to) => to,
+            Err(error) => return Err(MailerError::InvalidAddress(error.to_string())),
+        };
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(mail.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(mail.body);
+
+        let message = match message {
+            Ok(message) => message,
+            Err(error) => return Err(MailerError::Delivery(error.to_string())),
+        };
+
+        match self.transport.send(message).await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(MailerError::Delivery(error.to_string())),
+        }
+    }
+}
@@ -1 +1,7 @@
-pub mod persistence;
\ No newline at end of file
+pub mod persistence;
+pub mod http;
+pub mod security;
+pub mod clock;
+pub mod config;
+pub mod mail;
+pub mod jobs;
\ No newline at end of file
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod test_categories_repository_impl {
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+    use crate::application::repositories::{Repository, RepositoryError};
+    use crate::application::repositories::categories::CategoriesRepository;
+    use crate::domain::entities::categories::{Categories, CategoriesInput, DEFAULT_CATEGORY_ID};
+    use crate::domain::entities::users::{Users, UsersInput};
+    use crate::domain::value_objects::ValueObjectTrait;
+    use crate::infrastructure::persistence::database::__tests__::support::TestDatabase;
+    use crate::infrastructure::persistence::database::categories::CategoriesRepositoryImpl;
+    use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
+
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    struct Sut {
+        database: TestDatabase,
+        repository: CategoriesRepositoryImpl,
+        user: Users,
+    }
+
+    async fn setup_sut() -> Option<Sut> {
+        let database = TestDatabase::create().await?;
+
+        let user = Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: "doejoe@test.com".to_string(),
+            password: "12345678".to_string(),
+        }, NOW).unwrap();
+        let user = UsersRepositoryImpl::new(database.pool.clone()).save(user).await.unwrap();
+
+        let repository = CategoriesRepositoryImpl::new(database.pool.clone());
+
+        Some(Sut { database, repository, user })
+    }
+
+    fn category(sut: &Sut, name: &str, color: &str) -> Categories {
+        Categories::new(&CategoriesInput {
+            name: name.to_string(),
+            color: color.to_string(),
+            user_id: sut.user.id.to_string(),
+        }, NOW).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_should_save_and_find_a_category() {
+        let Some(sut) = setup_sut().await else { return };
+        let category = category(&sut, "New category", "#ff0000");
+
+        sut.repository.save(category.clone()).await.unwrap();
+        let found = sut.repository.find_by_id(category.id).await.unwrap();
+
+        assert_eq!(found.id, category.id);
+        assert_eq!(found.name, "New category");
+        assert_eq!(found.user_id, sut.user.id);
+        assert_eq!(found.created_at, NOW);
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_persist_the_color_as_its_canonical_string() {
+        let Some(sut) = setup_sut().await else { return };
+        let category = sut.repository.save(category(&sut, "New category", "rgb(255,0,0)")).await.unwrap();
+
+        let stored: String = sqlx::query_scalar("SELECT color FROM categories WHERE id = $1")
+            .bind(category.id)
+            .fetch_one(&sut.database.pool)
+            .await
+            .unwrap();
+
+        assert_eq!(stored, "rgb(255, 0, 0)");
+        assert_eq!(category.color.to_string(), stored);
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_find_the_categories_of_a_user() {
+        let Some(sut) = setup_sut().await else { return };
+
+        sut.repository.save(category(&sut, "First category", "#ff0000")).await.unwrap();
+        sut.repository.save(category(&sut, "Second category", "#00ff00")).await.unwrap();
+
+        let categories = sut.repository.find_by_user_id(sut.user.id).await.unwrap();
+
+        assert_eq!(categories.len(), 2);
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_find_the_seeded_default_category() {
+        let Some(sut) = setup_sut().await else { return };
+
+        let category = sut.repository.find_by_id(DEFAULT_CATEGORY_ID.parse().unwrap()).await.unwrap();
+
+        assert!(category.is_default());
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_an_update_with_a_stale_version() {
+        let Some(sut) = setup_sut().await else { return };
+        let mut category = sut.repository.save(category(&sut, "New category", "#ff0000")).await.unwrap();
+
+        category.name = "Renamed category".to_string();
+        sut.repository.update(category.clone()).await.unwrap();
+        let stale = sut.repository.update(category).await;
+
+        assert!(matches!(stale, Err(RepositoryError::Conflict(_))));
+
+        sut.database.drop().await;
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod test_database_config {
+    use std::env;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use crate::infrastructure::config::ConfigError;
+    use crate::infrastructure::persistence::database::connection::DatabaseConfig;
+
+    const KEYS: [&str; 6] = [
+        "DATABASE_URL",
+        "POOL_SIZE",
+        "DATABASE_CONNECT_TIMEOUT",
+        "DATABASE_ACQUIRE_TIMEOUT",
+        "DATABASE_CONNECT_RETRIES",
+        "DATABASE_RETRY_BACKOFF_MS",
+    ];
+
+    static ENV: Mutex<()> = Mutex::new(());
+
+    fn with_env<T>(vars: &[(&str, &str)], test: impl FnOnce() -> T) -> T {
+        let _guard = ENV.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        for key in KEYS {
+            env::remove_var(key);
+        }
+        for (key, value) in vars {
+            env::set_var(key, value);
+        }
+
+        let result = test();
+
+        for key in KEYS {
+            env::remove_var(key);
+        }
+
+        result
+    }
+
+    #[test]
+    fn it_should_fall_back_to_the_defaults() {
+        let config = with_env(&[], DatabaseConfig::from_env).unwrap();
+
+        assert!(config.url.starts_with("postgres://"));
+        assert_eq!(config.pool_size, 10);
+        assert_eq!(config.connect_timeout, Duration::from_secs(5));
+        assert_eq!(config.acquire_timeout, Duration::from_secs(5));
+        assert_eq!(config.connect_retries, 5);
+        assert_eq!(config.retry_backoff, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn it_should_read_every_setting_from_the_environment() {
+        let config = with_env(&[
+            ("DATABASE_URL", "postgres://user:secret@db:5432/aluraflix"),
+            ("POOL_SIZE", "20"),
+            ("DATABASE_CONNECT_TIMEOUT", "3"),
+            ("DATABASE_ACQUIRE_TIMEOUT", "7"),
+            ("DATABASE_CONNECT_RETRIES", "2"),
+            ("DATABASE_RETRY_BACKOFF_MS", "250"),
+        ], DatabaseConfig::from_env).unwrap();
+
+        assert_eq!(config.url, "postgres://user:secret@db:5432/aluraflix");
+        assert_eq!(config.pool_size, 20);
+        assert_eq!(config.connect_timeout, Duration::from_secs(3));
+        assert_eq!(config.acquire_timeout, Duration::from_secs(7));
+        assert_eq!(config.connect_retries, 2);
+        assert_eq!(config.retry_backoff, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn it_should_reject_a_non_numeric_pool_size() {
+        let result = with_env(&[("POOL_SIZE", "many")], DatabaseConfig::from_env);
+
+        assert!(matches!(result, Err(ConfigError::Invalid { key, value, .. }) if key == "POOL_SIZE" && value == "many"));
+    }
+
+    #[test]
+    fn it_should_reject_a_zero_pool_size() {
+        let result = with_env(&[("POOL_SIZE", "0")], DatabaseConfig::from_env);
+
+        assert!(matches!(result, Err(ConfigError::Invalid { key, .. }) if key == "POOL_SIZE"));
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_timeout() {
+        let result = with_env(&[("DATABASE_CONNECT_TIMEOUT", "-1")], DatabaseConfig::from_env);
+
+        assert!(matches!(result, Err(ConfigError::Invalid { key, .. }) if key == "DATABASE_CONNECT_TIMEOUT"));
+    }
+}
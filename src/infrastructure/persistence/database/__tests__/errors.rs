@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod test_database_errors {
+    use time::{Duration, OffsetDateTime};
+    use crate::application::repositories::{Repository, RepositoryError};
+    use crate::domain::entities::refresh_tokens::RefreshTokens;
+    use crate::domain::entities::users::{Users, UsersInput};
+    use crate::domain::errors::domain_error::DomainError;
+    use crate::domain::value_objects::ids::{TokenFamilyId, UserId};
+    use crate::infrastructure::persistence::database::__tests__::support::TestDatabase;
+    use crate::infrastructure::persistence::database::from_sqlstate;
+    use crate::infrastructure::persistence::database::refresh_tokens::RefreshTokensRepositoryImpl;
+    use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
+
+    fn user(email: &str) -> Users {
+        Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: email.to_string(),
+            password: "12345678".to_string(),
+        }, OffsetDateTime::now_utc()).unwrap()
+    }
+
+    fn detail(error: DomainError) -> String {
+        format!("{} {:?}", error.message, error.description)
+    }
+
+    #[test]
+    fn it_should_map_a_unique_violation_to_already_exists() {
+        assert!(matches!(from_sqlstate("23505"), Some(RepositoryError::AlreadyExists(_))));
+    }
+
+    #[test]
+    fn it_should_map_a_foreign_key_violation_to_a_fixed_invalid_reference() {
+        match from_sqlstate("23503") {
+            Some(RepositoryError::Domain(error)) => assert_eq!(error.message, "Invalid reference"),
+            other => panic!("unexpected mapping: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_should_map_any_data_exception_to_a_fixed_invalid_value() {
+        for code in ["22001", "22007", "22P02"] {
+            match from_sqlstate(code) {
+                Some(RepositoryError::Domain(error)) => assert_eq!(error.message, "Invalid value"),
+                other => panic!("unexpected mapping for {}: {:?}", code, other),
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_leave_other_sqlstates_unmapped() {
+        assert!(from_sqlstate("23514").is_none());
+        assert!(from_sqlstate("").is_none());
+    }
+
+    #[tokio::test]
+    async fn it_should_report_a_duplicate_email_as_already_exists() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let repository = UsersRepositoryImpl::new(database.pool.clone());
+
+        repository.save(user("doejoe@test.com")).await.unwrap();
+        let result = repository.save(user("doejoe@test.com")).await;
+
+        assert!(matches!(result, Err(RepositoryError::AlreadyExists(message)) if !message.contains("users_email_key")));
+
+        database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_not_expose_constraint_names_on_an_invalid_reference() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let repository = RefreshTokensRepositoryImpl::new(database.pool.clone());
+        let (token, _) = RefreshTokens::new(UserId::generate(), TokenFamilyId::generate(), Duration::days(1), OffsetDateTime::now_utc());
+
+        let result = repository.save(token).await;
+
+        match result {
+            Err(RepositoryError::Domain(error)) => {
+                let detail = detail(error);
+                assert!(detail.contains("Invalid reference"));
+                assert!(!detail.contains("refresh_tokens"));
+                assert!(!detail.contains("fkey"));
+            }
+            other => panic!("unexpected result: {:?}", other.map(|token| token.id)),
+        }
+
+        database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_not_expose_driver_text_on_an_invalid_value() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let repository = UsersRepositoryImpl::new(database.pool.clone());
+        let mut user = user("doejoe@test.com");
+        user.name = "a".repeat(300);
+
+        let result = repository.save(user).await;
+
+        match result {
+            Err(RepositoryError::Domain(error)) => {
+                let detail = detail(error);
+                assert!(detail.contains("Invalid value"));
+                assert!(!detail.contains("character varying"));
+            }
+            other => panic!("unexpected result: {:?}", other.map(|user| user.id)),
+        }
+
+        database.drop().await;
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod test_migrations {
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+    use uuid::Uuid;
+    use crate::application::repositories::{Repository, RepositoryError};
+    use crate::domain::entities::categories::{Categories, CategoriesInput};
+    use crate::domain::entities::users::{Users, UsersInput, SYSTEM_USER_ID};
+    use crate::domain::entities::videos::{Videos, VideosInput};
+    use crate::infrastructure::persistence::database::__tests__::support::TestDatabase;
+    use crate::infrastructure::persistence::database::categories::CategoriesRepositoryImpl;
+    use crate::infrastructure::persistence::database::migrations;
+    use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
+    use crate::infrastructure::persistence::database::videos::VideosRepositoryImpl;
+    use crate::domain::value_objects::password::DISABLED_PASSWORD_HASH;
+    use crate::domain::value_objects::ValueObjectTrait;
+
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    async fn seed_user(database: &TestDatabase) -> Users {
+        let user = Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: "doejoe@test.com".to_string(),
+            password: "12345678".to_string(),
+        }, NOW).unwrap();
+
+        UsersRepositoryImpl::new(database.pool.clone()).save(user).await.unwrap()
+    }
+
+    async fn seed_category(database: &TestDatabase, user: &Users) -> Categories {
+        let category = Categories::new(&CategoriesInput {
+            name: "Category".to_string(),
+            color: "#ff0000".to_string(),
+            user_id: user.id.to_string(),
+        }, NOW).unwrap();
+
+        CategoriesRepositoryImpl::new(database.pool.clone()).save(category).await.unwrap()
+    }
+
+    fn video(category_id: String, user: &Users) -> Videos {
+        Videos::new(&VideosInput {
+            title: "New video".to_string(),
+            description: "Description".to_string(),
+            url: "https://www.youtube.com/watch?v=6n3pFFPSlW4".to_string(),
+            category_id: Some(category_id),
+            user_id: user.id.to_string(),
+        }, NOW).unwrap()
+    }
+
+    fn sqlstate(error: sqlx::Error) -> Option<String> {
+        error.as_database_error().and_then(|error| error.code()).map(|code| code.to_string())
+    }
+
+    #[tokio::test]
+    async fn it_should_report_every_migration_as_applied() {
+        let Some(database) = TestDatabase::create().await else { return };
+
+        let statuses = migrations::status(&database.pool).await.unwrap();
+
+        assert!(!statuses.is_empty());
+        assert!(statuses.iter().all(|status| status.applied));
+
+        database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_revert_and_reapply_every_migration() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let total = migrations::status(&database.pool).await.unwrap().len();
+
+        let mut reverted = Vec::new();
+        while let Some(version) = migrations::revert(&database.pool).await.unwrap() {
+            reverted.push(version);
+        }
+        let statuses = migrations::status(&database.pool).await.unwrap();
+
+        assert_eq!(reverted.len(), total);
+        assert!(statuses.iter().all(|status| !status.applied));
+
+        migrations::run(&database.pool).await.unwrap();
+        let statuses = migrations::status(&database.pool).await.unwrap();
+
+        assert!(statuses.iter().all(|status| status.applied));
+
+        database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_a_video_with_an_unknown_category() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let user = seed_user(&database).await;
+
+        let result = VideosRepositoryImpl::new(database.pool.clone())
+            .save(video(Uuid::now_v7().to_string(), &user))
+            .await;
+
+        assert!(matches!(result, Err(RepositoryError::Domain(error)) if error.message == "Invalid reference"));
+
+        database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_restrict_deleting_a_category_that_still_has_videos() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let user = seed_user(&database).await;
+        let category = seed_category(&database, &user).await;
+        VideosRepositoryImpl::new(database.pool.clone())
+            .save(video(category.id.to_string(), &user))
+            .await
+            .unwrap();
+
+        let result = sqlx::query("DELETE FROM categories WHERE id = $1")
+            .bind(category.id)
+            .execute(&database.pool)
+            .await;
+
+        assert_eq!(result.err().and_then(sqlstate).as_deref(), Some("23503"));
+
+        database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_an_unknown_role() {
+        let Some(database) = TestDatabase::create().await else { return };
+        let user = seed_user(&database).await;
+
+        let result = sqlx::query("UPDATE users SET role = 'owner' WHERE id = $1")
+            .bind(user.id)
+            .execute(&database.pool)
+            .await;
+
+        assert_eq!(result.err().and_then(sqlstate).as_deref(), Some("23514"));
+
+        database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_seed_the_system_user_with_a_disabled_password() {
+        let Some(database) = TestDatabase::create().await else { return };
+
+        let user = UsersRepositoryImpl::new(database.pool.clone())
+            .find_by_id(SYSTEM_USER_ID.parse().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(user.password.to_string(), DISABLED_PASSWORD_HASH);
+        assert!(!user.password.verify(DISABLED_PASSWORD_HASH));
+
+        database.drop().await;
+    }
+}
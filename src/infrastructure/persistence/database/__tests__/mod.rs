@@ -0,0 +1,7 @@
+pub mod support;
+mod errors;
+mod videos;
+mod categories;
+mod migrations;
+mod connection;
+mod users;
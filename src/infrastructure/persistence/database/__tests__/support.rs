@@ -0,0 +1,68 @@
+#[cfg(test)]
+use std::str::FromStr;
+#[cfg(test)]
+use sqlx::PgPool;
+#[cfg(test)]
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+#[cfg(test)]
+use uuid::Uuid;
+#[cfg(test)]
+use crate::infrastructure::persistence::database::migrations;
+
+#[cfg(test)]
+pub struct TestDatabase {
+    pub pool: PgPool,
+    admin: PgPool,
+    name: String,
+    url: String,
+}
+
+#[cfg(test)]
+impl TestDatabase {
+    pub async fn create() -> Option<TestDatabase> {
+        let url = match std::env::var("TEST_DATABASE_URL") {
+            Ok(url) if !url.is_empty() => url,
+            _ => {
+                eprintln!("TEST_DATABASE_URL is not set, skipping Postgres test");
+                return None;
+            }
+        };
+
+        let admin = PgPool::connect(&url).await.expect("TEST_DATABASE_URL must point to a reachable Postgres");
+        let name = format!("aluraflix_test_{}", Uuid::now_v7().simple());
+
+        sqlx::query(&format!("CREATE DATABASE {}", name)).execute(&admin).await.unwrap();
+
+        let options = PgConnectOptions::from_str(&url).unwrap().database(&name);
+        let pool = PgPoolOptions::new().max_connections(5).connect_with(options).await.unwrap();
+
+        migrations::run(&pool).await.unwrap();
+
+        Some(TestDatabase { pool, admin, name, url })
+    }
+
+    pub fn url(&self) -> String {
+        let (base, query) = match self.url.split_once('?') {
+            Some((base, query)) => (base, format!("?{}", query)),
+            None => (self.url.as_str(), String::new()),
+        };
+        let authority = base.find("://").map(|index| index + 3).unwrap_or(0);
+        let host = match base[authority..].find('/') {
+            Some(index) => &base[..authority + index],
+            None => base,
+        };
+
+        format!("{}/{}{}", host, self.name, query)
+    }
+
+    pub async fn drop(self) {
+        self.pool.close().await;
+
+        sqlx::query(&format!("DROP DATABASE IF EXISTS {} WITH (FORCE)", self.name))
+            .execute(&self.admin)
+            .await
+            .unwrap();
+
+        self.admin.close().await;
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod test_users_repository_impl {
+    use std::sync::Arc;
+    use time::{Duration, OffsetDateTime};
+    use time::macros::datetime;
+    use crate::application::identity::CurrentUser;
+    use crate::application::repositories::{Repository, SoftDeleteRepository};
+    use crate::application::usecases::retention::{RetentionPolicy, RetentionUseCase};
+    use crate::application::usecases::users::UsersUseCase;
+    use crate::domain::entities::categories::{Categories, CategoriesInput};
+    use crate::domain::entities::users::{Users, UsersInput};
+    use crate::domain::entities::videos::{Videos, VideosInput};
+    use crate::domain::value_objects::role::Role;
+    use crate::infrastructure::clock::{FixedClock, SystemClock};
+    use crate::infrastructure::persistence::database::__tests__::support::TestDatabase;
+    use crate::infrastructure::persistence::database::categories::CategoriesRepositoryImpl;
+    use crate::infrastructure::persistence::database::unit_of_work::UnitOfWorkImpl;
+    use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
+    use crate::infrastructure::persistence::database::videos::VideosRepositoryImpl;
+
+    const ADMIN_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a8";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    struct Sut {
+        database: TestDatabase,
+        users_repository: Arc<UsersRepositoryImpl>,
+        videos_repository: VideosRepositoryImpl,
+        categories_repository: CategoriesRepositoryImpl,
+        owner: Users,
+        category: Categories,
+        owned_video: Videos,
+        foreign_video: Videos,
+    }
+
+    async fn seed_user(repository: &UsersRepositoryImpl, email: &str) -> Users {
+        let user = Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: email.to_string(),
+            password: "12345678".to_string(),
+        }, NOW).unwrap();
+
+        repository.save(user).await.unwrap()
+    }
+
+    fn video(category: &Categories, user: &Users) -> Videos {
+        Videos::new(&VideosInput {
+            title: "New video".to_string(),
+            description: "Description".to_string(),
+            url: "https://www.youtube.com/watch?v=6n3pFFPSlW4".to_string(),
+            category_id: Some(category.id.to_string()),
+            user_id: user.id.to_string(),
+        }, NOW).unwrap()
+    }
+
+    async fn setup_sut() -> Option<Sut> {
+        let database = TestDatabase::create().await?;
+        let users_repository = Arc::new(UsersRepositoryImpl::new(database.pool.clone()));
+        let videos_repository = VideosRepositoryImpl::new(database.pool.clone());
+        let categories_repository = CategoriesRepositoryImpl::new(database.pool.clone());
+
+        let owner = seed_user(&users_repository, "owner@test.com").await;
+        let other = seed_user(&users_repository, "other@test.com").await;
+
+        let category = categories_repository.save(Categories::new(&CategoriesInput {
+            name: "Owned category".to_string(),
+            color: "#ff0000".to_string(),
+            user_id: owner.id.to_string(),
+        }, NOW).unwrap()).await.unwrap();
+
+        let owned_video = videos_repository.save(video(&category, &owner)).await.unwrap();
+        let foreign_video = videos_repository.save(video(&category, &other)).await.unwrap();
+
+        Some(Sut {
+            database,
+            users_repository,
+            videos_repository,
+            categories_repository,
+            owner,
+            category,
+            owned_video,
+            foreign_video,
+        })
+    }
+
+    fn admin() -> CurrentUser {
+        CurrentUser::new(ADMIN_ID.parse().unwrap(), Role::Admin)
+    }
+
+    async fn assert_content_erased(sut: &Sut) {
+        assert!(sut.users_repository.find_deleted_by_id(sut.owner.id).await.is_err());
+        assert!(sut.categories_repository.find_deleted_by_id(sut.category.id).await.is_err());
+        assert!(sut.videos_repository.find_deleted_by_id(sut.owned_video.id).await.is_err());
+
+        let foreign_video = sut.videos_repository.find_by_id(sut.foreign_video.id).await.unwrap();
+        assert_eq!(foreign_video.category_id, Categories::default_id());
+    }
+
+    #[tokio::test]
+    async fn it_should_not_cascade_a_user_delete_into_their_content() {
+        let Some(sut) = setup_sut().await else { return };
+
+        let error = sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(sut.owner.id)
+            .execute(&sut.database.pool)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.as_database_error().and_then(|error| error.code()).as_deref(), Some("23503"));
+        assert!(sut.videos_repository.find_by_id(sut.owned_video.id).await.is_ok());
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_purge_a_user_who_owns_content() {
+        let Some(sut) = setup_sut().await else { return };
+        let clock = Arc::new(FixedClock::new(NOW));
+        let unit_of_work = Arc::new(UnitOfWorkImpl::new(sut.database.pool.clone()).with_clock(clock.clone()));
+        let use_case = UsersUseCase::new(sut.users_repository.clone(), unit_of_work, clock);
+        let id = sut.owner.id.to_string();
+
+        use_case.delete(&admin(), &id, None).await.unwrap();
+
+        assert!(sut.categories_repository.find_deleted_by_id(sut.category.id).await.is_ok());
+        assert!(sut.videos_repository.find_deleted_by_id(sut.owned_video.id).await.is_ok());
+
+        use_case.purge(&admin(), &id).await.unwrap();
+
+        assert_content_erased(&sut).await;
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_restore_a_user_with_the_content_deleted_alongside() {
+        let Some(sut) = setup_sut().await else { return };
+        let clock = SystemClock::shared();
+        let unit_of_work = Arc::new(UnitOfWorkImpl::new(sut.database.pool.clone()).with_clock(clock.clone()));
+        let use_case = UsersUseCase::new(sut.users_repository.clone(), unit_of_work, clock);
+        let id = sut.owner.id.to_string();
+
+        use_case.delete(&admin(), &id, None).await.unwrap();
+        let user = use_case.restore(&admin(), &id).await.unwrap();
+
+        assert!(!user.is_deleted());
+        assert!(sut.categories_repository.find_by_id(sut.category.id).await.is_ok());
+        assert!(sut.videos_repository.find_by_id(sut.owned_video.id).await.is_ok());
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_purge_an_expired_user_who_owns_content() {
+        let Some(sut) = setup_sut().await else { return };
+        let clock = Arc::new(FixedClock::new(NOW));
+        let unit_of_work = Arc::new(UnitOfWorkImpl::new(sut.database.pool.clone()).with_clock(clock.clone()));
+        let use_case = UsersUseCase::new(sut.users_repository.clone(), unit_of_work.clone(), clock.clone());
+
+        use_case.delete(&admin(), &sut.owner.id.to_string(), None).await.unwrap();
+
+        let deleted = sut.users_repository.find_deleted_by_id(sut.owner.id).await.unwrap();
+        assert_eq!(deleted.deleted_at, Some(NOW));
+
+        let policy = RetentionPolicy {
+            retention: Duration::days(30),
+            interval: Duration::hours(1),
+        };
+        clock.advance(Duration::days(31));
+        let retention = RetentionUseCase::new(unit_of_work, policy, clock);
+
+        let summary = retention.purge_expired().await.unwrap();
+
+        assert_eq!(summary.users, 1);
+        assert_content_erased(&sut).await;
+
+        sut.database.drop().await;
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod test_videos_repository_impl {
+    use std::sync::Arc;
+    use time::{Duration, OffsetDateTime};
+    use time::macros::datetime;
+    use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+    use crate::application::repositories::videos::VideosRepository;
+    use crate::domain::entities::categories::{Categories, CategoriesInput};
+    use crate::domain::entities::users::{Users, UsersInput};
+    use crate::domain::entities::videos::{Videos, VideosInput};
+    use crate::domain::value_objects::ValueObjectTrait;
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::persistence::database::__tests__::support::TestDatabase;
+    use crate::infrastructure::persistence::database::categories::CategoriesRepositoryImpl;
+    use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
+    use crate::infrastructure::persistence::database::videos::VideosRepositoryImpl;
+
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    struct Sut {
+        database: TestDatabase,
+        repository: VideosRepositoryImpl,
+        user: Users,
+        category: Categories,
+    }
+
+    async fn setup_sut() -> Option<Sut> {
+        let database = TestDatabase::create().await?;
+
+        let user = Users::new(&UsersInput {
+            name: "John Doe".to_string(),
+            email: "doejoe@test.com".to_string(),
+            password: "12345678".to_string(),
+        }, NOW).unwrap();
+        let user = UsersRepositoryImpl::new(database.pool.clone()).save(user).await.unwrap();
+
+        let category = Categories::new(&CategoriesInput {
+            name: "Category".to_string(),
+            color: "#ff0000".to_string(),
+            user_id: user.id.to_string(),
+        }, NOW).unwrap();
+        let category = CategoriesRepositoryImpl::new(database.pool.clone()).save(category).await.unwrap();
+
+        let repository = VideosRepositoryImpl::new(database.pool.clone());
+
+        Some(Sut { database, repository, user, category })
+    }
+
+    fn video(sut: &Sut, title: &str) -> Videos {
+        Videos::new(&VideosInput {
+            title: title.to_string(),
+            description: "Description".to_string(),
+            url: "https://www.youtube.com/watch?v=6n3pFFPSlW4".to_string(),
+            category_id: Some(sut.category.id.to_string()),
+            user_id: sut.user.id.to_string(),
+        }, NOW).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_should_save_and_find_a_video() {
+        let Some(sut) = setup_sut().await else { return };
+        let video = video(&sut, "New video");
+
+        sut.repository.save(video.clone()).await.unwrap();
+        let found = sut.repository.find_by_id(video.id).await.unwrap();
+
+        assert_eq!(found.id, video.id);
+        assert_eq!(found.title, "New video");
+        assert_eq!(found.url.value(), video.url.value());
+        assert_eq!(found.category_id, sut.category.id);
+        assert_eq!(found.user_id, sut.user.id);
+        assert_eq!(found.created_at, NOW);
+        assert_eq!(found.version, 1);
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_update_a_video_and_bump_its_version() {
+        let Some(sut) = setup_sut().await else { return };
+        let mut video = sut.repository.save(video(&sut, "New video")).await.unwrap();
+
+        video.title = "Updated video".to_string();
+        let updated = sut.repository.update(video.clone()).await.unwrap();
+        let stale = sut.repository.update(video).await;
+
+        assert_eq!(updated.title, "Updated video");
+        assert_eq!(updated.version, 2);
+        assert!(matches!(stale, Err(RepositoryError::Conflict(_))));
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_find_the_videos_of_a_category() {
+        let Some(sut) = setup_sut().await else { return };
+
+        sut.repository.save(video(&sut, "First video")).await.unwrap();
+        sut.repository.save(video(&sut, "Second video")).await.unwrap();
+
+        let videos = sut.repository.find_by_category_id(sut.category.id).await.unwrap();
+
+        assert_eq!(videos.len(), 2);
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_hide_a_deleted_video() {
+        let Some(sut) = setup_sut().await else { return };
+        let video = sut.repository.save(video(&sut, "New video")).await.unwrap();
+
+        assert!(sut.repository.delete(video.id).await.is_none());
+
+        assert!(matches!(sut.repository.find_by_id(video.id).await, Err(RepositoryError::NotFound(_))));
+        assert!(sut.repository.find_by_category_id(sut.category.id).await.unwrap().is_empty());
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_only_delete_a_video_at_the_expected_version() {
+        let Some(sut) = setup_sut().await else { return };
+        let mut video = sut.repository.save(video(&sut, "New video")).await.unwrap();
+
+        video.title = "Updated video".to_string();
+        sut.repository.update(video.clone()).await.unwrap();
+
+        assert!(matches!(sut.repository.delete_at_version(video.id, 1).await, Some(RepositoryError::Conflict(_))));
+        assert!(sut.repository.find_by_id(video.id).await.is_ok());
+
+        assert!(sut.repository.delete_at_version(video.id, 2).await.is_none());
+        assert!(matches!(sut.repository.delete_at_version(video.id, 3).await, Some(RepositoryError::NotFound(_))));
+
+        sut.database.drop().await;
+    }
+
+    #[tokio::test]
+    async fn it_should_stamp_deletes_and_restores_with_the_repository_clock() {
+        let Some(sut) = setup_sut().await else { return };
+        let clock = Arc::new(FixedClock::new(NOW + Duration::days(1)));
+        let repository = VideosRepositoryImpl::new(sut.database.pool.clone()).with_clock(clock.clone());
+        let video = repository.save(video(&sut, "New video")).await.unwrap();
+
+        assert!(repository.delete(video.id).await.is_none());
+        let deleted = repository.find_deleted_by_id(video.id).await.unwrap();
+
+        assert_eq!(deleted.deleted_at, Some(NOW + Duration::days(1)));
+        assert_eq!(deleted.updated_at, NOW + Duration::days(1));
+
+        clock.advance(Duration::days(1));
+        let restored = repository.restore(video.id).await.unwrap();
+
+        assert_eq!(restored.deleted_at, None);
+        assert_eq!(restored.updated_at, NOW + Duration::days(2));
+
+        sut.database.drop().await;
+    }
+}
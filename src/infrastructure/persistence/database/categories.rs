@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::application::repositories::categories::CategoriesRepository;
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::categories::Categories;
+use crate::domain::value_objects::ids::{CategoryId, UserId};
+use crate::domain::value_objects::ValueObjectTrait;
+use crate::infrastructure::persistence::database::conflict_or_not_found;
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::database::executor::{FetchWith, ExecuteWith, PgExecutor};
+use crate::infrastructure::persistence::database::pagination::{fetch_page, PageQuery};
+
+const PAGE_QUERY: PageQuery = PageQuery {
+    table: "categories",
+    columns: "id, name, color, user_id, created_at, updated_at, deleted_at, version",
+    types: &[("created_at", "timestamptz"), ("updated_at", "timestamptz"), ("name", "text"), ("user_id", "uuid")],
+    scope: Some("deleted_at IS NULL"),
+};
+
+pub struct CategoriesRepositoryImpl {
+    pub executor: PgExecutor,
+    clock: ClockContract,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct CategoriesModel {
+    pub id: CategoryId,
+    pub name: String,
+    pub color: String,
+    pub user_id: UserId,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub deleted_at: Option<OffsetDateTime>,
+    pub version: i32,
+}
+
+impl From<&Categories> for CategoriesModel {
+    fn from(category: &Categories) -> Self {
+        Self {
+            id: category.id,
+            name: category.name.to_string(),
+            color: category.color.to_string(),
+            user_id: category.user_id,
+            created_at: category.created_at,
+            updated_at: category.updated_at,
+            deleted_at: category.deleted_at,
+            version: category.version,
+        }
+    }
+}
+
+impl CategoriesRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { executor: PgExecutor::from(pool), clock: SystemClock::shared() }
+    }
+
+    pub fn with_executor(executor: PgExecutor) -> Self {
+        Self { executor, clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl Repository<Categories, CategoryId> for CategoriesRepositoryImpl {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<Categories>, RepositoryError> {
+        fetch_page::<Categories, CategoriesModel>(&self.executor, &PAGE_QUERY, &request).await
+    }
+
+    async fn find_by_id(&self, id: CategoryId) -> Result<Categories, RepositoryError> {
+        let model = sqlx::query_as::<_, CategoriesModel>(
+            r#"
+            SELECT id, name, color, user_id, created_at, updated_at, deleted_at, version
+            FROM categories
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+            .bind(id)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Categories::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Category not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn save(&self, entity: Categories) -> Result<Categories, RepositoryError> {
+        let model = CategoriesModel::from(&entity);
+
+        let model = sqlx::query_as::<_, CategoriesModel>(
+            r#"
+            INSERT INTO categories (id, name, color, user_id, created_at, updated_at, version)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, name, color, user_id, created_at, updated_at, deleted_at, version
+            "#,
+        )
+            .bind(model.id)
+            .bind(model.name)
+            .bind(model.color)
+            .bind(model.user_id)
+            .bind(model.created_at)
+            .bind(model.updated_at)
+            .bind(model.version)
+            .fetch_one_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => Ok(Categories::from(model)),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn update(&self, entity: Categories) -> Result<Categories, RepositoryError> {
+        let model = CategoriesModel::from(&entity);
+        let id = model.id;
+
+        let model = sqlx::query_as::<_, CategoriesModel>(
+            r#"
+            UPDATE categories
+            SET name = $2, color = $3, updated_at = $4, version = version + 1
+            WHERE id = $1 AND version = $5 AND deleted_at IS NULL
+            RETURNING id, name, color, user_id, created_at, updated_at, deleted_at, version
+            "#,
+        )
+            .bind(model.id)
+            .bind(model.name)
+            .bind(model.color)
+            .bind(model.updated_at)
+            .bind(model.version)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Categories::from(model)),
+            Ok(None) => Err(conflict_or_not_found(&self.executor, "categories", id.value(), "Category").await),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn delete(&self, id: CategoryId) -> Option<RepositoryError> {
+        let result = sqlx::query("UPDATE categories SET deleted_at = $2, updated_at = $2, version = version + 1 WHERE id = $1 AND deleted_at IS NULL")
+            .bind(id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(RepositoryError::NotFound("Category not found".to_string())),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
+
+#[async_trait]
+impl SoftDeleteRepository<Categories, CategoryId> for CategoriesRepositoryImpl {
+    async fn find_deleted_by_id(&self, id: CategoryId) -> Result<Categories, RepositoryError> {
+        let model = sqlx::query_as::<_, CategoriesModel>(
+            r#"
+            SELECT id, name, color, user_id, created_at, updated_at, deleted_at, version
+            FROM categories
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            "#,
+        )
+            .bind(id)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Categories::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Category not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn delete_at_version(&self, id: CategoryId, version: i32) -> Option<RepositoryError> {
+        let result = sqlx::query("UPDATE categories SET deleted_at = $2, updated_at = $2, version = version + 1 WHERE id = $1 AND version = $3 AND deleted_at IS NULL")
+            .bind(id)
+            .bind(self.clock.now())
+            .bind(version)
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(conflict_or_not_found(&self.executor, "categories", id.value(), "Category").await),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+
+    async fn restore(&self, id: CategoryId) -> Result<Categories, RepositoryError> {
+        let model = sqlx::query_as::<_, CategoriesModel>(
+            r#"
+            UPDATE categories
+            SET deleted_at = NULL, updated_at = $2, version = version + 1
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            RETURNING id, name, color, user_id, created_at, updated_at, deleted_at, version
+            "#,
+        )
+            .bind(id)
+            .bind(self.clock.now())
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Categories::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Category not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn purge(&self, id: CategoryId) -> Option<RepositoryError> {
+        let result = sqlx::query("DELETE FROM categories WHERE id = $1 AND deleted_at IS NOT NULL")
+            .bind(id)
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(RepositoryError::NotFound("Category not found".to_string())),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+
+    async fn purge_deleted_before(&self, cutoff: OffsetDateTime) -> Result<u64, RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM categories
+            WHERE deleted_at < $1
+              AND NOT EXISTS (SELECT 1 FROM videos WHERE videos.category_id = categories.id)
+            "#,
+        )
+            .bind(cutoff)
+            .execute_with(&self.executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[async_trait]
+impl CategoriesRepository for CategoriesRepositoryImpl {
+    async fn find_by_category_id(&self, category_id: CategoryId) -> Result<Vec<Categories>, RepositoryError> {
+        match self.find_by_id(category_id).await {
+            Ok(category) => Ok(vec![category]),
+            Err(RepositoryError::NotFound(_)) => Ok(vec![]),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_user_id(&self, user_id: UserId) -> Result<Vec<Categories>, RepositoryError> {
+        let models = sqlx::query_as::<_, CategoriesModel>(
+            r#"
+            SELECT id, name, color, user_id, created_at, updated_at, deleted_at, version
+            FROM categories
+            WHERE user_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at
+            "#,
+        )
+            .bind(user_id)
+            .fetch_all_with(&self.executor)
+            .await?;
+
+        Ok(models.into_iter().map(Categories::from).collect())
+    }
+    async fn find_deleted_by_user_id(&self, user_id: UserId) -> Result<Vec<Categories>, RepositoryError> {
+        let models = sqlx::query_as::<_, CategoriesModel>(
+            r#"
+            SELECT id, name, color, user_id, created_at, updated_at, deleted_at, version
+            FROM categories
+            WHERE user_id = $1 AND deleted_at IS NOT NULL
+            ORDER BY created_at
+            "#,
+        )
+            .bind(user_id)
+            .fetch_all_with(&self.executor)
+            .await?;
+
+        Ok(models.into_iter().map(Categories::from).collect())
+    }
+}
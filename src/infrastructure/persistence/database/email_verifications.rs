@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::email_verifications::EmailVerificationsRepository;
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::email_verifications::EmailVerifications;
+use crate::domain::value_objects::ids::{EmailVerificationId, UserId};
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::database::executor::{FetchWith, ExecuteWith, PgExecutor};
+use crate::infrastructure::persistence::database::pagination::{fetch_page, PageQuery};
+
+const PAGE_QUERY: PageQuery = PageQuery {
+    table: "email_verifications",
+    columns: "id, user_id, token_hash, expires_at, used_at, created_at",
+    types: &[("created_at", "timestamptz"), ("expires_at", "timestamptz"), ("user_id", "uuid")],
+    scope: None,
+};
+
+pub struct EmailVerificationsRepositoryImpl {
+    pub executor: PgExecutor,
+    clock: ClockContract,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct EmailVerificationsModel {
+    pub id: EmailVerificationId,
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl EmailVerificationsRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { executor: PgExecutor::from(pool), clock: SystemClock::shared() }
+    }
+
+    pub fn with_executor(executor: PgExecutor) -> Self {
+        Self { executor, clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl Repository<EmailVerifications, EmailVerificationId> for EmailVerificationsRepositoryImpl {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<EmailVerifications>, RepositoryError> {
+        fetch_page::<EmailVerifications, EmailVerificationsModel>(&self.executor, &PAGE_QUERY, &request).await
+    }
+
+    async fn find_by_id(&self, id: EmailVerificationId) -> Result<EmailVerifications, RepositoryError> {
+        let model = sqlx::query_as::<_, EmailVerificationsModel>(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM email_verifications
+            WHERE id = $1
+            "#,
+        )
+            .bind(id)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(EmailVerifications::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Email verification not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn save(&self, entity: EmailVerifications) -> Result<EmailVerifications, RepositoryError> {
+        let model = sqlx::query_as::<_, EmailVerificationsModel>(
+            r#"
+            INSERT INTO email_verifications (id, user_id, token_hash, expires_at, used_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, token_hash, expires_at, used_at, created_at
+            "#,
+        )
+            .bind(entity.id)
+            .bind(entity.user_id)
+            .bind(entity.token_hash)
+            .bind(entity.expires_at)
+            .bind(entity.used_at)
+            .bind(entity.created_at)
+            .fetch_one_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => Ok(EmailVerifications::from(model)),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn update(&self, entity: EmailVerifications) -> Result<EmailVerifications, RepositoryError> {
+        let model = sqlx::query_as::<_, EmailVerificationsModel>(
+            r#"
+            UPDATE email_verifications
+            SET expires_at = $2, used_at = $3
+            WHERE id = $1
+            RETURNING id, user_id, token_hash, expires_at, used_at, created_at
+            "#,
+        )
+            .bind(entity.id)
+            .bind(entity.expires_at)
+            .bind(entity.used_at)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(EmailVerifications::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Email verification not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn delete(&self, id: EmailVerificationId) -> Option<RepositoryError> {
+        let result = sqlx::query("DELETE FROM email_verifications WHERE id = $1")
+            .bind(id)
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(RepositoryError::NotFound("Email verification not found".to_string())),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationsRepository for EmailVerificationsRepositoryImpl {
+    async fn find_by_token_hash(&self, token_hash: &str) -> Option<EmailVerifications> {
+        let model = sqlx::query_as::<_, EmailVerificationsModel>(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM email_verifications
+            WHERE token_hash = $1
+            "#,
+        )
+            .bind(token_hash)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => model.map(EmailVerifications::from),
+            Err(_) => None,
+        }
+    }
+
+    async fn consume_for_user(&self, user_id: UserId) -> Option<RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE email_verifications
+            SET used_at = $2
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+        )
+            .bind(user_id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
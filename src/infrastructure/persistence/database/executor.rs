@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+use sqlx::postgres::{PgArguments, PgQueryResult, PgRow};
+use sqlx::query::{Query, QueryAs, QueryScalar};
+use tokio::sync::Mutex;
+
+pub type SharedTransaction = Arc<Mutex<Option<Transaction<'static, Postgres>>>>;
+
+#[derive(Clone)]
+pub enum PgExecutor {
+    Pool(PgPool),
+    Transaction(SharedTransaction),
+}
+
+fn finished() -> sqlx::Error {
+    sqlx::Error::Protocol("the transaction has already been committed or rolled back".to_string())
+}
+
+#[async_trait]
+pub trait FetchWith<T> {
+    async fn fetch_one_with(self, executor: &PgExecutor) -> Result<T, sqlx::Error>;
+    async fn fetch_optional_with(self, executor: &PgExecutor) -> Result<Option<T>, sqlx::Error>;
+    async fn fetch_all_with(self, executor: &PgExecutor) -> Result<Vec<T>, sqlx::Error>;
+}
+
+#[async_trait]
+pub trait ExecuteWith {
+    async fn execute_with(self, executor: &PgExecutor) -> Result<PgQueryResult, sqlx::Error>;
+}
+
+impl From<PgPool> for PgExecutor {
+    fn from(pool: PgPool) -> Self {
+        PgExecutor::Pool(pool)
+    }
+}
+
+macro_rules! fetch_with {
+    ($query:expr, $executor:expr, $method:ident) => {
+        match $executor {
+            PgExecutor::Pool(pool) => $query.$method(pool).await,
+            PgExecutor::Transaction(transaction) => {
+                let mut transaction = transaction.lock().await;
+
+                match transaction.as_mut() {
+                    Some(transaction) => $query.$method(&mut **transaction).await,
+                    None => Err(finished()),
+                }
+            }
+        }
+    };
+}
+
+#[async_trait]
+impl<'q, M> FetchWith<M> for QueryAs<'q, Postgres, M, PgArguments>
+where
+    M: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+{
+    async fn fetch_one_with(self, executor: &PgExecutor) -> Result<M, sqlx::Error> {
+        fetch_with!(self, executor, fetch_one)
+    }
+
+    async fn fetch_optional_with(self, executor: &PgExecutor) -> Result<Option<M>, sqlx::Error> {
+        fetch_with!(self, executor, fetch_optional)
+    }
+
+    async fn fetch_all_with(self, executor: &PgExecutor) -> Result<Vec<M>, sqlx::Error> {
+        fetch_with!(self, executor, fetch_all)
+    }
+}
+
+#[async_trait]
+impl<'q, O> FetchWith<O> for QueryScalar<'q, Postgres, O, PgArguments>
+where
+    (O,): for<'r> FromRow<'r, PgRow>,
+    O: Send + Unpin,
+{
+    async fn fetch_one_with(self, executor: &PgExecutor) -> Result<O, sqlx::Error> {
+        fetch_with!(self, executor, fetch_one)
+    }
+
+    async fn fetch_optional_with(self, executor: &PgExecutor) -> Result<Option<O>, sqlx::Error> {
+        fetch_with!(self, executor, fetch_optional)
+    }
+
+    async fn fetch_all_with(self, executor: &PgExecutor) -> Result<Vec<O>, sqlx::Error> {
+        fetch_with!(self, executor, fetch_all)
+    }
+}
+
+#[async_trait]
+impl<'q> ExecuteWith for Query<'q, Postgres, PgArguments> {
+    async fn execute_with(self, executor: &PgExecutor) -> Result<PgQueryResult, sqlx::Error> {
+        fetch_with!(self, executor, execute)
+    }
+}
@@ -0,0 +1,63 @@
+use sqlx::migrate::{Migrate, MigrateError, Migrator};
+use sqlx::PgPool;
+
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+#[derive(Debug)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+}
+
+pub async fn run(pool: &PgPool) -> Result<(), MigrateError> {
+    MIGRATOR.run(pool).await
+}
+
+pub async fn revert(pool: &PgPool) -> Result<Option<i64>, MigrateError> {
+    let applied = applied_versions(pool).await?;
+
+    let last = match applied.last() {
+        Some(version) => *version,
+        None => return Ok(None),
+    };
+
+    let target = applied.iter().rev().nth(1).copied().unwrap_or(0);
+
+    MIGRATOR.undo(pool, target).await?;
+
+    Ok(Some(last))
+}
+
+pub async fn status(pool: &PgPool) -> Result<Vec<MigrationStatus>, MigrateError> {
+    let applied = applied_versions(pool).await?;
+
+    let statuses = MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .map(|migration| MigrationStatus {
+            version: migration.version,
+            description: migration.description.to_string(),
+            applied: applied.contains(&migration.version),
+        })
+        .collect();
+
+    Ok(statuses)
+}
+
+async fn applied_versions(pool: &PgPool) -> Result<Vec<i64>, MigrateError> {
+    let mut connection = pool.acquire().await?;
+
+    connection.ensure_migrations_table().await?;
+
+    let mut versions: Vec<i64> = connection
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect();
+
+    versions.sort();
+
+    Ok(versions)
+}
@@ -1,8 +1,74 @@
+pub mod connection;
+pub mod executor;
 pub mod users;
+pub mod videos;
+pub mod categories;
+pub mod refresh_tokens;
+pub mod email_verifications;
+pub mod password_resets;
+pub mod migrations;
+pub mod pagination;
+pub mod unit_of_work;
+
+pub(crate) mod __tests__;
 
 use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::application::repositories::RepositoryError;
+use crate::domain::errors::domain_error::DomainError;
+use crate::infrastructure::persistence::database::connection::DatabaseConfig;
+use crate::infrastructure::persistence::database::executor::{FetchWith, PgExecutor};
+
+const UNIQUE_VIOLATION: &str = "23505";
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+const DATA_EXCEPTION_CLASS: &str = "22";
 
 #[async_trait]
 pub trait Database {
-    async fn connect(url: &str, pool_size: u32) -> Result<Self, sqlx::Error> where Self: Sized;
-}
\ No newline at end of file
+    async fn connect(config: &DatabaseConfig) -> Result<Self, sqlx::Error> where Self: Sized;
+    async fn ping(&self) -> Result<(), sqlx::Error>;
+    fn pool(&self) -> &PgPool;
+}
+
+fn from_sqlstate(code: &str) -> Option<RepositoryError> {
+    match code {
+        UNIQUE_VIOLATION => Some(RepositoryError::AlreadyExists("The record already exists".to_string())),
+        FOREIGN_KEY_VIOLATION => Some(RepositoryError::Domain(DomainError::new("Invalid reference", "The record references a resource that does not exist"))),
+        code if code.starts_with(DATA_EXCEPTION_CLASS) => Some(RepositoryError::Domain(DomainError::new("Invalid value", "A value is malformed or out of range"))),
+        _ => None,
+    }
+}
+
+impl From<sqlx::Error> for RepositoryError {
+    fn from(error: sqlx::Error) -> Self {
+        if let sqlx::Error::Database(ref database_error) = error {
+            let code = database_error.code().unwrap_or_default().to_string();
+
+            if let Some(mapped) = from_sqlstate(&code) {
+                eprintln!("Database error {}: {}", code, database_error.message());
+                return mapped;
+            }
+        }
+
+        match error {
+            sqlx::Error::RowNotFound => RepositoryError::NotFound(error.to_string()),
+            error => RepositoryError::Infrastructure(error.to_string()),
+        }
+    }
+}
+
+pub async fn conflict_or_not_found(executor: &PgExecutor, table: &str, id: &Uuid, label: &str) -> RepositoryError {
+    let query = format!("SELECT EXISTS (SELECT 1 FROM {} WHERE id = $1 AND deleted_at IS NULL)", table);
+
+    let exists = sqlx::query_scalar::<_, bool>(&query)
+        .bind(id)
+        .fetch_one_with(executor)
+        .await;
+
+    match exists {
+        Ok(true) => RepositoryError::Conflict(format!("{} was modified by another request", label)),
+        Ok(false) => RepositoryError::NotFound(format!("{} not found", label)),
+        Err(err) => RepositoryError::from(err),
+    }
+}
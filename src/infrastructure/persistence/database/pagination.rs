@@ -0,0 +1,89 @@
+use sqlx::{FromRow, Postgres, QueryBuilder};
+use sqlx::postgres::PgRow;
+use crate::application::repositories::RepositoryError;
+use crate::application::repositories::pagination::{PageRequest, PageResult, Pageable, SortDirection};
+use crate::domain::errors::domain_error::DomainError;
+use crate::infrastructure::persistence::database::executor::{FetchWith, PgExecutor};
+
+pub struct PageQuery {
+    pub table: &'static str,
+    pub columns: &'static str,
+    pub types: &'static [(&'static str, &'static str)],
+    pub scope: Option<&'static str>,
+}
+
+impl PageQuery {
+    fn column_type(&self, field: &str) -> Result<&'static str, RepositoryError> {
+        match self.types.iter().find(|(column, _)| *column == field) {
+            Some((_, column_type)) => Ok(column_type),
+            None => Err(RepositoryError::Domain(DomainError::new("Invalid field", field))),
+        }
+    }
+
+    fn push_filters(&self, builder: &mut QueryBuilder<Postgres>, request: &PageRequest) -> Result<bool, RepositoryError> {
+        let mut has_conditions = false;
+
+        if let Some(scope) = self.scope {
+            builder.push(" WHERE ");
+            builder.push(scope);
+            has_conditions = true;
+        }
+
+        for filter in request.filters.iter() {
+            let column_type = self.column_type(&filter.field)?;
+
+            builder.push(if has_conditions { " AND " } else { " WHERE " });
+            has_conditions = true;
+            builder.push(format!("{} = ", filter.field));
+            builder.push_bind(filter.value.clone());
+            builder.push(format!("::{}", column_type));
+        }
+
+        Ok(has_conditions)
+    }
+}
+
+pub async fn fetch_page<T, M>(executor: &PgExecutor, query: &PageQuery, request: &PageRequest) -> Result<PageResult<T>, RepositoryError>
+where
+    T: Pageable + From<M>,
+    M: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+{
+    let cursor = request.validate::<T>()?;
+    let field = request.sort.field.as_str();
+    let sort_type = query.column_type(field)?;
+
+    let (direction, comparison) = match request.sort.direction {
+        SortDirection::Asc => ("ASC", ">"),
+        SortDirection::Desc => ("DESC", "<"),
+    };
+
+    let mut count = QueryBuilder::<Postgres>::new(format!("SELECT COUNT(*) FROM {}", query.table));
+    query.push_filters(&mut count, request)?;
+
+    let total: i64 = count.build_query_scalar().fetch_one_with(executor).await?;
+
+    let mut select = QueryBuilder::<Postgres>::new(format!("SELECT {} FROM {}", query.columns, query.table));
+    let has_conditions = query.push_filters(&mut select, request)?;
+
+    if let Some(ref cursor) = cursor {
+        select.push(if has_conditions { " AND " } else { " WHERE " });
+        select.push(format!("({}, id) {} (", field, comparison));
+        select.push_bind(cursor.value.clone());
+        select.push(format!("::{}, ", sort_type));
+        select.push_bind(cursor.id.clone());
+        select.push("::uuid)");
+    }
+
+    select.push(format!(" ORDER BY {} {}, id {}", field, direction, direction));
+    select.push(" LIMIT ");
+    select.push_bind(request.limit as i64 + 1);
+
+    if cursor.is_none() {
+        select.push(" OFFSET ");
+        select.push_bind(request.offset() as i64);
+    }
+
+    let models = select.build_query_as::<M>().fetch_all_with(executor).await?;
+
+    Ok(PageResult::from_window(models.into_iter().map(T::from).collect(), total as u64, request))
+}
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::password_resets::PasswordResetsRepository;
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::password_resets::PasswordResets;
+use crate::domain::value_objects::ids::{PasswordResetId, UserId};
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::database::executor::{FetchWith, ExecuteWith, PgExecutor};
+use crate::infrastructure::persistence::database::pagination::{fetch_page, PageQuery};
+
+const PAGE_QUERY: PageQuery = PageQuery {
+    table: "password_resets",
+    columns: "id, user_id, token_hash, expires_at, used_at, created_at",
+    types: &[("created_at", "timestamptz"), ("expires_at", "timestamptz"), ("user_id", "uuid")],
+    scope: None,
+};
+
+pub struct PasswordResetsRepositoryImpl {
+    pub executor: PgExecutor,
+    clock: ClockContract,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PasswordResetsModel {
+    pub id: PasswordResetId,
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl PasswordResetsRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { executor: PgExecutor::from(pool), clock: SystemClock::shared() }
+    }
+
+    pub fn with_executor(executor: PgExecutor) -> Self {
+        Self { executor, clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl Repository<PasswordResets, PasswordResetId> for PasswordResetsRepositoryImpl {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<PasswordResets>, RepositoryError> {
+        fetch_page::<PasswordResets, PasswordResetsModel>(&self.executor, &PAGE_QUERY, &request).await
+    }
+
+    async fn find_by_id(&self, id: PasswordResetId) -> Result<PasswordResets, RepositoryError> {
+        let model = sqlx::query_as::<_, PasswordResetsModel>(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM password_resets
+            WHERE id = $1
+            "#,
+        )
+            .bind(id)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(PasswordResets::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Password reset not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn save(&self, entity: PasswordResets) -> Result<PasswordResets, RepositoryError> {
+        let model = sqlx::query_as::<_, PasswordResetsModel>(
+            r#"
+            INSERT INTO password_resets (id, user_id, token_hash, expires_at, used_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, user_id, token_hash, expires_at, used_at, created_at
+            "#,
+        )
+            .bind(entity.id)
+            .bind(entity.user_id)
+            .bind(entity.token_hash)
+            .bind(entity.expires_at)
+            .bind(entity.used_at)
+            .bind(entity.created_at)
+            .fetch_one_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => Ok(PasswordResets::from(model)),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn update(&self, entity: PasswordResets) -> Result<PasswordResets, RepositoryError> {
+        let model = sqlx::query_as::<_, PasswordResetsModel>(
+            r#"
+            UPDATE password_resets
+            SET expires_at = $2, used_at = $3
+            WHERE id = $1
+            RETURNING id, user_id, token_hash, expires_at, used_at, created_at
+            "#,
+        )
+            .bind(entity.id)
+            .bind(entity.expires_at)
+            .bind(entity.used_at)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(PasswordResets::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Password reset not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn delete(&self, id: PasswordResetId) -> Option<RepositoryError> {
+        let result = sqlx::query("DELETE FROM password_resets WHERE id = $1")
+            .bind(id)
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(RepositoryError::NotFound("Password reset not found".to_string())),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
+
+#[async_trait]
+impl PasswordResetsRepository for PasswordResetsRepositoryImpl {
+    async fn find_by_token_hash(&self, token_hash: &str) -> Option<PasswordResets> {
+        let model = sqlx::query_as::<_, PasswordResetsModel>(
+            r#"
+            SELECT id, user_id, token_hash, expires_at, used_at, created_at
+            FROM password_resets
+            WHERE token_hash = $1
+            "#,
+        )
+            .bind(token_hash)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => model.map(PasswordResets::from),
+            Err(_) => None,
+        }
+    }
+
+    async fn consume_for_user(&self, user_id: UserId) -> Option<RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE password_resets
+            SET used_at = $2
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+        )
+            .bind(user_id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::application::repositories::refresh_tokens::RefreshTokensRepository;
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::refresh_tokens::RefreshTokens;
+use crate::domain::value_objects::ids::{RefreshTokenId, TokenFamilyId, UserId};
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::database::executor::{FetchWith, ExecuteWith, PgExecutor};
+use crate::infrastructure::persistence::database::pagination::{fetch_page, PageQuery};
+
+const PAGE_QUERY: PageQuery = PageQuery {
+    table: "refresh_tokens",
+    columns: "id, user_id, family_id, token_hash, expires_at, revoked_at, created_at",
+    types: &[("created_at", "timestamptz"), ("expires_at", "timestamptz"), ("user_id", "uuid"), ("family_id", "uuid")],
+    scope: None,
+};
+
+pub struct RefreshTokensRepositoryImpl {
+    pub executor: PgExecutor,
+    clock: ClockContract,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct RefreshTokensModel {
+    pub id: RefreshTokenId,
+    pub user_id: UserId,
+    pub family_id: TokenFamilyId,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub revoked_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl RefreshTokensRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { executor: PgExecutor::from(pool), clock: SystemClock::shared() }
+    }
+
+    pub fn with_executor(executor: PgExecutor) -> Self {
+        Self { executor, clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl Repository<RefreshTokens, RefreshTokenId> for RefreshTokensRepositoryImpl {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<RefreshTokens>, RepositoryError> {
+        fetch_page::<RefreshTokens, RefreshTokensModel>(&self.executor, &PAGE_QUERY, &request).await
+    }
+
+    async fn find_by_id(&self, id: RefreshTokenId) -> Result<RefreshTokens, RepositoryError> {
+        let model = sqlx::query_as::<_, RefreshTokensModel>(
+            r#"
+            SELECT id, user_id, family_id, token_hash, expires_at, revoked_at, created_at
+            FROM refresh_tokens
+            WHERE id = $1
+            "#,
+        )
+            .bind(id)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(RefreshTokens::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Refresh token not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn save(&self, entity: RefreshTokens) -> Result<RefreshTokens, RepositoryError> {
+        let model = sqlx::query_as::<_, RefreshTokensModel>(
+            r#"
+            INSERT INTO refresh_tokens (id, user_id, family_id, token_hash, expires_at, revoked_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, user_id, family_id, token_hash, expires_at, revoked_at, created_at
+            "#,
+        )
+            .bind(entity.id)
+            .bind(entity.user_id)
+            .bind(entity.family_id)
+            .bind(entity.token_hash)
+            .bind(entity.expires_at)
+            .bind(entity.revoked_at)
+            .bind(entity.created_at)
+            .fetch_one_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => Ok(RefreshTokens::from(model)),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn update(&self, entity: RefreshTokens) -> Result<RefreshTokens, RepositoryError> {
+        let model = sqlx::query_as::<_, RefreshTokensModel>(
+            r#"
+            UPDATE refresh_tokens
+            SET expires_at = $2, revoked_at = $3
+            WHERE id = $1
+            RETURNING id, user_id, family_id, token_hash, expires_at, revoked_at, created_at
+            "#,
+        )
+            .bind(entity.id)
+            .bind(entity.expires_at)
+            .bind(entity.revoked_at)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(RefreshTokens::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Refresh token not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn delete(&self, id: RefreshTokenId) -> Option<RepositoryError> {
+        let result = sqlx::query("DELETE FROM refresh_tokens WHERE id = $1")
+            .bind(id)
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(RepositoryError::NotFound("Refresh token not found".to_string())),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
+
+#[async_trait]
+impl RefreshTokensRepository for RefreshTokensRepositoryImpl {
+    async fn find_by_token_hash(&self, token_hash: &str) -> Option<RefreshTokens> {
+        let model = sqlx::query_as::<_, RefreshTokensModel>(
+            r#"
+            SELECT id, user_id, family_id, token_hash, expires_at, revoked_at, created_at
+            FROM refresh_tokens
+            WHERE token_hash = $1
+            "#,
+        )
+            .bind(token_hash)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => model.map(RefreshTokens::from),
+            Err(_) => None,
+        }
+    }
+
+    async fn revoke_family(&self, family_id: TokenFamilyId) -> Option<RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = $2
+            WHERE family_id = $1 AND revoked_at IS NULL
+            "#,
+        )
+            .bind(family_id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+
+    async fn revoke_for_user(&self, user_id: UserId) -> Option<RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE refresh_tokens
+            SET revoked_at = $2
+            WHERE user_id = $1 AND revoked_at IS NULL
+            "#,
+        )
+            .bind(user_id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
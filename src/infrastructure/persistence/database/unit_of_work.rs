@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tokio::sync::Mutex;
+use crate::application::repositories::RepositoryError;
+use crate::application::repositories::categories::CategoriesRepository;
+use crate::application::repositories::email_verifications::EmailVerificationsRepository;
+use crate::application::repositories::password_resets::PasswordResetsRepository;
+use crate::application::repositories::refresh_tokens::RefreshTokensRepository;
+use crate::application::repositories::unit_of_work::{Transaction, UnitOfWork};
+use crate::application::repositories::users::UsersRepository;
+use crate::application::repositories::videos::VideosRepository;
+use crate::application::services::clock::ClockContract;
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::database::categories::CategoriesRepositoryImpl;
+use crate::infrastructure::persistence::database::email_verifications::EmailVerificationsRepositoryImpl;
+use crate::infrastructure::persistence::database::executor::{PgExecutor, SharedTransaction};
+use crate::infrastructure::persistence::database::password_resets::PasswordResetsRepositoryImpl;
+use crate::infrastructure::persistence::database::refresh_tokens::RefreshTokensRepositoryImpl;
+use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
+use crate::infrastructure::persistence::database::videos::VideosRepositoryImpl;
+
+pub struct UnitOfWorkImpl {
+    pool: PgPool,
+    clock: ClockContract,
+}
+
+pub struct TransactionImpl {
+    transaction: SharedTransaction,
+    users: UsersRepositoryImpl,
+    videos: VideosRepositoryImpl,
+    categories: CategoriesRepositoryImpl,
+    refresh_tokens: RefreshTokensRepositoryImpl,
+    email_verifications: EmailVerificationsRepositoryImpl,
+    password_resets: PasswordResetsRepositoryImpl,
+}
+
+impl UnitOfWorkImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool, clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for UnitOfWorkImpl {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, RepositoryError> {
+        let transaction: SharedTransaction = Arc::new(Mutex::new(Some(self.pool.begin().await?)));
+        let executor = PgExecutor::Transaction(transaction.clone());
+
+        Ok(Box::new(TransactionImpl {
+            transaction,
+            users: UsersRepositoryImpl::with_executor(executor.clone()).with_clock(self.clock.clone()),
+            videos: VideosRepositoryImpl::with_executor(executor.clone()).with_clock(self.clock.clone()),
+            categories: CategoriesRepositoryImpl::with_executor(executor.clone()).with_clock(self.clock.clone()),
+            refresh_tokens: RefreshTokensRepositoryImpl::with_executor(executor.clone()).with_clock(self.clock.clone()),
+            email_verifications: EmailVerificationsRepositoryImpl::with_executor(executor.clone()).with_clock(self.clock.clone()),
+            password_resets: PasswordResetsRepositoryImpl::with_executor(executor).with_clock(self.clock.clone()),
+        }))
+    }
+}
+
+#[async_trait]
+impl Transaction for TransactionImpl {
+    fn users(&self) -> &dyn UsersRepository {
+        &self.users
+    }
+
+    fn videos(&self) -> &dyn VideosRepository {
+        &self.videos
+    }
+
+    fn categories(&self) -> &dyn CategoriesRepository {
+        &self.categories
+    }
+
+    fn refresh_tokens(&self) -> &dyn RefreshTokensRepository {
+        &self.refresh_tokens
+    }
+
+    fn email_verifications(&self) -> &dyn EmailVerificationsRepository {
+        &self.email_verifications
+    }
+
+    fn password_resets(&self) -> &dyn PasswordResetsRepository {
+        &self.password_resets
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        match self.transaction.lock().await.take() {
+            Some(transaction) => Ok(transaction.commit().await?),
+            None => Err(RepositoryError::Infrastructure("The transaction has already finished".to_string())),
+        }
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        match self.transaction.lock().await.take() {
+            Some(transaction) => Ok(transaction.rollback().await?),
+            None => Err(RepositoryError::Infrastructure("The transaction has already finished".to_string())),
+        }
+    }
+}
@@ -0,0 +1,388 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::application::repositories::videos::VideosRepository;
+use crate::application::services::clock::ClockContract;
+use crate::domain::entities::videos::Videos;
+use crate::domain::value_objects::ids::{CategoryId, UserId, VideoId};
+use crate::domain::value_objects::ValueObjectTrait;
+use crate::infrastructure::persistence::database::conflict_or_not_found;
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::database::executor::{FetchWith, ExecuteWith, PgExecutor};
+use crate::infrastructure::persistence::database::pagination::{fetch_page, PageQuery};
+
+const PAGE_QUERY: PageQuery = PageQuery {
+    table: "videos",
+    columns: "id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version",
+    types: &[("created_at", "timestamptz"), ("updated_at", "timestamptz"), ("title", "text"), ("category_id", "uuid"), ("user_id", "uuid")],
+    scope: Some("deleted_at IS NULL"),
+};
+
+pub struct VideosRepositoryImpl {
+    pub executor: PgExecutor,
+    clock: ClockContract,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct VideosModel {
+    pub id: VideoId,
+    pub title: String,
+    pub description: String,
+    pub url: String,
+    pub category_id: CategoryId,
+    pub user_id: UserId,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+    pub deleted_at: Option<OffsetDateTime>,
+    pub version: i32,
+}
+
+impl From<&Videos> for VideosModel {
+    fn from(video: &Videos) -> Self {
+        Self {
+            id: video.id,
+            title: video.title.to_string(),
+            description: video.description.to_string(),
+            url: video.url.to_string(),
+            category_id: video.category_id,
+            user_id: video.user_id,
+            created_at: video.created_at,
+            updated_at: video.updated_at,
+            deleted_at: video.deleted_at,
+            version: video.version,
+        }
+    }
+}
+
+impl VideosRepositoryImpl {
+    pub fn new(pool: PgPool) -> Self {
+        Self { executor: PgExecutor::from(pool), clock: SystemClock::shared() }
+    }
+
+    pub fn with_executor(executor: PgExecutor) -> Self {
+        Self { executor, clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+#[async_trait]
+impl Repository<Videos, VideoId> for VideosRepositoryImpl {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<Videos>, RepositoryError> {
+        fetch_page::<Videos, VideosModel>(&self.executor, &PAGE_QUERY, &request).await
+    }
+
+    async fn find_by_id(&self, id: VideoId) -> Result<Videos, RepositoryError> {
+        let model = sqlx::query_as::<_, VideosModel>(
+            r#"
+            SELECT id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version
+            FROM videos
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+            .bind(id)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Videos::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Video not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn save(&self, entity: Videos) -> Result<Videos, RepositoryError> {
+        let model = VideosModel::from(&entity);
+
+        let model = sqlx::query_as::<_, VideosModel>(
+            r#"
+            INSERT INTO videos (id, title, description, url, category_id, user_id, created_at, updated_at, version)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version
+            "#,
+        )
+            .bind(model.id)
+            .bind(model.title)
+            .bind(model.description)
+            .bind(model.url)
+            .bind(model.category_id)
+            .bind(model.user_id)
+            .bind(model.created_at)
+            .bind(model.updated_at)
+            .bind(model.version)
+            .fetch_one_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(model) => Ok(Videos::from(model)),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn update(&self, entity: Videos) -> Result<Videos, RepositoryError> {
+        let model = VideosModel::from(&entity);
+        let id = model.id;
+
+        let model = sqlx::query_as::<_, VideosModel>(
+            r#"
+            UPDATE videos
+            SET title = $2, description = $3, url = $4, category_id = $5, updated_at = $6, version = version + 1
+            WHERE id = $1 AND version = $7 AND deleted_at IS NULL
+            RETURNING id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version
+            "#,
+        )
+            .bind(model.id)
+            .bind(model.title)
+            .bind(model.description)
+            .bind(model.url)
+            .bind(model.category_id)
+            .bind(model.updated_at)
+            .bind(model.version)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Videos::from(model)),
+            Ok(None) => Err(conflict_or_not_found(&self.executor, "videos", id.value(), "Video").await),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn delete(&self, id: VideoId) -> Option<RepositoryError> {
+        let result = sqlx::query("UPDATE videos SET deleted_at = $2, updated_at = $2, version = version + 1 WHERE id = $1 AND deleted_at IS NULL")
+            .bind(id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(RepositoryError::NotFound("Video not found".to_string())),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+}
+
+#[async_trait]
+impl SoftDeleteRepository<Videos, VideoId> for VideosRepositoryImpl {
+    async fn find_deleted_by_id(&self, id: VideoId) -> Result<Videos, RepositoryError> {
+        let model = sqlx::query_as::<_, VideosModel>(
+            r#"
+            SELECT id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version
+            FROM videos
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            "#,
+        )
+            .bind(id)
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Videos::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Video not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn delete_at_version(&self, id: VideoId, version: i32) -> Option<RepositoryError> {
+        let result = sqlx::query("UPDATE videos SET deleted_at = $2, updated_at = $2, version = version + 1 WHERE id = $1 AND version = $3 AND deleted_at IS NULL")
+            .bind(id)
+            .bind(self.clock.now())
+            .bind(version)
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(conflict_or_not_found(&self.executor, "videos", id.value(), "Video").await),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+
+    async fn restore(&self, id: VideoId) -> Result<Videos, RepositoryError> {
+        let model = sqlx::query_as::<_, VideosModel>(
+            r#"
+            UPDATE videos
+            SET deleted_at = NULL, updated_at = $2, version = version + 1
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            RETURNING id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version
+            "#,
+        )
+            .bind(id)
+            .bind(self.clock.now())
+            .fetch_optional_with(&self.executor)
+            .await;
+
+        match model {
+            Ok(Some(model)) => Ok(Videos::from(model)),
+            Ok(None) => Err(RepositoryError::NotFound("Video not found".to_string())),
+            Err(err) => Err(RepositoryError::from(err)),
+        }
+    }
+
+    async fn purge(&self, id: VideoId) -> Option<RepositoryError> {
+        let result = sqlx::query("DELETE FROM videos WHERE id = $1 AND deleted_at IS NOT NULL")
+            .bind(id)
+            .execute_with(&self.executor)
+            .await;
+
+        match result {
+            Ok(result) if result.rows_affected() == 0 => Some(RepositoryError::NotFound("Video not found".to_string())),
+            Ok(_) => None,
+            Err(err) => Some(RepositoryError::from(err)),
+        }
+    }
+
+    async fn purge_deleted_before(&self, cutoff: OffsetDateTime) -> Result<u64, RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM videos
+            WHERE deleted_at < $1
+            "#,
+        )
+            .bind(cutoff)
+            .execute_with(&self.executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[async_trait]
+impl VideosRepository for VideosRepositoryImpl {
+    async fn find_by_category_id(&self, category_id: CategoryId) -> Result<Vec<Videos>, RepositoryError> {
+        let models = sqlx::query_as::<_, VideosModel>(
+            r#"
+            SELECT id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version
+            FROM videos
+            WHERE category_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at
+            "#,
+        )
+            .bind(category_id)
+            .fetch_all_with(&self.executor)
+            .await?;
+
+        Ok(models.into_iter().map(Videos::from).collect())
+    }
+
+    async fn search(&self, query: &str, request: PageRequest) -> Result<PageResult<Videos>, RepositoryError> {
+        request.require_offset()?;
+
+        let prefix_query = prefix_query(query);
+        let pattern = format!("%{}%", escape_like(query.trim()));
+
+        let total: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM videos
+            WHERE deleted_at IS NULL
+              AND (search_vector @@ to_tsquery('simple', immutable_unaccent($1))
+                   OR search_document LIKE lower(immutable_unaccent($2)))
+            "#,
+        )
+            .bind(&prefix_query)
+            .bind(&pattern)
+            .fetch_one_with(&self.executor)
+            .await?;
+
+        let models = sqlx::query_as::<_, VideosModel>(
+            r#"
+            SELECT id, title, description, url, category_id, user_id, created_at, updated_at, deleted_at, version
+            FROM videos
+            WHERE deleted_at IS NULL
+              AND (search_vector @@ to_tsquery('simple', immutable_unaccent($1))
+                   OR search_document LIKE lower(immutable_unaccent($2)))
+            ORDER BY ts_rank(search_vector, to_tsquery('simple', immutable_unaccent($1))) DESC,
+                     similarity(search_document, lower(immutable_unaccent($3))) DESC,
+                     created_at,
+                     id
+            LIMIT $4 OFFSET $5
+            "#,
+        )
+            .bind(&prefix_query)
+            .bind(&pattern)
+            .bind(query.trim())
+            .bind(request.limit as i64)
+            .bind(request.offset() as i64)
+            .fetch_all_with(&self.executor)
+            .await?;
+
+        Ok(PageResult::from_page(models.into_iter().map(Videos::from).collect(), total as u64, &request))
+    }
+
+    async fn purge_deleted_by_category_id(&self, category_id: CategoryId) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM videos WHERE category_id = $1 AND deleted_at IS NOT NULL")
+            .bind(category_id)
+            .execute_with(&self.executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn delete_by_user_id(&self, user_id: UserId) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("UPDATE videos SET deleted_at = $2, updated_at = $2, version = version + 1 WHERE user_id = $1 AND deleted_at IS NULL")
+            .bind(user_id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn restore_by_user_id(&self, user_id: UserId, deleted_since: OffsetDateTime) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("UPDATE videos SET deleted_at = NULL, updated_at = $3, version = version + 1 WHERE user_id = $1 AND deleted_at >= $2")
+            .bind(user_id)
+            .bind(deleted_since)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn purge_by_user_id(&self, user_id: UserId) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM videos WHERE user_id = $1")
+            .bind(user_id)
+            .execute_with(&self.executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn reassign_foreign_videos(&self, category_id: CategoryId, owner_id: UserId, target_id: CategoryId) -> Result<u64, RepositoryError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE videos
+            SET category_id = $3, updated_at = $4, version = version + 1
+            WHERE category_id = $1 AND user_id <> $2
+            "#,
+        )
+            .bind(category_id)
+            .bind(owner_id)
+            .bind(target_id)
+            .bind(self.clock.now())
+            .execute_with(&self.executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+fn prefix_query(query: &str) -> String {
+    query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("{}:*", term))
+        .collect::<Vec<String>>()
+        .join(" & ")
+}
+
+fn escape_like(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
@@ -0,0 +1 @@
+mod unit_of_work;
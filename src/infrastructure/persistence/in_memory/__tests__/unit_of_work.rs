@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod test_unit_of_work_in_memory {
+    use std::sync::Arc;
+    use time::OffsetDateTime;
+    use time::macros::datetime;
+    use crate::application::repositories::{Repository, RepositoryError};
+    use crate::application::repositories::unit_of_work::UnitOfWork;
+    use crate::domain::entities::categories::Categories;
+    use crate::domain::entities::videos::{Videos, VideosInput};
+    use crate::infrastructure::clock::FixedClock;
+    use crate::infrastructure::persistence::in_memory::unit_of_work::UnitOfWorkInMemory;
+    use crate::infrastructure::persistence::in_memory::videos::VideosRepositoryInMemory;
+
+    const USER_ID: &str = "018b33b7-c8dd-76a2-98b5-d621862882a8";
+    const NOW: OffsetDateTime = datetime!(2023-10-20 12:00 UTC);
+
+    fn video(title: &str) -> Videos {
+        Videos::new(&VideosInput {
+            title: title.to_string(),
+            description: "Description".to_string(),
+            url: "https://www.youtube.com/watch?v=6n3pFFPSlW4".to_string(),
+            category_id: Some(Categories::default_id().to_string()),
+            user_id: USER_ID.to_string(),
+        }, NOW).unwrap()
+    }
+
+    fn setup_sut() -> UnitOfWorkInMemory {
+        let clock = Arc::new(FixedClock::new(NOW));
+
+        UnitOfWorkInMemory::new().with_videos(Arc::new(VideosRepositoryInMemory::new().with_clock(clock)))
+    }
+
+    #[tokio::test]
+    async fn it_should_apply_the_staged_writes_on_commit() {
+        let sut = setup_sut();
+        let transaction = sut.begin().await.unwrap();
+
+        let staged = transaction.videos().save(video("Staged")).await.unwrap();
+
+        assert!(sut.videos.find_by_id(staged.id).await.is_err());
+
+        transaction.commit().await.unwrap();
+
+        assert!(sut.videos.find_by_id(staged.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_writes_made_outside_a_rolled_back_transaction() {
+        let sut = setup_sut();
+        let transaction = sut.begin().await.unwrap();
+
+        let staged = transaction.videos().save(video("Staged")).await.unwrap();
+        let outside = sut.videos.save(video("Outside")).await.unwrap();
+
+        transaction.rollback().await.unwrap();
+
+        assert!(sut.videos.find_by_id(staged.id).await.is_err());
+        assert!(sut.videos.find_by_id(outside.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_should_discard_the_staged_writes_of_a_dropped_transaction() {
+        let sut = setup_sut();
+        let transaction = sut.begin().await.unwrap();
+
+        let staged = transaction.videos().save(video("Staged")).await.unwrap();
+        let outside = sut.videos.save(video("Outside")).await.unwrap();
+
+        drop(transaction);
+
+        assert!(sut.videos.find_by_id(staged.id).await.is_err());
+        assert!(sut.videos.find_by_id(outside.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_writes_made_outside_a_committed_transaction() {
+        let sut = setup_sut();
+        let existing = sut.videos.save(video("Existing")).await.unwrap();
+        let transaction = sut.begin().await.unwrap();
+
+        assert!(transaction.videos().delete(existing.id).await.is_none());
+        let outside = sut.videos.save(video("Outside")).await.unwrap();
+
+        transaction.commit().await.unwrap();
+
+        assert!(sut.videos.find_by_id(existing.id).await.is_err());
+        assert!(sut.videos.find_by_id(outside.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_should_not_commit_over_a_row_changed_outside_the_transaction() {
+        let sut = setup_sut();
+        let existing = sut.videos.save(video("Existing")).await.unwrap();
+        let transaction = sut.begin().await.unwrap();
+
+        let mut staged = existing.clone();
+        staged.title = "Staged".to_string();
+        transaction.videos().update(staged).await.unwrap();
+
+        let mut outside = existing.clone();
+        outside.title = "Outside".to_string();
+        sut.videos.update(outside).await.unwrap();
+
+        let result = transaction.commit().await;
+
+        assert!(matches!(result.unwrap_err(), RepositoryError::Conflict(_)));
+        assert_eq!(sut.videos.find_by_id(existing.id).await.unwrap().title, "Outside");
+    }
+}
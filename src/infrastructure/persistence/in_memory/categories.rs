@@ -1,55 +1,94 @@
 use async_trait::async_trait;
+use time::OffsetDateTime;
+use tokio::sync::RwLock;
+use crate::application::services::clock::ClockContract;
 use crate::application::repositories::categories::CategoriesRepository;
-use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{PageRequest, PageResult};
 use crate::domain::entities::categories::Categories;
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::ids::{CategoryId, UserId};
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::in_memory::pagination::paginate;
 
-#[derive(Clone)]
 pub struct CategoriesRepositoryInMemory {
-    pub categories: Vec<Categories>,
+    pub categories: RwLock<Vec<Categories>>,
+    clock: ClockContract,
 }
 
 impl CategoriesRepositoryInMemory {
     pub fn new() -> Self {
-        Self { categories: vec![] }
+        Self { categories: RwLock::new(vec![]), clock: SystemClock::shared() }
     }
 
-    fn len(&self) -> usize {
-        self.categories.len()
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn stage(&self) -> Self {
+        Self { categories: RwLock::new(self.categories.read().await.clone()), clock: self.clock.clone() }
+    }
+}
+
+impl Default for CategoriesRepositoryInMemory {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[async_trait]
-impl Repository<Categories> for CategoriesRepositoryInMemory {
-    async fn find_all(&self) -> Vec<Categories> {
-        if self.len() > 10 {
-            panic!("Too many categories in memory, please clear the memory before running the tests");
-        }
+impl Repository<Categories, CategoryId> for CategoriesRepositoryInMemory {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<Categories>, RepositoryError> {
+        let categories = self.categories.read().await;
 
-        self.categories.clone()
+        paginate(categories.iter().filter(|v| !v.is_deleted()), &request)
     }
 
-    async fn find_by_id(&self, id: UniqueEntityID) -> Result<Categories, RepositoryError> {
-        match self.categories.iter().find(|v| v.id == id) {
+    async fn find_by_id(&self, id: CategoryId) -> Result<Categories, RepositoryError> {
+        let categories = self.categories.read().await;
+
+        match categories.iter().find(|v| v.id == id && !v.is_deleted()) {
             Some(category) => Ok(category.clone()),
             None => Err(RepositoryError::NotFound("Category not found".to_string())),
         }
     }
 
-    async fn save(&mut self, entity: Categories) -> Result<Categories, RepositoryError> {
-        match self.categories.iter().find(|v| v.id == entity.id) {
+    async fn save(&self, entity: Categories) -> Result<Categories, RepositoryError> {
+        let mut categories = self.categories.write().await;
+
+        match categories.iter().find(|v| v.id == entity.id) {
             Some(_) => Err(RepositoryError::AlreadyExists("Category already exists".to_string())),
             None => {
-                self.categories.push(entity.clone());
+                categories.push(entity.clone());
                 Ok(entity)
             }
         }
     }
 
-    async fn delete(&mut self, id: UniqueEntityID) -> Option<RepositoryError> {
-        match self.categories.iter().position(|v| v.id == id) {
+    async fn update(&self, entity: Categories) -> Result<Categories, RepositoryError> {
+        let mut categories = self.categories.write().await;
+
+        match categories.iter().position(|v| v.id == entity.id && !v.is_deleted()) {
+            Some(index) if categories[index].version != entity.version => {
+                Err(RepositoryError::Conflict("Category was modified by another request".to_string()))
+            }
             Some(index) => {
-                self.categories.remove(index);
+                let mut entity = entity;
+                entity.version += 1;
+                categories[index] = entity.clone();
+                Ok(entity)
+            }
+            None => Err(RepositoryError::NotFound("Category not found".to_string())),
+        }
+    }
+
+    async fn delete(&self, id: CategoryId) -> Option<RepositoryError> {
+        let mut categories = self.categories.write().await;
+
+        match categories.iter_mut().find(|v| v.id == id && !v.is_deleted()) {
+            Some(category) => {
+                category.soft_delete(self.clock.now());
+                category.version += 1;
                 None
             }
             None => Some(RepositoryError::NotFound("Category not found".to_string())),
@@ -57,13 +96,83 @@ impl Repository<Categories> for CategoriesRepositoryInMemory {
     }
 }
 
+#[async_trait]
+impl SoftDeleteRepository<Categories, CategoryId> for CategoriesRepositoryInMemory {
+    async fn find_deleted_by_id(&self, id: CategoryId) -> Result<Categories, RepositoryError> {
+        let categories = self.categories.read().await;
+
+        match categories.iter().find(|v| v.id == id && v.is_deleted()) {
+            Some(category) => Ok(category.clone()),
+            None => Err(RepositoryError::NotFound("Category not found".to_string())),
+        }
+    }
+
+    async fn delete_at_version(&self, id: CategoryId, version: i32) -> Option<RepositoryError> {
+        let mut categories = self.categories.write().await;
+
+        match categories.iter_mut().find(|v| v.id == id && !v.is_deleted()) {
+            Some(category) if category.version != version => Some(RepositoryError::Conflict("Category was modified by another request".to_string())),
+            Some(category) => {
+                category.soft_delete(self.clock.now());
+                category.version += 1;
+                None
+            }
+            None => Some(RepositoryError::NotFound("Category not found".to_string())),
+        }
+    }
+
+    async fn restore(&self, id: CategoryId) -> Result<Categories, RepositoryError> {
+        let mut categories = self.categories.write().await;
+
+        match categories.iter_mut().find(|v| v.id == id && v.is_deleted()) {
+            Some(category) => {
+                category.restore(self.clock.now());
+                category.version += 1;
+                Ok(category.clone())
+            }
+            None => Err(RepositoryError::NotFound("Category not found".to_string())),
+        }
+    }
+
+    async fn purge(&self, id: CategoryId) -> Option<RepositoryError> {
+        let mut categories = self.categories.write().await;
+
+        match categories.iter().position(|v| v.id == id && v.is_deleted()) {
+            Some(index) => {
+                categories.remove(index);
+                None
+            }
+            None => Some(RepositoryError::NotFound("Category not found".to_string())),
+        }
+    }
+
+    async fn purge_deleted_before(&self, cutoff: OffsetDateTime) -> Result<u64, RepositoryError> {
+        let mut categories = self.categories.write().await;
+        let total = categories.len();
+
+        categories.retain(|v| v.deleted_at.is_none_or(|deleted_at| deleted_at >= cutoff));
+
+        Ok((total - categories.len()) as u64)
+    }
+}
+
 #[async_trait]
 impl CategoriesRepository for CategoriesRepositoryInMemory {
-    async fn find_by_category_id(&self, category_id: UniqueEntityID) -> Vec<Categories> {
-        todo!()
+    async fn find_by_category_id(&self, category_id: CategoryId) -> Result<Vec<Categories>, RepositoryError> {
+        let categories = self.categories.read().await;
+
+        Ok(categories.iter().filter(|v| v.id == category_id && !v.is_deleted()).cloned().collect())
+    }
+
+    async fn find_by_user_id(&self, user_id: UserId) -> Result<Vec<Categories>, RepositoryError> {
+        let categories = self.categories.read().await;
+
+        Ok(categories.iter().filter(|v| v.user_id == user_id && !v.is_deleted()).cloned().collect())
     }
 
-    async fn find_by_user_id(&self, user_id: UniqueEntityID) -> Vec<Categories> {
-        todo!()
+    async fn find_deleted_by_user_id(&self, user_id: UserId) -> Result<Vec<Categories>, RepositoryError> {
+        let categories = self.categories.read().await;
+
+        Ok(categories.iter().filter(|v| v.user_id == user_id && v.is_deleted()).cloned().collect())
     }
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use crate::application::services::clock::ClockContract;
+use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::email_verifications::EmailVerificationsRepository;
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::domain::entities::email_verifications::EmailVerifications;
+use crate::domain::value_objects::ids::{EmailVerificationId, UserId};
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::in_memory::pagination::paginate;
+
+pub struct EmailVerificationsRepositoryInMemory {
+    pub email_verifications: RwLock<Vec<EmailVerifications>>,
+    clock: ClockContract,
+}
+
+impl EmailVerificationsRepositoryInMemory {
+    pub fn new() -> Self {
+        Self { email_verifications: RwLock::new(vec![]), clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn stage(&self) -> Self {
+        Self { email_verifications: RwLock::new(self.email_verifications.read().await.clone()), clock: self.clock.clone() }
+    }
+}
+
+impl Default for EmailVerificationsRepositoryInMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Repository<EmailVerifications, EmailVerificationId> for EmailVerificationsRepositoryInMemory {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<EmailVerifications>, RepositoryError> {
+        let email_verifications = self.email_verifications.read().await;
+
+        paginate(email_verifications.iter(), &request)
+    }
+
+    async fn find_by_id(&self, id: EmailVerificationId) -> Result<EmailVerifications, RepositoryError> {
+        let email_verifications = self.email_verifications.read().await;
+
+        match email_verifications.iter().find(|v| v.id == id) {
+            Some(verification) => Ok(verification.clone()),
+            None => Err(RepositoryError::NotFound("Email verification not found".to_string())),
+        }
+    }
+
+    async fn save(&self, entity: EmailVerifications) -> Result<EmailVerifications, RepositoryError> {
+        let mut email_verifications = self.email_verifications.write().await;
+
+        match email_verifications.iter().find(|v| v.id == entity.id || v.token_hash == entity.token_hash) {
+            Some(_) => Err(RepositoryError::AlreadyExists("Email verification already exists".to_string())),
+            None => {
+                email_verifications.push(entity.clone());
+                Ok(entity)
+            }
+        }
+    }
+
+    async fn update(&self, entity: EmailVerifications) -> Result<EmailVerifications, RepositoryError> {
+        let mut email_verifications = self.email_verifications.write().await;
+
+        match email_verifications.iter().position(|v| v.id == entity.id) {
+            Some(index) => {
+                email_verifications[index] = entity.clone();
+                Ok(entity)
+            }
+            None => Err(RepositoryError::NotFound("Email verification not found".to_string())),
+        }
+    }
+
+    async fn delete(&self, id: EmailVerificationId) -> Option<RepositoryError> {
+        let mut email_verifications = self.email_verifications.write().await;
+
+        match email_verifications.iter().position(|v| v.id == id) {
+            Some(index) => {
+                email_verifications.remove(index);
+                None
+            }
+            None => Some(RepositoryError::NotFound("Email verification not found".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationsRepository for EmailVerificationsRepositoryInMemory {
+    async fn find_by_token_hash(&self, token_hash: &str) -> Option<EmailVerifications> {
+        let email_verifications = self.email_verifications.read().await;
+
+        email_verifications.iter().find(|v| v.token_hash == token_hash).cloned()
+    }
+
+    async fn consume_for_user(&self, user_id: UserId) -> Option<RepositoryError> {
+        let mut email_verifications = self.email_verifications.write().await;
+        let now = self.clock.now();
+
+        email_verifications
+            .iter_mut()
+            .filter(|v| v.user_id == user_id)
+            .for_each(|v| v.consume(now));
+
+        None
+    }
+}
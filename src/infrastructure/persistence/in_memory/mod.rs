@@ -1,3 +1,11 @@
 pub mod videos;
 pub mod categories;
-pub mod users;
\ No newline at end of file
+pub mod users;
+pub mod refresh_tokens;
+pub mod email_verifications;
+pub mod password_resets;
+pub mod pagination;
+pub mod search;
+pub mod unit_of_work;
+
+mod __tests__;
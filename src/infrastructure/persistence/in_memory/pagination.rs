@@ -0,0 +1,56 @@
+use std::cmp::Ordering;
+use crate::application::repositories::RepositoryError;
+use crate::application::repositories::pagination::{PageRequest, PageResult, Pageable};
+use crate::domain::errors::domain_error::DomainError;
+
+fn compare<T: Pageable>(a: &T, b: &T, field: &str) -> Ordering {
+    a.sort_value(field)
+        .cmp(&b.sort_value(field))
+        .then_with(|| a.cursor_id().cmp(&b.cursor_id()))
+}
+
+pub fn paginate<'a, T: Pageable + Clone + 'a>(items: impl IntoIterator<Item = &'a T>, request: &PageRequest) -> Result<PageResult<T>, RepositoryError> {
+    let cursor = request.validate::<T>()?;
+    let field = request.sort.field.as_str();
+    let direction = request.sort.direction;
+
+    let mut matching: Vec<&T> = items
+        .into_iter()
+        .filter(|item| {
+            request.filters.iter().all(|filter| item.filter_value(&filter.field).as_deref() == Some(filter.value.as_str()))
+        })
+        .collect();
+
+    matching.sort_by(|a, b| direction.apply(compare(*a, *b, field)));
+
+    let total = matching.len() as u64;
+
+    let remaining: Vec<&T> = match cursor {
+        Some(cursor) => {
+            let bound = match matching.first().and_then(|item| item.sort_value(field)) {
+                Some(sample) => match sample.parse_like(&cursor.value) {
+                    Some(bound) => Some(bound),
+                    None => return Err(RepositoryError::Domain(DomainError::new("Invalid cursor", "The cursor is malformed or has expired"))),
+                },
+                None => None,
+            };
+
+            matching
+                .into_iter()
+                .filter(|item| {
+                    let key = (item.sort_value(field), item.cursor_id());
+                    direction.apply(key.cmp(&(bound.clone(), cursor.id.clone()))) == Ordering::Greater
+                })
+                .collect()
+        }
+        None => matching.into_iter().skip(request.offset() as usize).collect(),
+    };
+
+    let window = remaining
+        .into_iter()
+        .take(request.limit as usize + 1)
+        .cloned()
+        .collect();
+
+    Ok(PageResult::from_window(window, total, request))
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use crate::application::services::clock::ClockContract;
+use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::password_resets::PasswordResetsRepository;
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::domain::entities::password_resets::PasswordResets;
+use crate::domain::value_objects::ids::{PasswordResetId, UserId};
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::in_memory::pagination::paginate;
+
+pub struct PasswordResetsRepositoryInMemory {
+    pub password_resets: RwLock<Vec<PasswordResets>>,
+    clock: ClockContract,
+}
+
+impl PasswordResetsRepositoryInMemory {
+    pub fn new() -> Self {
+        Self { password_resets: RwLock::new(vec![]), clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn stage(&self) -> Self {
+        Self { password_resets: RwLock::new(self.password_resets.read().await.clone()), clock: self.clock.clone() }
+    }
+}
+
+impl Default for PasswordResetsRepositoryInMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Repository<PasswordResets, PasswordResetId> for PasswordResetsRepositoryInMemory {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<PasswordResets>, RepositoryError> {
+        let password_resets = self.password_resets.read().await;
+
+        paginate(password_resets.iter(), &request)
+    }
+
+    async fn find_by_id(&self, id: PasswordResetId) -> Result<PasswordResets, RepositoryError> {
+        let password_resets = self.password_resets.read().await;
+
+        match password_resets.iter().find(|v| v.id == id) {
+            Some(reset) => Ok(reset.clone()),
+            None => Err(RepositoryError::NotFound("Password reset not found".to_string())),
+        }
+    }
+
+    async fn save(&self, entity: PasswordResets) -> Result<PasswordResets, RepositoryError> {
+        let mut password_resets = self.password_resets.write().await;
+
+        match password_resets.iter().find(|v| v.id == entity.id || v.token_hash == entity.token_hash) {
+            Some(_) => Err(RepositoryError::AlreadyExists("Password reset already exists".to_string())),
+            None => {
+                password_resets.push(entity.clone());
+                Ok(entity)
+            }
+        }
+    }
+
+    async fn update(&self, entity: PasswordResets) -> Result<PasswordResets, RepositoryError> {
+        let mut password_resets = self.password_resets.write().await;
+
+        match password_resets.iter().position(|v| v.id == entity.id) {
+            Some(index) => {
+                password_resets[index] = entity.clone();
+                Ok(entity)
+            }
+            None => Err(RepositoryError::NotFound("Password reset not found".to_string())),
+        }
+    }
+
+    async fn delete(&self, id: PasswordResetId) -> Option<RepositoryError> {
+        let mut password_resets = self.password_resets.write().await;
+
+        match password_resets.iter().position(|v| v.id == id) {
+            Some(index) => {
+                password_resets.remove(index);
+                None
+            }
+            None => Some(RepositoryError::NotFound("Password reset not found".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl PasswordResetsRepository for PasswordResetsRepositoryInMemory {
+    async fn find_by_token_hash(&self, token_hash: &str) -> Option<PasswordResets> {
+        let password_resets = self.password_resets.read().await;
+
+        password_resets.iter().find(|v| v.token_hash == token_hash).cloned()
+    }
+
+    async fn consume_for_user(&self, user_id: UserId) -> Option<RepositoryError> {
+        let mut password_resets = self.password_resets.write().await;
+        let now = self.clock.now();
+
+        password_resets
+            .iter_mut()
+            .filter(|v| v.user_id == user_id)
+            .for_each(|v| v.consume(now));
+
+        None
+    }
+}
@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use crate::application::services::clock::ClockContract;
+use crate::application::repositories::{Repository, RepositoryError};
+use crate::application::repositories::pagination::{PageRequest, PageResult};
+use crate::application::repositories::refresh_tokens::RefreshTokensRepository;
+use crate::domain::entities::refresh_tokens::RefreshTokens;
+use crate::domain::value_objects::ids::{RefreshTokenId, TokenFamilyId, UserId};
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::in_memory::pagination::paginate;
+
+pub struct RefreshTokensRepositoryInMemory {
+    pub refresh_tokens: RwLock<Vec<RefreshTokens>>,
+    clock: ClockContract,
+}
+
+impl RefreshTokensRepositoryInMemory {
+    pub fn new() -> Self {
+        Self { refresh_tokens: RwLock::new(vec![]), clock: SystemClock::shared() }
+    }
+
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn stage(&self) -> Self {
+        Self { refresh_tokens: RwLock::new(self.refresh_tokens.read().await.clone()), clock: self.clock.clone() }
+    }
+}
+
+impl Default for RefreshTokensRepositoryInMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Repository<RefreshTokens, RefreshTokenId> for RefreshTokensRepositoryInMemory {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<RefreshTokens>, RepositoryError> {
+        let refresh_tokens = self.refresh_tokens.read().await;
+
+        paginate(refresh_tokens.iter(), &request)
+    }
+
+    async fn find_by_id(&self, id: RefreshTokenId) -> Result<RefreshTokens, RepositoryError> {
+        let refresh_tokens = self.refresh_tokens.read().await;
+
+        match refresh_tokens.iter().find(|v| v.id == id) {
+            Some(refresh_token) => Ok(refresh_token.clone()),
+            None => Err(RepositoryError::NotFound("Refresh token not found".to_string())),
+        }
+    }
+
+    async fn save(&self, entity: RefreshTokens) -> Result<RefreshTokens, RepositoryError> {
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+
+        match refresh_tokens.iter().find(|v| v.id == entity.id || v.token_hash == entity.token_hash) {
+            Some(_) => Err(RepositoryError::AlreadyExists("Refresh token already exists".to_string())),
+            None => {
+                refresh_tokens.push(entity.clone());
+                Ok(entity)
+            }
+        }
+    }
+
+    async fn update(&self, entity: RefreshTokens) -> Result<RefreshTokens, RepositoryError> {
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+
+        match refresh_tokens.iter().position(|v| v.id == entity.id) {
+            Some(index) => {
+                refresh_tokens[index] = entity.clone();
+                Ok(entity)
+            }
+            None => Err(RepositoryError::NotFound("Refresh token not found".to_string())),
+        }
+    }
+
+    async fn delete(&self, id: RefreshTokenId) -> Option<RepositoryError> {
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+
+        match refresh_tokens.iter().position(|v| v.id == id) {
+            Some(index) => {
+                refresh_tokens.remove(index);
+                None
+            }
+            None => Some(RepositoryError::NotFound("Refresh token not found".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl RefreshTokensRepository for RefreshTokensRepositoryInMemory {
+    async fn find_by_token_hash(&self, token_hash: &str) -> Option<RefreshTokens> {
+        let refresh_tokens = self.refresh_tokens.read().await;
+
+        refresh_tokens.iter().find(|v| v.token_hash == token_hash).cloned()
+    }
+
+    async fn revoke_family(&self, family_id: TokenFamilyId) -> Option<RepositoryError> {
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+        let now = self.clock.now();
+
+        refresh_tokens
+            .iter_mut()
+            .filter(|v| v.family_id == family_id)
+            .for_each(|v| v.revoke(now));
+
+        None
+    }
+
+    async fn revoke_for_user(&self, user_id: UserId) -> Option<RepositoryError> {
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+        let now = self.clock.now();
+
+        refresh_tokens
+            .iter_mut()
+            .filter(|v| v.user_id == user_id)
+            .for_each(|v| v.revoke(now));
+
+        None
+    }
+}
@@ -0,0 +1,43 @@
+pub fn normalize(value: &str) -> String {
+    value
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            'ý' | 'ÿ' => 'y',
+            c => c,
+        })
+        .collect()
+}
+
+pub fn terms(value: &str) -> Vec<String> {
+    normalize(value).split_whitespace().map(str::to_string).collect()
+}
+
+pub fn rank(terms: &[String], title: &str, description: &str) -> Option<usize> {
+    if terms.is_empty() {
+        return None;
+    }
+
+    let title = normalize(title);
+    let description = normalize(description);
+
+    let mut score = 0;
+
+    for term in terms {
+        match (title.contains(term.as_str()), description.contains(term.as_str())) {
+            (true, true) => score += 3,
+            (true, false) => score += 2,
+            (false, true) => score += 1,
+            (false, false) => return None,
+        }
+    }
+
+    Some(score)
+}
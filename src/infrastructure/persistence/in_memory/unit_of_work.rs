@@ -0,0 +1,266 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use crate::application::repositories::RepositoryError;
+use crate::application::repositories::categories::CategoriesRepository;
+use crate::application::repositories::email_verifications::EmailVerificationsRepository;
+use crate::application::repositories::password_resets::PasswordResetsRepository;
+use crate::application::repositories::refresh_tokens::RefreshTokensRepository;
+use crate::application::repositories::unit_of_work::{Transaction, UnitOfWork};
+use crate::application::repositories::users::UsersRepository;
+use crate::application::repositories::videos::VideosRepository;
+use crate::domain::entities::categories::Categories;
+use crate::domain::entities::email_verifications::EmailVerifications;
+use crate::domain::entities::password_resets::PasswordResets;
+use crate::domain::entities::refresh_tokens::RefreshTokens;
+use crate::domain::entities::users::Users;
+use crate::domain::entities::videos::Videos;
+use crate::domain::value_objects::ids::{CategoryId, EmailVerificationId, PasswordResetId, RefreshTokenId, UserId, VideoId};
+use crate::infrastructure::persistence::in_memory::categories::CategoriesRepositoryInMemory;
+use crate::infrastructure::persistence::in_memory::email_verifications::EmailVerificationsRepositoryInMemory;
+use crate::infrastructure::persistence::in_memory::password_resets::PasswordResetsRepositoryInMemory;
+use crate::infrastructure::persistence::in_memory::refresh_tokens::RefreshTokensRepositoryInMemory;
+use crate::infrastructure::persistence::in_memory::users::UsersRepositoryInMemory;
+use crate::infrastructure::persistence::in_memory::videos::VideosRepositoryInMemory;
+
+#[derive(Clone, Default)]
+pub struct UnitOfWorkInMemory {
+    pub users: Arc<UsersRepositoryInMemory>,
+    pub videos: Arc<VideosRepositoryInMemory>,
+    pub categories: Arc<CategoriesRepositoryInMemory>,
+    pub refresh_tokens: Arc<RefreshTokensRepositoryInMemory>,
+    pub email_verifications: Arc<EmailVerificationsRepositoryInMemory>,
+    pub password_resets: Arc<PasswordResetsRepositoryInMemory>,
+    serializer: Arc<Mutex<()>>,
+}
+
+struct Snapshot {
+    users: Vec<Users>,
+    videos: Vec<Videos>,
+    categories: Vec<Categories>,
+    refresh_tokens: Vec<RefreshTokens>,
+    email_verifications: Vec<EmailVerifications>,
+    password_resets: Vec<PasswordResets>,
+}
+
+pub struct TransactionInMemory {
+    repositories: UnitOfWorkInMemory,
+    staged: UnitOfWorkInMemory,
+    snapshot: Snapshot,
+    _guard: OwnedMutexGuard<()>,
+}
+
+trait Keyed: Clone + PartialEq {
+    type Key: PartialEq;
+
+    fn key(&self) -> Self::Key;
+}
+
+struct Change<T: Keyed> {
+    key: T::Key,
+    before: Option<T>,
+    after: Option<T>,
+}
+
+fn find<T: Keyed>(items: &[T], key: &T::Key) -> Option<T> {
+    items.iter().find(|item| &item.key() == key).cloned()
+}
+
+fn changes<T: Keyed>(before: &[T], after: &[T]) -> Vec<Change<T>> {
+    let mut changes: Vec<Change<T>> = after
+        .iter()
+        .filter_map(|item| match find(before, &item.key()) {
+            Some(previous) if &previous == item => None,
+            previous => Some(Change { key: item.key(), before: previous, after: Some(item.clone()) }),
+        })
+        .collect();
+
+    changes.extend(before
+        .iter()
+        .filter(|item| find(after, &item.key()).is_none())
+        .map(|item| Change { key: item.key(), before: Some(item.clone()), after: None }));
+
+    changes
+}
+
+fn conflicts<T: Keyed>(current: &[T], changes: &[Change<T>]) -> bool {
+    changes.iter().any(|change| find(current, &change.key) != change.before)
+}
+
+fn apply<T: Keyed>(current: &mut Vec<T>, changes: Vec<Change<T>>) {
+    for change in changes {
+        let position = current.iter().position(|item| item.key() == change.key);
+
+        match (change.after, position) {
+            (Some(item), Some(position)) => current[position] = item,
+            (Some(item), None) => current.push(item),
+            (None, Some(position)) => {
+                current.remove(position);
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+macro_rules! keyed {
+    ($($entity:ty => $key:ty),* $(,)?) => {
+        $(impl Keyed for $entity {
+            type Key = $key;
+
+            fn key(&self) -> $key {
+                self.id
+            }
+        })*
+    };
+}
+
+keyed! {
+    Users => UserId,
+    Videos => VideoId,
+    Categories => CategoryId,
+    RefreshTokens => RefreshTokenId,
+    EmailVerifications => EmailVerificationId,
+    PasswordResets => PasswordResetId,
+}
+
+impl UnitOfWorkInMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_users(mut self, users: Arc<UsersRepositoryInMemory>) -> Self {
+        self.users = users;
+        self
+    }
+
+    pub fn with_videos(mut self, videos: Arc<VideosRepositoryInMemory>) -> Self {
+        self.videos = videos;
+        self
+    }
+
+    pub fn with_categories(mut self, categories: Arc<CategoriesRepositoryInMemory>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    pub fn with_refresh_tokens(mut self, refresh_tokens: Arc<RefreshTokensRepositoryInMemory>) -> Self {
+        self.refresh_tokens = refresh_tokens;
+        self
+    }
+
+    pub fn with_email_verifications(mut self, email_verifications: Arc<EmailVerificationsRepositoryInMemory>) -> Self {
+        self.email_verifications = email_verifications;
+        self
+    }
+
+    pub fn with_password_resets(mut self, password_resets: Arc<PasswordResetsRepositoryInMemory>) -> Self {
+        self.password_resets = password_resets;
+        self
+    }
+
+    async fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            users: self.users.users.read().await.clone(),
+            videos: self.videos.videos.read().await.clone(),
+            categories: self.categories.categories.read().await.clone(),
+            refresh_tokens: self.refresh_tokens.refresh_tokens.read().await.clone(),
+            email_verifications: self.email_verifications.email_verifications.read().await.clone(),
+            password_resets: self.password_resets.password_resets.read().await.clone(),
+        }
+    }
+
+    async fn stage(&self) -> Self {
+        Self {
+            users: Arc::new(self.users.stage().await),
+            videos: Arc::new(self.videos.stage().await),
+            categories: Arc::new(self.categories.stage().await),
+            refresh_tokens: Arc::new(self.refresh_tokens.stage().await),
+            email_verifications: Arc::new(self.email_verifications.stage().await),
+            password_resets: Arc::new(self.password_resets.stage().await),
+            serializer: self.serializer.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for UnitOfWorkInMemory {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, RepositoryError> {
+        let guard = self.serializer.clone().lock_owned().await;
+        let staged = self.stage().await;
+
+        Ok(Box::new(TransactionInMemory {
+            repositories: self.clone(),
+            snapshot: staged.snapshot().await,
+            staged,
+            _guard: guard,
+        }))
+    }
+}
+
+#[async_trait]
+impl Transaction for TransactionInMemory {
+    fn users(&self) -> &dyn UsersRepository {
+        self.staged.users.as_ref()
+    }
+
+    fn videos(&self) -> &dyn VideosRepository {
+        self.staged.videos.as_ref()
+    }
+
+    fn categories(&self) -> &dyn CategoriesRepository {
+        self.staged.categories.as_ref()
+    }
+
+    fn refresh_tokens(&self) -> &dyn RefreshTokensRepository {
+        self.staged.refresh_tokens.as_ref()
+    }
+
+    fn email_verifications(&self) -> &dyn EmailVerificationsRepository {
+        self.staged.email_verifications.as_ref()
+    }
+
+    fn password_resets(&self) -> &dyn PasswordResetsRepository {
+        self.staged.password_resets.as_ref()
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        let staged = self.staged.snapshot().await;
+        let repositories = &self.repositories;
+
+        let mut users = repositories.users.users.write().await;
+        let mut videos = repositories.videos.videos.write().await;
+        let mut categories = repositories.categories.categories.write().await;
+        let mut refresh_tokens = repositories.refresh_tokens.refresh_tokens.write().await;
+        let mut email_verifications = repositories.email_verifications.email_verifications.write().await;
+        let mut password_resets = repositories.password_resets.password_resets.write().await;
+
+        let users_changes = changes(&self.snapshot.users, &staged.users);
+        let videos_changes = changes(&self.snapshot.videos, &staged.videos);
+        let categories_changes = changes(&self.snapshot.categories, &staged.categories);
+        let refresh_tokens_changes = changes(&self.snapshot.refresh_tokens, &staged.refresh_tokens);
+        let email_verifications_changes = changes(&self.snapshot.email_verifications, &staged.email_verifications);
+        let password_resets_changes = changes(&self.snapshot.password_resets, &staged.password_resets);
+
+        if conflicts(&users, &users_changes)
+            || conflicts(&videos, &videos_changes)
+            || conflicts(&categories, &categories_changes)
+            || conflicts(&refresh_tokens, &refresh_tokens_changes)
+            || conflicts(&email_verifications, &email_verifications_changes)
+            || conflicts(&password_resets, &password_resets_changes) {
+            return Err(RepositoryError::Conflict("Transaction conflicts with a concurrent write".to_string()));
+        }
+
+        apply(&mut users, users_changes);
+        apply(&mut videos, videos_changes);
+        apply(&mut categories, categories_changes);
+        apply(&mut refresh_tokens, refresh_tokens_changes);
+        apply(&mut email_verifications, email_verifications_changes);
+        apply(&mut password_resets, password_resets_changes);
+
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
@@ -1,55 +1,95 @@
 use async_trait::async_trait;
-use crate::application::repositories::{Repository, RepositoryError};
+use time::OffsetDateTime;
+use tokio::sync::RwLock;
+use crate::application::services::clock::ClockContract;
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{PageRequest, PageResult};
 use crate::application::repositories::users::UsersRepository;
 use crate::domain::entities::users::Users;
 use crate::domain::value_objects::email::EmailEntity;
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::ids::UserId;
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::in_memory::pagination::paginate;
 
 pub struct UsersRepositoryInMemory {
-    pub users: Vec<Users>,
+    pub users: RwLock<Vec<Users>>,
+    clock: ClockContract,
 }
 
 impl UsersRepositoryInMemory {
-    pub fn new() ->Self {
-        Self { users: vec![] }
+    pub fn new() -> Self {
+        Self { users: RwLock::new(vec![]), clock: SystemClock::shared() }
     }
 
-    fn len(&self) -> usize {
-        self.users.len()
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn stage(&self) -> Self {
+        Self { users: RwLock::new(self.users.read().await.clone()), clock: self.clock.clone() }
+    }
+}
+
+impl Default for UsersRepositoryInMemory {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[async_trait]
-impl Repository<Users> for UsersRepositoryInMemory {
-    async fn find_all(&self) -> Vec<Users> {
-        if self.len() > 10 {
-            panic!("Too many users in memory, please clear the memory before running the tests");
-        }
+impl Repository<Users, UserId> for UsersRepositoryInMemory {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<Users>, RepositoryError> {
+        let users = self.users.read().await;
 
-        self.users.clone()
+        paginate(users.iter().filter(|v| !v.is_deleted()), &request)
     }
 
-    async fn find_by_id(&self, id: UniqueEntityID) -> Result<Users, RepositoryError> {
-        match self.users.iter().find(|v| v.id == id) {
+    async fn find_by_id(&self, id: UserId) -> Result<Users, RepositoryError> {
+        let users = self.users.read().await;
+
+        match users.iter().find(|v| v.id == id && !v.is_deleted()) {
             Some(user) => Ok(user.clone()),
             None => Err(RepositoryError::NotFound("User not found".to_string())),
         }
     }
 
-    async fn save(&mut self, entity: Users) -> Result<Users, RepositoryError> {
-        match self.users.iter().find(|v| v.id == entity.id) {
+    async fn save(&self, entity: Users) -> Result<Users, RepositoryError> {
+        let mut users = self.users.write().await;
+
+        match users.iter().find(|v| v.id == entity.id) {
             Some(_) => Err(RepositoryError::AlreadyExists("User already exists".to_string())),
             None => {
-                self.users.push(entity.clone());
+                users.push(entity.clone());
                 Ok(entity)
             }
         }
     }
 
-    async fn delete(&mut self, id: UniqueEntityID) -> Option<RepositoryError> {
-        match self.users.iter().position(|v| v.id == id) {
+    async fn update(&self, entity: Users) -> Result<Users, RepositoryError> {
+        let mut users = self.users.write().await;
+
+        match users.iter().position(|v| v.id == entity.id && !v.is_deleted()) {
+            Some(index) if users[index].version != entity.version => {
+                Err(RepositoryError::Conflict("User was modified by another request".to_string()))
+            }
             Some(index) => {
-                self.users.remove(index);
+                let mut entity = entity;
+                entity.version += 1;
+                users[index] = entity.clone();
+                Ok(entity)
+            }
+            None => Err(RepositoryError::NotFound("User not found".to_string())),
+        }
+    }
+
+    async fn delete(&self, id: UserId) -> Option<RepositoryError> {
+        let mut users = self.users.write().await;
+
+        match users.iter_mut().find(|v| v.id == id && !v.is_deleted()) {
+            Some(user) => {
+                user.soft_delete(self.clock.now());
+                user.version += 1;
                 None
             }
             None => Some(RepositoryError::NotFound("User not found".to_string())),
@@ -58,9 +98,76 @@ impl Repository<Users> for UsersRepositoryInMemory {
 }
 
 #[async_trait]
+impl SoftDeleteRepository<Users, UserId> for UsersRepositoryInMemory {
+    async fn find_deleted_by_id(&self, id: UserId) -> Result<Users, RepositoryError> {
+        let users = self.users.read().await;
 
+        match users.iter().find(|v| v.id == id && v.is_deleted()) {
+            Some(user) => Ok(user.clone()),
+            None => Err(RepositoryError::NotFound("User not found".to_string())),
+        }
+    }
+
+    async fn delete_at_version(&self, id: UserId, version: i32) -> Option<RepositoryError> {
+        let mut users = self.users.write().await;
+
+        match users.iter_mut().find(|v| v.id == id && !v.is_deleted()) {
+            Some(user) if user.version != version => Some(RepositoryError::Conflict("User was modified by another request".to_string())),
+            Some(user) => {
+                user.soft_delete(self.clock.now());
+                user.version += 1;
+                None
+            }
+            None => Some(RepositoryError::NotFound("User not found".to_string())),
+        }
+    }
+
+    async fn restore(&self, id: UserId) -> Result<Users, RepositoryError> {
+        let mut users = self.users.write().await;
+
+        match users.iter_mut().find(|v| v.id == id && v.is_deleted()) {
+            Some(user) => {
+                user.restore(self.clock.now());
+                user.version += 1;
+                Ok(user.clone())
+            }
+            None => Err(RepositoryError::NotFound("User not found".to_string())),
+        }
+    }
+
+    async fn purge(&self, id: UserId) -> Option<RepositoryError> {
+        let mut users = self.users.write().await;
+
+        match users.iter().position(|v| v.id == id && v.is_deleted()) {
+            Some(index) => {
+                users.remove(index);
+                None
+            }
+            None => Some(RepositoryError::NotFound("User not found".to_string())),
+        }
+    }
+
+    async fn purge_deleted_before(&self, cutoff: OffsetDateTime) -> Result<u64, RepositoryError> {
+        let mut users = self.users.write().await;
+        let total = users.len();
+
+        users.retain(|v| v.deleted_at.is_none_or(|deleted_at| deleted_at >= cutoff));
+
+        Ok((total - users.len()) as u64)
+    }
+}
+
+#[async_trait]
 impl UsersRepository for UsersRepositoryInMemory {
-    async fn find_by_email(&self, email: EmailEntity) -> Option<Users> {
-        self.users.iter().find(|v| v.email == email).cloned()
+    async fn find_by_email(&self, email: EmailEntity) -> Result<Option<Users>, RepositoryError> {
+        let users = self.users.read().await;
+
+        Ok(users.iter().find(|v| v.email == email && !v.is_deleted()).cloned())
+    }
+
+    async fn find_deleted_before(&self, cutoff: OffsetDateTime) -> Result<Vec<Users>, RepositoryError> {
+        let users = self.users.read().await;
+
+        Ok(users.iter().filter(|v| v.deleted_at.is_some_and(|deleted_at| deleted_at < cutoff)).cloned().collect())
     }
 }
\ No newline at end of file
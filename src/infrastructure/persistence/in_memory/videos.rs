@@ -1,65 +1,260 @@
 use async_trait::async_trait;
-use crate::application::repositories::{Repository, RepositoryError};
+use time::OffsetDateTime;
+use tokio::sync::RwLock;
+use crate::application::services::clock::ClockContract;
+use crate::application::repositories::{Repository, RepositoryError, SoftDeleteRepository};
+use crate::application::repositories::pagination::{PageRequest, PageResult};
 use crate::application::repositories::videos::VideosRepository;
 use crate::domain::entities::videos::{Videos};
-use crate::domain::value_objects::unique_id::UniqueEntityID;
+use crate::domain::value_objects::ids::{CategoryId, UserId, VideoId};
+use crate::application::repositories::pagination::Pageable;
+use crate::infrastructure::clock::SystemClock;
+use crate::infrastructure::persistence::in_memory::pagination::paginate;
+use crate::infrastructure::persistence::in_memory::search::{rank, terms};
 
-#[derive(Clone)]
 pub struct VideosRepositoryInMemory {
-    pub videos: Vec<Videos>,
+    pub videos: RwLock<Vec<Videos>>,
+    clock: ClockContract,
 }
 
 impl VideosRepositoryInMemory {
     pub fn new() -> Self {
-        Self { videos: vec![] }
+        Self { videos: RwLock::new(vec![]), clock: SystemClock::shared() }
     }
 
-    fn len(&self) -> usize {
-        self.videos.len()
+    pub fn with_clock(mut self, clock: ClockContract) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn stage(&self) -> Self {
+        Self { videos: RwLock::new(self.videos.read().await.clone()), clock: self.clock.clone() }
+    }
+}
+
+impl Default for VideosRepositoryInMemory {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[async_trait]
-impl Repository<Videos> for VideosRepositoryInMemory {
-    async fn find_all(&self) -> Vec<Videos> {
-        if self.len() > 10 {
-            panic!("Too many videos in memory, please clear the memory before running the tests");
-        }
+impl Repository<Videos, VideoId> for VideosRepositoryInMemory {
+    async fn find_all(&self, request: PageRequest) -> Result<PageResult<Videos>, RepositoryError> {
+        let videos = self.videos.read().await;
 
-        self.videos.clone()
+        paginate(videos.iter().filter(|v| !v.is_deleted()), &request)
     }
 
-    async fn find_by_id(&self, id: UniqueEntityID) -> Result<Videos, RepositoryError> {
-        match self.videos.iter().find(|v| v.id == id) {
+    async fn find_by_id(&self, id: VideoId) -> Result<Videos, RepositoryError> {
+        let videos = self.videos.read().await;
+
+        match videos.iter().find(|v| v.id == id && !v.is_deleted()) {
             Some(video) => Ok(video.clone()),
             None => Err(RepositoryError::NotFound("Video not found".to_string())),
         }
     }
 
-    async fn save(&mut self, entity: Videos) -> Result<Videos, RepositoryError> {
-        match self.videos.iter().find(|v| v.id == entity.id) {
+    async fn save(&self, entity: Videos) -> Result<Videos, RepositoryError> {
+        let mut videos = self.videos.write().await;
+
+        match videos.iter().find(|v| v.id == entity.id) {
             Some(_) => Err(RepositoryError::AlreadyExists("Video already exists".to_string())),
             None => {
-                self.videos.push(entity.clone());
+                videos.push(entity.clone());
                 Ok(entity)
             }
         }
     }
 
-    async fn delete(&mut self, id: UniqueEntityID) -> Option<RepositoryError> {
-        match self.videos.iter().position(|v| v.id == id) {
+    async fn update(&self, entity: Videos) -> Result<Videos, RepositoryError> {
+        let mut videos = self.videos.write().await;
+
+        match videos.iter().position(|v| v.id == entity.id && !v.is_deleted()) {
+            Some(index) if videos[index].version != entity.version => {
+                Err(RepositoryError::Conflict("Video was modified by another request".to_string()))
+            }
+            Some(index) => {
+                let mut entity = entity;
+                entity.version += 1;
+                videos[index] = entity.clone();
+                Ok(entity)
+            }
+            None => Err(RepositoryError::NotFound("Video not found".to_string())),
+        }
+    }
+
+    async fn delete(&self, id: VideoId) -> Option<RepositoryError> {
+        let mut videos = self.videos.write().await;
+
+        match videos.iter_mut().find(|v| v.id == id && !v.is_deleted()) {
+            Some(video) => {
+                video.soft_delete(self.clock.now());
+                video.version += 1;
+                None
+            }
+            None => Some(RepositoryError::NotFound("Video not found".to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl SoftDeleteRepository<Videos, VideoId> for VideosRepositoryInMemory {
+    async fn find_deleted_by_id(&self, id: VideoId) -> Result<Videos, RepositoryError> {
+        let videos = self.videos.read().await;
+
+        match videos.iter().find(|v| v.id == id && v.is_deleted()) {
+            Some(video) => Ok(video.clone()),
+            None => Err(RepositoryError::NotFound("Video not found".to_string())),
+        }
+    }
+
+    async fn delete_at_version(&self, id: VideoId, version: i32) -> Option<RepositoryError> {
+        let mut videos = self.videos.write().await;
+
+        match videos.iter_mut().find(|v| v.id == id && !v.is_deleted()) {
+            Some(video) if video.version != version => Some(RepositoryError::Conflict("Video was modified by another request".to_string())),
+            Some(video) => {
+                video.soft_delete(self.clock.now());
+                video.version += 1;
+                None
+            }
+            None => Some(RepositoryError::NotFound("Video not found".to_string())),
+        }
+    }
+
+    async fn restore(&self, id: VideoId) -> Result<Videos, RepositoryError> {
+        let mut videos = self.videos.write().await;
+
+        match videos.iter_mut().find(|v| v.id == id && v.is_deleted()) {
+            Some(video) => {
+                video.restore(self.clock.now());
+                video.version += 1;
+                Ok(video.clone())
+            }
+            None => Err(RepositoryError::NotFound("Video not found".to_string())),
+        }
+    }
+
+    async fn purge(&self, id: VideoId) -> Option<RepositoryError> {
+        let mut videos = self.videos.write().await;
+
+        match videos.iter().position(|v| v.id == id && v.is_deleted()) {
             Some(index) => {
-                self.videos.remove(index);
+                videos.remove(index);
                 None
             }
             None => Some(RepositoryError::NotFound("Video not found".to_string())),
         }
     }
+
+    async fn purge_deleted_before(&self, cutoff: OffsetDateTime) -> Result<u64, RepositoryError> {
+        let mut videos = self.videos.write().await;
+        let total = videos.len();
+
+        videos.retain(|v| v.deleted_at.is_none_or(|deleted_at| deleted_at >= cutoff));
+
+        Ok((total - videos.len()) as u64)
+    }
 }
 
 #[async_trait]
 impl VideosRepository for VideosRepositoryInMemory {
-    async fn find_by_category_id(&self, category_id: UniqueEntityID) -> Vec<Videos> {
-        self.videos.iter().filter(|v| v.category_id == category_id).cloned().collect()
+    async fn find_by_category_id(&self, category_id: CategoryId) -> Result<Vec<Videos>, RepositoryError> {
+        let videos = self.videos.read().await;
+
+        Ok(videos.iter().filter(|v| v.category_id == category_id && !v.is_deleted()).cloned().collect())
+    }
+
+    async fn search(&self, query: &str, request: PageRequest) -> Result<PageResult<Videos>, RepositoryError> {
+        let videos = self.videos.read().await;
+
+        request.require_offset()?;
+
+        let terms = terms(query);
+
+        let mut ranked: Vec<(usize, &Videos)> = videos
+            .iter()
+            .filter(|v| !v.is_deleted())
+            .filter_map(|v| rank(&terms, &v.title, &v.description).map(|score| (score, v)))
+            .collect();
+
+        ranked.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| a.created_at.cmp(&b.created_at))
+                .then_with(|| a.cursor_id().cmp(&b.cursor_id()))
+        });
+
+        let total = ranked.len() as u64;
+
+        let items = ranked
+            .into_iter()
+            .skip(request.offset() as usize)
+            .take(request.limit as usize)
+            .map(|(_, v)| v.clone())
+            .collect();
+
+        Ok(PageResult::from_page(items, total, &request))
+    }
+
+    async fn purge_deleted_by_category_id(&self, category_id: CategoryId) -> Result<u64, RepositoryError> {
+        let mut videos = self.videos.write().await;
+        let total = videos.len();
+
+        videos.retain(|v| !(v.category_id == category_id && v.is_deleted()));
+
+        Ok((total - videos.len()) as u64)
+    }
+
+    async fn delete_by_user_id(&self, user_id: UserId) -> Result<u64, RepositoryError> {
+        let mut videos = self.videos.write().await;
+        let now = self.clock.now();
+        let mut affected = 0;
+
+        for video in videos.iter_mut().filter(|v| v.user_id == user_id && !v.is_deleted()) {
+            video.soft_delete(now);
+            video.version += 1;
+            affected += 1;
+        }
+
+        Ok(affected)
+    }
+
+    async fn restore_by_user_id(&self, user_id: UserId, deleted_since: OffsetDateTime) -> Result<u64, RepositoryError> {
+        let mut videos = self.videos.write().await;
+        let now = self.clock.now();
+        let mut affected = 0;
+
+        for video in videos.iter_mut().filter(|v| v.user_id == user_id && v.deleted_at.is_some_and(|at| at >= deleted_since)) {
+            video.restore(now);
+            video.version += 1;
+            affected += 1;
+        }
+
+        Ok(affected)
     }
-}
\ No newline at end of file
+
+    async fn purge_by_user_id(&self, user_id: UserId) -> Result<u64, RepositoryError> {
+        let mut videos = self.videos.write().await;
+        let total = videos.len();
+
+        videos.retain(|v| v.user_id != user_id);
+
+        Ok((total - videos.len()) as u64)
+    }
+
+    async fn reassign_foreign_videos(&self, category_id: CategoryId, owner_id: UserId, target_id: CategoryId) -> Result<u64, RepositoryError> {
+        let mut videos = self.videos.write().await;
+        let now = self.clock.now();
+        let mut affected = 0;
+
+        for video in videos.iter_mut().filter(|v| v.category_id == category_id && v.user_id != owner_id) {
+            video.move_to_category(target_id, now);
+            video.version += 1;
+            affected += 1;
+        }
+
+        Ok(affected)
+    }
+}
@@ -0,0 +1,89 @@
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::errors::ErrorKind;
+use time::Duration;
+use crate::application::services::clock::ClockContract;
+use crate::application::services::tokens::{AccessClaims, TokenError, TokenService};
+use crate::domain::value_objects::role::Role;
+use crate::domain::value_objects::ids::UserId;
+use crate::infrastructure::config::{parse_or, required, ConfigError};
+
+pub struct JwtConfig {
+    pub secret: String,
+    pub access_token_ttl: Duration,
+    pub refresh_token_ttl: Duration,
+}
+
+impl JwtConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let secret = required("JWT_SECRET")?;
+        let access_token_ttl = parse_or("JWT_ACCESS_TTL", 900i64, "must be a number of seconds")?;
+        let refresh_token_ttl = parse_or("JWT_REFRESH_TTL", 2592000i64, "must be a number of seconds")?;
+
+        Ok(Self {
+            secret,
+            access_token_ttl: Duration::seconds(access_token_ttl),
+            refresh_token_ttl: Duration::seconds(refresh_token_ttl),
+        })
+    }
+}
+
+pub struct JwtTokenService {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    access_token_ttl: Duration,
+    refresh_token_ttl: Duration,
+    clock: ClockContract,
+}
+
+impl JwtTokenService {
+    pub fn new(config: JwtConfig, clock: ClockContract) -> Self {
+        Self {
+            encoding_key: EncodingKey::from_secret(config.secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(config.secret.as_bytes()),
+            access_token_ttl: config.access_token_ttl,
+            refresh_token_ttl: config.refresh_token_ttl,
+            clock,
+        }
+    }
+}
+
+impl TokenService for JwtTokenService {
+    fn issue_access_token(&self, user_id: &UserId, role: Role) -> Result<String, TokenError> {
+        let now = self.clock.now();
+
+        let claims = AccessClaims {
+            sub: user_id.to_string(),
+            role,
+            iat: now.unix_timestamp(),
+            exp: (now + self.access_token_ttl).unix_timestamp(),
+        };
+
+        match jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key) {
+            Ok(token) => Ok(token),
+            Err(error) => Err(TokenError::Signing(error.to_string())),
+        }
+    }
+
+    fn verify_access_token(&self, token: &str) -> Result<AccessClaims, TokenError> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.leeway = 0;
+        validation.validate_exp = false;
+
+        match jsonwebtoken::decode::<AccessClaims>(token, &self.decoding_key, &validation) {
+            Ok(data) if data.claims.exp <= self.clock.now().unix_timestamp() => Err(TokenError::Expired),
+            Ok(data) => Ok(data.claims),
+            Err(error) => match error.kind() {
+                ErrorKind::ExpiredSignature => Err(TokenError::Expired),
+                _ => Err(TokenError::Invalid),
+            },
+        }
+    }
+
+    fn access_token_ttl(&self) -> Duration {
+        self.access_token_ttl
+    }
+
+    fn refresh_token_ttl(&self) -> Duration {
+        self.refresh_token_ttl
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use time::{Duration, OffsetDateTime};
+use crate::application::services::clock::ClockContract;
+use crate::application::services::login_throttle::{LoginAttemptKey, LoginThrottle};
+use crate::infrastructure::config::{parse_or, ConfigError};
+
+const PRUNE_THRESHOLD: usize = 10_000;
+
+pub struct LoginThrottleConfig {
+    pub max_attempts_per_email: u32,
+    pub max_attempts_per_ip: u32,
+    pub backoff_base: Duration,
+    pub lockout: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoginAttempts {
+    pub failures: u32,
+    pub last_failure: OffsetDateTime,
+}
+
+pub struct InMemoryLoginThrottle {
+    config: LoginThrottleConfig,
+    clock: ClockContract,
+    pub attempts: Mutex<HashMap<LoginAttemptKey, LoginAttempts>>,
+}
+
+impl LoginThrottleConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let max_attempts_per_email = parse_or("LOGIN_MAX_ATTEMPTS", 5u32, "must be a positive number")?;
+        let max_attempts_per_ip = parse_or("LOGIN_MAX_ATTEMPTS_PER_IP", 50u32, "must be a positive number")?;
+        let backoff_base = parse_or("LOGIN_BACKOFF_BASE", 1i64, "must be a number of seconds")?;
+        let lockout = parse_or("LOGIN_LOCKOUT", 900i64, "must be a number of seconds")?;
+
+        Ok(Self {
+            max_attempts_per_email: max_attempts_per_email.max(1),
+            max_attempts_per_ip: max_attempts_per_ip.max(1),
+            backoff_base: Duration::seconds(backoff_base),
+            lockout: Duration::seconds(lockout),
+        })
+    }
+
+    fn delay(&self, key: &LoginAttemptKey, failures: u32) -> Duration {
+        match key {
+            LoginAttemptKey::Email(_) if failures >= self.max_attempts_per_email => self.lockout,
+            LoginAttemptKey::Email(_) if failures > 0 => {
+                let factor = 2i32.saturating_pow(failures - 1);
+
+                self.backoff_base.saturating_mul(factor).min(self.lockout)
+            }
+            LoginAttemptKey::Ip(_) if failures >= self.max_attempts_per_ip => self.lockout,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+impl InMemoryLoginThrottle {
+    pub fn new(config: LoginThrottleConfig, clock: ClockContract) -> Self {
+        Self {
+            config,
+            clock,
+            attempts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn is_stale(&self, attempts: &LoginAttempts, now: OffsetDateTime) -> bool {
+        now - attempts.last_failure > self.config.lockout
+    }
+
+    fn release_one(attempts: &mut HashMap<LoginAttemptKey, LoginAttempts>, key: &LoginAttemptKey) {
+        if let Some(entry) = attempts.get_mut(key) {
+            entry.failures = entry.failures.saturating_sub(1);
+
+            if entry.failures == 0 {
+                attempts.remove(key);
+            }
+        }
+    }
+}
+
+impl LoginThrottle for InMemoryLoginThrottle {
+    fn check(&self, keys: &[LoginAttemptKey]) -> Result<(), Duration> {
+        let now = self.clock.now();
+        let mut attempts = self.attempts.lock().unwrap();
+
+        let retry_after = keys
+            .iter()
+            .filter_map(|key| attempts.get(key).map(|entry| (key, entry)))
+            .map(|(key, entry)| entry.last_failure + self.config.delay(key, entry.failures) - now)
+            .max()
+            .unwrap_or(Duration::ZERO);
+
+        if retry_after.is_positive() {
+            return Err(retry_after);
+        }
+
+        if attempts.len() > PRUNE_THRESHOLD {
+            attempts.retain(|_, entry| !self.is_stale(entry, now));
+        }
+
+        for key in keys {
+            let entry = attempts.entry(key.clone()).or_insert(LoginAttempts { failures: 0, last_failure: now });
+
+            if self.is_stale(entry, now) {
+                entry.failures = 0;
+            }
+
+            entry.failures = entry.failures.saturating_add(1);
+            entry.last_failure = now;
+        }
+
+        Ok(())
+    }
+
+    fn record_success(&self, keys: &[LoginAttemptKey]) {
+        let mut attempts = self.attempts.lock().unwrap();
+
+        for key in keys {
+            match key {
+                LoginAttemptKey::Email(_) => {
+                    attempts.remove(key);
+                }
+                LoginAttemptKey::Ip(_) => Self::release_one(&mut attempts, key),
+            }
+        }
+    }
+
+    fn release(&self, keys: &[LoginAttemptKey]) {
+        let mut attempts = self.attempts.lock().unwrap();
+
+        for key in keys {
+            Self::release_one(&mut attempts, key);
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod jwt;
+pub mod login_throttle;
\ No newline at end of file
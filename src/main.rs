@@ -1,33 +1,183 @@
-use std::sync::{Arc, Mutex};
-use crate::domain::value_objects::ValueObjectTrait;
-use crate::infrastructure::persistence::in_memory::users::UsersRepositoryInMemory;
-use crate::application::usecases::authentication::AuthUseCase;
-use crate::domain::entities::users::UsersInput;
-use crate::infrastructure::persistence::database::users::UsersRepositoryImpl;
-
-mod domain;
-mod application;
-mod infrastructure;
+use std::env;
+use std::process;
+use std::sync::Arc;
+use sqlx::PgPool;
+use aluraflix_rust::application::usecases::authentication::AuthUseCase;
+use aluraflix_rust::application::usecases::categories::CategoriesUseCase;
+use aluraflix_rust::application::usecases::retention::RetentionUseCase;
+use aluraflix_rust::application::usecases::users::{ChangeRoleInput, UsersUseCase, UsersUseCaseError};
+use aluraflix_rust::application::usecases::videos::VideosUseCase;
+use aluraflix_rust::infrastructure::clock::SystemClock;
+use aluraflix_rust::infrastructure::config;
+use aluraflix_rust::infrastructure::http;
+use aluraflix_rust::infrastructure::jobs;
+use aluraflix_rust::domain::value_objects::ValueObjectTrait;
+use aluraflix_rust::infrastructure::http::client_ip::ClientIpSource;
+use aluraflix_rust::infrastructure::http::state::AppState;
+use aluraflix_rust::infrastructure::mail::{build_mailer, MailConfig};
+use aluraflix_rust::infrastructure::persistence::database::Database;
+use aluraflix_rust::infrastructure::persistence::database::categories::CategoriesRepositoryImpl;
+use aluraflix_rust::infrastructure::persistence::database::connection::{DatabaseConfig, PostgresDatabase};
+use aluraflix_rust::infrastructure::persistence::database::email_verifications::EmailVerificationsRepositoryImpl;
+use aluraflix_rust::infrastructure::persistence::database::password_resets::PasswordResetsRepositoryImpl;
+use aluraflix_rust::infrastructure::persistence::database::migrations;
+use aluraflix_rust::infrastructure::persistence::database::refresh_tokens::RefreshTokensRepositoryImpl;
+use aluraflix_rust::infrastructure::persistence::database::users::UsersRepositoryImpl;
+use aluraflix_rust::infrastructure::persistence::database::unit_of_work::UnitOfWorkImpl;
+use aluraflix_rust::infrastructure::persistence::database::videos::VideosRepositoryImpl;
+use aluraflix_rust::infrastructure::security::jwt::{JwtConfig, JwtTokenService};
+use aluraflix_rust::infrastructure::security::login_throttle::{InMemoryLoginThrottle, LoginThrottleConfig};
 
 #[tokio::main]
 async fn main() {
-    let user_repositories = UsersRepositoryImpl::new().await;
+    let args: Vec<String> = env::args().collect();
+
+    let database_config = match DatabaseConfig::from_env() {
+        Ok(config) => config,
+        Err(error) => panic!("Invalid database configuration: {}", error),
+    };
+
+    let database = match PostgresDatabase::connect(&database_config).await {
+        Ok(database) => database,
+        Err(error) => panic!("Error connecting to database: {}", error),
+    };
 
-    if user_repositories.is_err() {
-        panic!("Error connecting to database");
+    if args.get(1).map(String::as_str) == Some("migrate") {
+        migrate(database.pool(), args.get(2).map(String::as_str)).await;
+        return;
     }
 
-    let mut use_case = AuthUseCase::new(
-        Arc::new(Mutex::new(user_repositories.unwrap()))
-    );
+    if let Err(error) = migrations::run(database.pool()).await {
+        panic!("Error running database migrations: {}", error);
+    }
+
+    if args.get(1).map(String::as_str) == Some("role") {
+        assign_role(database.pool(), args.get(2).map(String::as_str), args.get(3).map(String::as_str)).await;
+        return;
+    }
 
-    let user_input = UsersInput{
-        name: String::from("Teste"),
-        email: String::from("johndoe@test.com"),
-        password: String::from("12345678")
+    let jwt_config = match JwtConfig::from_env() {
+        Ok(config) => config,
+        Err(error) => panic!("Invalid JWT configuration: {}", error),
     };
 
-    let user = use_case.sign_up(user_input).await.unwrap();
+    let account_policy = match config::account_policy_from_env() {
+        Ok(policy) => policy,
+        Err(error) => panic!("Invalid account configuration: {}", error),
+    };
+
+    let login_throttle_config = match LoginThrottleConfig::from_env() {
+        Ok(config) => config,
+        Err(error) => panic!("Invalid login throttle configuration: {}", error),
+    };
+
+    let client_ip_source = match ClientIpSource::from_env() {
+        Ok(source) => source,
+        Err(error) => panic!("Invalid client IP configuration: {}", error),
+    };
+
+    let retention_policy = match config::retention_policy_from_env() {
+        Ok(policy) => policy,
+        Err(error) => panic!("Invalid retention configuration: {}", error),
+    };
+
+    let mailer = match MailConfig::from_env().map(build_mailer) {
+        Ok(Ok(mailer)) => mailer,
+        Ok(Err(error)) => panic!("Error building the mailer: {:?}", error),
+        Err(error) => panic!("Invalid mail configuration: {}", error),
+    };
 
-    println!("{:?}", user);
+    let clock = SystemClock::shared();
+
+    let users_repository = Arc::new(UsersRepositoryImpl::new(database.pool().clone()).with_clock(clock.clone()));
+    let refresh_tokens_repository = RefreshTokensRepositoryImpl::new(database.pool().clone()).with_clock(clock.clone());
+    let email_verifications_repository = EmailVerificationsRepositoryImpl::new(database.pool().clone()).with_clock(clock.clone());
+    let password_resets_repository = PasswordResetsRepositoryImpl::new(database.pool().clone()).with_clock(clock.clone());
+
+    let videos_repository = Arc::new(VideosRepositoryImpl::new(database.pool().clone()).with_clock(clock.clone()));
+    let categories_repository = Arc::new(CategoriesRepositoryImpl::new(database.pool().clone()).with_clock(clock.clone()));
+
+    let unit_of_work = Arc::new(UnitOfWorkImpl::new(database.pool().clone()).with_clock(clock.clone()));
+
+    let state = AppState::new(
+        AuthUseCase::new(
+            users_repository.clone(),
+            Arc::new(refresh_tokens_repository),
+            Arc::new(email_verifications_repository),
+            Arc::new(password_resets_repository),
+            unit_of_work.clone(),
+            Arc::new(JwtTokenService::new(jwt_config, clock.clone())),
+            mailer,
+            Arc::new(InMemoryLoginThrottle::new(login_throttle_config, clock.clone())),
+            account_policy,
+            clock.clone(),
+        ),
+        VideosUseCase::new(videos_repository.clone(), categories_repository.clone(), clock.clone()),
+        CategoriesUseCase::new(categories_repository, videos_repository, unit_of_work.clone(), clock.clone()),
+        UsersUseCase::new(users_repository, unit_of_work.clone(), clock.clone()),
+    )
+    .with_client_ip_source(client_ip_source)
+    .with_database(Arc::new(database));
+
+    jobs::retention::spawn(Arc::new(RetentionUseCase::new(unit_of_work, retention_policy, clock)));
+
+    if let Err(error) = http::serve(state).await {
+        panic!("Error running the HTTP server: {}", error);
+    }
+}
+
+async fn migrate(pool: &PgPool, action: Option<&str>) {
+    match action {
+        Some("run") => match migrations::run(pool).await {
+            Ok(()) => println!("Migrations applied"),
+            Err(error) => panic!("Error running database migrations: {}", error),
+        },
+        Some("revert") => match migrations::revert(pool).await {
+            Ok(Some(version)) => println!("Reverted migration {}", version),
+            Ok(None) => println!("No migrations to revert"),
+            Err(error) => panic!("Error reverting database migration: {}", error),
+        },
+        Some("status") => match migrations::status(pool).await {
+            Ok(statuses) => {
+                for status in statuses {
+                    let state = if status.applied { "applied" } else { "pending" };
+                    println!("{} {} {}", status.version, state, status.description);
+                }
+            }
+            Err(error) => panic!("Error reading migration status: {}", error),
+        },
+        _ => {
+            eprintln!("Usage: aluraflix_rust migrate <run|revert|status>");
+            process::exit(2);
+        }
+    }
+}
+
+async fn assign_role(pool: &PgPool, email: Option<&str>, role: Option<&str>) {
+    let (email, role) = match (email, role) {
+        (Some(email), Some(role)) => (email, role),
+        _ => {
+            eprintln!("Usage: aluraflix_rust role <email> <viewer|creator|moderator|admin>");
+            process::exit(2);
+        }
+    };
+
+    let clock = SystemClock::shared();
+    let users_repository = Arc::new(UsersRepositoryImpl::new(pool.clone()).with_clock(clock.clone()));
+    let unit_of_work = Arc::new(UnitOfWorkImpl::new(pool.clone()).with_clock(clock.clone()));
+    let use_case = UsersUseCase::new(users_repository, unit_of_work, clock);
+
+    match use_case.assign_role(email, ChangeRoleInput { role: role.to_string() }).await {
+        Ok(user) => println!("{} is now {}", user.email.to_string(), user.role.to_string()),
+        Err(UsersUseCaseError::UserNotFound) => {
+            eprintln!("User {} not found", email);
+            process::exit(1);
+        }
+        Err(UsersUseCaseError::Domain(error)) => {
+            eprintln!("{:?}", error);
+            eprintln!("Usage: aluraflix_rust role <email> <viewer|creator|moderator|admin>");
+            process::exit(2);
+        }
+        Err(error) => panic!("Error updating user role: {:?}", error),
+    }
 }